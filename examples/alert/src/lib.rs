@@ -49,6 +49,6 @@ impl Component for AlertDemo {
 // This is the entry point for the web app
 #[wasm_bindgen]
 pub fn run_app() -> Result<(), JsValue> {
-    yew::start_app::<AlertDemo>();
+    yew::Renderer::<AlertDemo>::new().render();
     Ok(())
 }
@@ -34,5 +34,5 @@ impl Component for BadgeDemo {
 
 #[wasm_bindgen]
 pub fn main() {
-    yew::start_app::<BadgeDemo>();
+    yew::Renderer::<BadgeDemo>::new().render();
 }
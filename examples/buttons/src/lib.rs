@@ -42,5 +42,5 @@ impl Component for LinkDemo {
 
 #[wasm_bindgen]
 pub fn main() {
-    yew::start_app::<LinkDemo>();
+    yew::Renderer::<LinkDemo>::new().render();
 }
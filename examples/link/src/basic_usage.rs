@@ -1,9 +1,5 @@
-use domatt::attributes::anchor::Href;
-use domatt::attributes::aria::AriaLabel;
 use yew::{html, Component};
-use yew_clay::{ClayContainer, ClayLink, LinkDisplayType};
-use yew_dom_attributes::anchor_props::AnchorProps;
-use yew_dom_attributes::DomInjector;
+use yew_clay::{AnchorProps, ClayContainer, ClayLink, LinkDisplayType};
 
 use crate::CONTAINER_CLASS;
 
@@ -17,12 +13,11 @@ impl Component for BasicUsage {
     type Properties = ();
 
     fn create(_ctx: &yew::Context<Self>) -> Self {
-        let mut anchor_props = AnchorProps::new();
-        anchor_props.add_attribute(Box::new(Href::new(String::from("#link-styles"))));
+        let anchor_props = AnchorProps::new().with_attribute("href", "#link-styles");
 
-        let mut with_aria_props = AnchorProps::new();
-        with_aria_props.add_attribute(Box::new(AriaLabel::new(String::from("My Link"))));
-        with_aria_props.add_attribute(Box::new(Href::new(String::from("#link-styles"))));
+        let with_aria_props = AnchorProps::new()
+            .with_attribute("aria-label", "My Link")
+            .with_attribute("href", "#link-styles");
 
         Self {
             anchor_props,
@@ -1,11 +1,8 @@
-use domatt::attributes::svg::Href;
-use domatt::events::Click;
 use gloo_dialogs::confirm;
 use std::rc::Rc;
-use yew::{html, Component, ContextProvider, MouseEvent};
-use yew_clay::{ClayContainer, ClayLink, LinkContext};
-use yew_dom_attributes::anchor_props::AnchorProps;
-use yew_dom_attributes::DomInjector;
+use yew::events::Event;
+use yew::{html, Component, ContextProvider};
+use yew_clay::{AnchorProps, ClayContainer, ClayLink, LinkContext};
 
 use crate::CONTAINER_CLASS;
 
@@ -23,23 +20,21 @@ impl Component for WithContext {
     type Properties = ();
 
     fn create(ctx: &yew::Context<Self>) -> Self {
-        let mut context_props = AnchorProps::new();
-        let click = ctx.link().callback(|ev: MouseEvent| {
+        let click = ctx.link().callback(|ev: Event| {
             match confirm("r u sure?") {
                 true => (),
                 false => ev.prevent_default(),
             };
             Msg::None
         });
-        context_props.add_listener("link-alert", Rc::new(Click::from(click)));
+        let context_props = AnchorProps::new().with_listener("click", click);
 
         let link_context = LinkContext {
             tag: String::from("a"),
             props: Rc::new(context_props),
         };
 
-        let mut anchor_props = AnchorProps::new();
-        anchor_props.add_attribute(Box::new(Href::new(String::from("#"))));
+        let anchor_props = AnchorProps::new().with_attribute("href", "#");
 
         Self {
             link_context,
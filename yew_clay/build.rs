@@ -0,0 +1,85 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// The Lexicon spritemap the ClaySymbol enum is generated from.
+const SPRITEMAP: &str = "icons/icons.svg";
+const SYMBOL_ID: &str = "<symbol id=\"";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", SPRITEMAP);
+
+    let spritemap = fs::read_to_string(SPRITEMAP).expect("the Lexicon spritemap to be readable");
+    let symbols = get_symbol_ids(&spritemap);
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR to be set by cargo");
+
+    fs::write(Path::new(&out_dir).join("clay_symbol.rs"), gen_enum(&symbols))
+        .expect("the generated symbols to be writable");
+}
+
+fn get_symbol_ids(spritemap: &str) -> Vec<String> {
+    let mut ids: Vec<String> = spritemap
+        .match_indices(SYMBOL_ID)
+        .filter_map(|(index, _)| {
+            let id_start = index + SYMBOL_ID.len();
+            let id_len = spritemap[id_start..].find('"')?;
+            Some(spritemap[id_start..id_start + id_len].to_string())
+        })
+        .collect();
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+/// Converts a symbol id like `angle-left` into a variant name like `AngleLeft`.
+fn to_variant(id: &str) -> String {
+    id.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn gen_enum(symbols: &[String]) -> String {
+    let variants: Vec<(String, &String)> = symbols.iter().map(|id| (to_variant(id), id)).collect();
+    let mut code = String::new();
+
+    code.push_str(
+        "/// The symbols of the Lexicon spritemap that ships with Clay. Use `Custom` to reference\n\
+         /// symbols of other spritemaps.\n\
+         #[derive(Debug, Clone, PartialEq, Eq, Hash)]\n\
+         pub enum ClaySymbol {\n",
+    );
+    for (variant, id) in &variants {
+        writeln!(code, "    /// `{}`", id).unwrap();
+        writeln!(code, "    {},", variant).unwrap();
+    }
+    code.push_str("    /// A symbol that is not part of the Lexicon spritemap.\n    Custom(String),\n}\n\n");
+
+    code.push_str(
+        "impl AsRef<str> for ClaySymbol {\n    fn as_ref(&self) -> &str {\n        match self {\n",
+    );
+    for (variant, id) in &variants {
+        writeln!(code, "            Self::{} => \"{}\",", variant, id).unwrap();
+    }
+    code.push_str("            Self::Custom(symbol) => symbol,\n        }\n    }\n}\n\n");
+
+    code.push_str(
+        "impl ClaySymbol {\n    \
+         /// Finds the Lexicon symbol with the given id.\n    \
+         pub fn from_lexicon(id: &str) -> Option<Self> {\n        \
+         match id {\n",
+    );
+    for (variant, id) in &variants {
+        writeln!(code, "            \"{}\" => Some(Self::{}),", id, variant).unwrap();
+    }
+    code.push_str("            _ => None,\n        }\n    }\n}\n");
+
+    code
+}
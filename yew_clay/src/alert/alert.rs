@@ -1,46 +1,21 @@
-use super::utils::autoclose_timer::{
-    close, get_time_to_close, initialize_autoclose_callbacks, pause, start,
-};
+use super::toast_container::ToastContainerContext;
+use super::utils::autoclose_timer::use_clay_alert_timer;
 use super::utils::element_generators::{
     gen_default_alert, gen_default_footer_element, gen_dismiss_button, gen_inline_footer_element,
     gen_stripe_alert, gen_title_element,
 };
-use super::{AlertDisplayType, AlertVariant, ClayAlertProps};
+use super::{AlertDisplayType, AlertVariant, AriaLive, ClayAlertProps};
 use crate::alert::utils::sub_components::ConditionalContainer;
+use crate::html_props::with_html_props;
 use crate::layout::{ClayContentCol, ClayContentRow, ClayContentSection};
-use gloo_events::EventListener;
-use std::collections::HashMap;
-use web_sys::MouseEvent;
-use yew::{classes, html, Callback, Component, Context, Html, NodeRef};
-use yew_dom_attributes::DomInjector;
-
-/// A Yew implementation of ClayAlert. For more info about ClayAlert, check the documentation:
-/// <https://clayui.com/docs/components/alert.html>
-pub struct ClayAlert {
-    node_ref: NodeRef,
-    /// This vec holds all the EventListeners defined for this component. They will be automatically
-    /// removed when the component is destroyed.
-    listeners: HashMap<String, EventListener>,
-    timer_id: Option<i32>,
-    pause_timer: Option<Callback<MouseEvent>>,
-    start_timer: Option<Callback<MouseEvent>>,
-    started_time: Option<f64>,
-    time_to_close: Option<u32>,
-}
-
-pub enum Msg {
-    Close,
-    PauseTimer,
-    StartTimer,
-}
+use wasm_bindgen::JsCast;
+use web_sys::{FocusEvent, MouseEvent, Node};
+use yew::{classes, function_component, html, use_context, Callback, Html};
 
 impl ClayAlert {
     const ALERT: &'static str = "alert-";
 
-    fn get_show_dismissible(
-        on_close: &Option<Callback<MouseEvent>>,
-        hide_close_icon: bool,
-    ) -> bool {
+    fn get_show_dismissible(on_close: &Option<Callback<()>>, hide_close_icon: bool) -> bool {
         on_close.is_some() && !hide_close_icon
     }
 
@@ -63,6 +38,21 @@ impl ClayAlert {
         }
     }
 
+    fn get_aria_live(display_type: &AlertDisplayType, aria_live: Option<AriaLive>) -> AriaLive {
+        aria_live.unwrap_or(match display_type {
+            AlertDisplayType::Danger | AlertDisplayType::Warning => AriaLive::Assertive,
+            AlertDisplayType::Info | AlertDisplayType::Success => AriaLive::Polite,
+        })
+    }
+
+    fn get_role(aria_live: AriaLive) -> Option<&'static str> {
+        match aria_live {
+            AriaLive::Assertive => Some("alert"),
+            AriaLive::Polite => Some("status"),
+            AriaLive::Off => None,
+        }
+    }
+
     fn get_display_class(display_type: &AlertDisplayType) -> Option<String> {
         let display_type_str = display_type.as_ref();
         let mut display_class = String::with_capacity(display_type_str.len() + Self::ALERT.len());
@@ -72,89 +62,91 @@ impl ClayAlert {
     }
 }
 
-impl Component for ClayAlert {
-    type Message = Msg;
-    type Properties = ClayAlertProps;
-
-    fn create(ctx: &Context<Self>) -> Self {
-        let ClayAlertProps { auto_close, .. } = ctx.props().clone();
-        let time_to_close = get_time_to_close(auto_close);
-
-        let (pause_timer, start_timer) = initialize_autoclose_callbacks(ctx, time_to_close);
-
-        Self {
-            node_ref: ctx.props().node_ref.clone(),
-            listeners: HashMap::new(),
-            timer_id: None,
-            start_timer,
-            pause_timer,
-            started_time: None,
-            time_to_close,
-        }
-    }
-
-    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-        match msg {
-            Msg::Close => {
-                close(ctx);
-                true
-            }
-            Msg::PauseTimer => {
-                self.time_to_close = pause(
-                    self.timer_id.take(),
-                    &self.time_to_close,
-                    &self.started_time,
-                );
-                false
-            }
-            Msg::StartTimer => {
-                let maybe_start = start(&self.time_to_close, ctx);
-                if let Some((started_time, handler)) = maybe_start {
-                    self.started_time = started_time;
-                    self.timer_id = handler;
-                }
-
-                false
+/// A Yew implementation of ClayAlert. For more info about ClayAlert, check the documentation:
+/// <https://clayui.com/docs/components/alert.html>
+#[function_component(ClayAlert)]
+pub fn clay_alert(props: &ClayAlertProps) -> Html {
+    // Inside of a toast container, the container is the live region, so the alert must
+    // not be announced a second time.
+    let in_live_region = use_context::<ToastContainerContext>().is_some();
+    let timer = use_clay_alert_timer(
+        &props.node_ref,
+        &props.auto_close,
+        props.on_close.clone(),
+        props.on_progress.clone(),
+    );
+
+    let ClayAlertProps {
+        class,
+        children,
+        variant,
+        hide_close_icon,
+        on_close,
+        display_type,
+        spritemap,
+        title,
+        actions,
+        aria_live,
+        close_aria_label,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let (role, aria_live) = if in_live_region {
+        (None, None)
+    } else {
+        let aria_live = ClayAlert::get_aria_live(&display_type, aria_live);
+        (
+            ClayAlert::get_role(aria_live),
+            Some(aria_live.as_ref().to_string()),
+        )
+    };
+
+    let show_dismissible = ClayAlert::get_show_dismissible(&on_close, hide_close_icon);
+    let dismissible_class = ClayAlert::get_dismissible_class(show_dismissible);
+    let variant_class = ClayAlert::get_variant_class(&variant);
+    let display_class = ClayAlert::get_display_class(&display_type);
+
+    let onmouseenter = timer.set_hovered.reform(|_: MouseEvent| true);
+    let onmouseleave = timer.set_hovered.reform(|_: MouseEvent| false);
+    let onfocusin = timer.set_focus_within.reform(|_: FocusEvent| true);
+    // Focus moving between the elements of the alert doesn't count as leaving it.
+    let onfocusout = {
+        let node_ref = node_ref.clone();
+        let set_focus_within = timer.set_focus_within;
+        Callback::from(move |event: FocusEvent| {
+            let target = event
+                .related_target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            let inside = match (node_ref.cast::<Node>(), target) {
+                (Some(node), Some(target)) => node.contains(Some(&target)),
+                _ => false,
+            };
+            if !inside {
+                set_focus_within.emit(false);
             }
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let ClayAlertProps {
-            class,
-            children,
-            variant,
-            hide_close_icon,
-            on_close,
-            display_type,
-            spritemap,
-            title,
-            actions,
-            ..
-        } = ctx.props().clone();
-
-        let show_dismissible = Self::get_show_dismissible(&on_close, hide_close_icon);
-        let dismissible_class = Self::get_dismissible_class(show_dismissible);
-        let variant_class = Self::get_variant_class(&variant);
-        let display_class = Self::get_display_class(&display_type);
-
-        let start_timer = self.start_timer.clone();
-        let pause_timer = self.pause_timer.clone();
-
-        let stripe_alert_indicator = gen_stripe_alert(spritemap, &display_type, &variant);
-        let default_alert_indicator = gen_default_alert(spritemap, &display_type, &variant);
-        let title_element = gen_title_element(&title);
-        let default_footer_element = gen_default_footer_element(&variant, &actions);
-        let inline_footer_element = gen_inline_footer_element(&variant, &actions);
-        let dismiss_button = gen_dismiss_button(show_dismissible, on_close, spritemap);
-
+        })
+    };
+
+    let stripe_alert_indicator = gen_stripe_alert(spritemap, &display_type, &variant);
+    let default_alert_indicator = gen_default_alert(spritemap, &display_type, &variant);
+    let title_element = gen_title_element(&title);
+    let default_footer_element = gen_default_footer_element(&variant, &actions);
+    let inline_footer_element = gen_inline_footer_element(&variant, &actions);
+    let dismiss_button =
+        gen_dismiss_button(show_dismissible, on_close, spritemap, close_aria_label);
+
+    with_html_props(
         html! {
             <div
                 class={classes!(class, "alert", dismissible_class, variant_class, display_class)}
-                role={"alert"}
-                ref={self.node_ref.clone()}
-                onmouseout={start_timer}
-                onmouseover={pause_timer} >
+                aria-live={aria_live}
+                role={role}
+                ref={node_ref}
+                onfocusin={onfocusin}
+                onfocusout={onfocusout}
+                onmouseenter={onmouseenter}
+                onmouseleave={onmouseleave} >
                 <ConditionalContainer {variant}>
                     <ClayContentRow class={"alert-autofit-row"}>
                         {stripe_alert_indicator}
@@ -171,33 +163,7 @@ impl Component for ClayAlert {
                     {dismiss_button}
                 </ConditionalContainer>
             </div>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
-        if first_render {
-            let ClayAlertProps {
-                auto_close,
-                on_close,
-                ..
-            } = ctx.props().clone();
-
-            if auto_close.is_some() && on_close.is_some() {
-                self.start_timer
-                    .as_ref()
-                    .and_then(|cb| Some(cb.emit(MouseEvent::new("mouseover").unwrap())));
-            }
-        }
-
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            html_props.inject(&self.node_ref, &mut self.listeners);
-        }
-    }
-
-    fn destroy(&mut self, _ctx: &Context<Self>) {
-        self.pause_timer
-            .as_ref()
-            .and_then(|cb| Some(cb.emit(MouseEvent::new("mouseout").unwrap())));
-    }
+        },
+        props.html_props.as_deref(),
+    )
 }
@@ -1,16 +1,5 @@
-use gloo_events::EventListener;
-use std::collections::HashMap;
-use yew::{classes, html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
-
-/// A Yew implementation of ClayAlertFooter.
-pub struct ClayAlertFooter {
-    node_ref: NodeRef,
-    /// This vec holds all the EventListeners defined for this component. They will be automatically
-    /// removed when the component is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 /// Props for ClayAlert. For details, check the docs:
 /// <https://clayui.com/docs/components/alert/api.html#alert>
@@ -30,36 +19,22 @@ pub struct ClayAlertFooterProps {
     pub html_props: Option<GlobalProps>,
 }
 
-impl Component for ClayAlertFooter {
-    type Message = ();
-    type Properties = ClayAlertFooterProps;
-
-    fn create(ctx: &Context<Self>) -> Self {
-        Self {
-            node_ref: ctx.props().node_ref.clone(),
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let ClayAlertFooterProps {
-            class,
-            children,
-            node_ref,
-            ..
-        } = ctx.props().clone();
-
+/// A Yew implementation of ClayAlertFooter.
+#[function_component(ClayAlertFooter)]
+pub fn clay_alert_footer(props: &ClayAlertFooterProps) -> Html {
+    let ClayAlertFooterProps {
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    with_html_props(
         html! {
             <div ref={node_ref} class={classes!(class, "alert-footer")}>
                 {children}
             </div>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            html_props.inject(&self.node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.html_props.as_ref(),
+    )
 }
@@ -1,9 +1,14 @@
 mod alert;
 mod footer;
 mod toast_container;
+mod toast_provider;
 mod utils;
 
 pub use alert::*;
 pub use footer::*;
 pub use toast_container::*;
+pub use toast_provider::{
+    use_toast, ToastDispatcher, ToastId, ToastProvider, ToastProviderProps, ToastSpec,
+};
+pub use utils::autoclose_timer::{use_clay_alert_timer, AlertTimerHandle};
 pub use utils::{enums::*, props::*};
@@ -1,21 +1,23 @@
-use gloo_events::EventListener;
-use std::collections::HashMap;
-use yew::{classes, html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
+use super::AriaLive;
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{
+    classes, function_component, html, Children, Classes, ContextProvider, Html, NodeRef,
+    Properties,
+};
 
-/// A Yew implementation of ClayToastContainer.
-pub struct ClayToastContainer {
-    node_ref: NodeRef,
-    /// This vec holds all the EventListeners defined for this component. They will be automatically
-    /// removed when the component is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+/// Set by ClayToastContainer so that the alerts inside of it leave the announcements to the
+/// container.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct ToastContainerContext;
 
 /// Props for ClayToastContainer. For details, check the docs:
 /// <https://clayui.com/docs/components/alert/api.html#alert>
 #[derive(Debug, Properties, PartialEq, Clone, Default)]
 pub struct ClayToastContainerProps {
+    /// How urgently screen readers should announce new toasts.
+    #[prop_or_default]
+    pub aria_live: AriaLive,
+
     #[prop_or_default]
     pub class: Classes,
 
@@ -30,35 +32,33 @@ pub struct ClayToastContainerProps {
     pub html_props: Option<GlobalProps>,
 }
 
-impl Component for ClayToastContainer {
-    type Message = ();
-    type Properties = ClayToastContainerProps;
-
-    fn create(ctx: &Context<Self>) -> Self {
-        Self {
-            node_ref: ctx.props().node_ref.clone(),
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let ClayToastContainerProps {
-            class, children, ..
-        } = ctx.props().clone();
+/// A Yew implementation of ClayToastContainer.
+#[function_component(ClayToastContainer)]
+pub fn clay_toast_container(props: &ClayToastContainerProps) -> Html {
+    let ClayToastContainerProps {
+        aria_live,
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
 
+    // The container is a single live region that only announces the toasts that are
+    // added, so that removals and updates aren't read again.
+    with_html_props(
         html! {
-            <div ref={self.node_ref.clone()} class={classes!(class, "alert-container", "container")}>
-                <div class={"alert-notifications alert-notifications-fixed"}>
-                    {children}
+            <div ref={node_ref} class={classes!(class, "alert-container", "container")}>
+                <div
+                    aria-atomic="false"
+                    aria-live={aria_live.as_ref().to_string()}
+                    aria-relevant="additions"
+                    class={"alert-notifications alert-notifications-fixed"} >
+                    <ContextProvider<ToastContainerContext> context={ToastContainerContext}>
+                        {children}
+                    </ContextProvider<ToastContainerContext>>
                 </div>
             </div>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            html_props.inject(&self.node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.html_props.as_ref(),
+    )
 }
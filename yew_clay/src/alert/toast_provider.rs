@@ -0,0 +1,320 @@
+use super::{AlertDisplayType, AutoCloseValue, ClayAlert, ClayToastContainer};
+use crate::button::ClayButtonGroup;
+use std::cell::Cell;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::rc::Rc;
+use yew::virtual_dom::VChild;
+use yew::{
+    function_component, hook, html, use_context, use_effect_with, use_memo, use_reducer, Callback,
+    Children, Classes, ContextProvider, Html, Properties, Reducible,
+};
+
+/// Identifies a toast pushed through a ToastDispatcher.
+pub type ToastId = usize;
+
+/// Describes a toast to be displayed by the ToastProvider.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct ToastSpec {
+    /// Determines the style of the toast.
+    pub display_type: AlertDisplayType,
+
+    /// The summary of the toast, often is something like 'Error' or 'Info'.
+    pub title: Option<String>,
+
+    /// The content of the toast.
+    pub body: Html,
+
+    /// Flag to indicate the toast should be dismissed automatically. It also accepts a
+    /// duration (in ms) which indicates how long to wait.
+    pub auto_close: Option<AutoCloseValue>,
+
+    /// A ClayButtonGroup with the actions that can be carried out on this toast.
+    pub actions: Option<VChild<ClayButtonGroup>>,
+}
+
+/// Handle to push and dismiss toasts from anywhere under a ToastProvider. It can be obtained
+/// with the `use_toast` hook, or from the context in struct components.
+#[derive(Clone)]
+pub struct ToastDispatcher {
+    next_id: Rc<Cell<ToastId>>,
+    on_push: Callback<(ToastId, ToastSpec)>,
+    on_dismiss: Callback<ToastId>,
+}
+
+impl ToastDispatcher {
+    /// Displays a new toast, or queues it if the maximum amount of toasts is already visible.
+    /// Returns the id that can be used to dismiss it.
+    pub fn push(&self, spec: ToastSpec) -> ToastId {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+        self.on_push.emit((id, spec));
+        id
+    }
+
+    /// Removes a toast, whether it is visible or still waiting in the queue.
+    pub fn dismiss(&self, id: ToastId) {
+        self.on_dismiss.emit(id);
+    }
+}
+
+impl PartialEq for ToastDispatcher {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.next_id, &other.next_id)
+    }
+}
+
+impl Debug for ToastDispatcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToastDispatcher")
+            .field("next_id", &self.next_id.get())
+            .finish()
+    }
+}
+
+/// Props for ToastProvider.
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ToastProviderProps {
+    /// The maximum amount of toasts displayed at the same time.
+    #[prop_or(5)]
+    pub max_visible: usize,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    /// The aria-label of the close button of the toasts.
+    #[prop_or("Close".into())]
+    pub close_aria_label: String,
+
+    /// Classes to add to the ClayToastContainer.
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: Children,
+}
+
+enum ToastAction {
+    Push(ToastId, ToastSpec),
+    Dismiss(ToastId),
+    SetMaxVisible(usize),
+}
+
+/// The toasts of a ToastProvider, split between the visible ones and the ones waiting for a
+/// free spot.
+#[derive(Clone)]
+struct ToastQueue {
+    max_visible: usize,
+    visible: Vec<(ToastId, ToastSpec)>,
+    queue: VecDeque<(ToastId, ToastSpec)>,
+}
+
+impl ToastQueue {
+    fn new(max_visible: usize) -> Self {
+        Self {
+            max_visible,
+            visible: Vec::new(),
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Moves toasts from the queue to the visible list until the limit is reached.
+    fn fill_visible(&mut self) {
+        while self.visible.len() < self.max_visible {
+            match self.queue.pop_front() {
+                Some(toast) => self.visible.push(toast),
+                None => break,
+            }
+        }
+    }
+}
+
+impl Reducible for ToastQueue {
+    type Action = ToastAction;
+
+    fn reduce(self: Rc<Self>, action: Self::Action) -> Rc<Self> {
+        let mut toasts = (*self).clone();
+        match action {
+            ToastAction::Push(id, spec) => {
+                toasts.queue.push_back((id, spec));
+            }
+            ToastAction::Dismiss(id) => {
+                let visible_len = toasts.visible.len();
+                toasts.visible.retain(|(toast_id, _)| *toast_id != id);
+                if toasts.visible.len() == visible_len {
+                    toasts.queue.retain(|(toast_id, _)| *toast_id != id);
+                }
+            }
+            ToastAction::SetMaxVisible(max_visible) => {
+                toasts.max_visible = max_visible;
+            }
+        }
+        toasts.fill_visible();
+        Rc::new(toasts)
+    }
+}
+
+/// Provides a ToastDispatcher to its children and renders the toasts pushed through it into a
+/// ClayToastContainer. Toasts that exceed `max_visible` wait in a queue and are displayed in
+/// the order they were pushed as the visible ones are dismissed.
+#[function_component(ToastProvider)]
+pub fn toast_provider(props: &ToastProviderProps) -> Html {
+    let toasts = use_reducer(|| ToastQueue::new(props.max_visible));
+    let dispatcher = {
+        let toasts = toasts.dispatcher();
+        use_memo((), move |_| {
+            let on_push = toasts.clone();
+            ToastDispatcher {
+                next_id: Rc::new(Cell::new(0)),
+                on_push: Callback::from(move |(id, spec)| {
+                    on_push.dispatch(ToastAction::Push(id, spec))
+                }),
+                on_dismiss: Callback::from(move |id| toasts.dispatch(ToastAction::Dismiss(id))),
+            }
+        })
+    };
+
+    {
+        let toasts = toasts.dispatcher();
+        use_effect_with(props.max_visible, move |max_visible| {
+            toasts.dispatch(ToastAction::SetMaxVisible(*max_visible));
+            || ()
+        });
+    }
+
+    let ToastProviderProps {
+        spritemap,
+        close_aria_label,
+        class,
+        children,
+        ..
+    } = props.clone();
+
+    let toast_alerts = toasts
+        .visible
+        .iter()
+        .map(|(id, spec)| {
+            let id = *id;
+            let ToastSpec {
+                display_type,
+                title,
+                body,
+                auto_close,
+                actions,
+            } = spec.clone();
+
+            html! {
+                <ClayAlert
+                    key={id}
+                    {actions}
+                    {auto_close}
+                    close_aria_label={close_aria_label.clone()}
+                    {display_type}
+                    on_close={dispatcher.on_dismiss.reform(move |_| id)}
+                    {spritemap}
+                    {title} >
+                    {body}
+                </ClayAlert>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <ContextProvider<ToastDispatcher> context={(*dispatcher).clone()}>
+            {children}
+            <ClayToastContainer {class}>
+                {toast_alerts}
+            </ClayToastContainer>
+        </ContextProvider<ToastDispatcher>>
+    }
+}
+
+/// Hook to access the ToastDispatcher of the nearest ToastProvider from a function component.
+#[hook]
+pub fn use_toast() -> ToastDispatcher {
+    use_context::<ToastDispatcher>().expect("use_toast to be called inside of a ToastProvider")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reduce(toasts: ToastQueue, actions: Vec<ToastAction>) -> Rc<ToastQueue> {
+        actions
+            .into_iter()
+            .fold(Rc::new(toasts), |toasts, action| toasts.reduce(action))
+    }
+
+    fn push(id: ToastId) -> ToastAction {
+        ToastAction::Push(id, ToastSpec::default())
+    }
+
+    fn ids(toasts: &[(ToastId, ToastSpec)]) -> Vec<ToastId> {
+        toasts.iter().map(|(id, _)| *id).collect()
+    }
+
+    fn queued_ids(toasts: &ToastQueue) -> Vec<ToastId> {
+        toasts.queue.iter().map(|(id, _)| *id).collect()
+    }
+
+    #[test]
+    fn queues_the_toasts_over_the_limit() {
+        let toasts = reduce(ToastQueue::new(2), vec![push(0), push(1), push(2)]);
+
+        assert_eq!(ids(&toasts.visible), vec![0, 1]);
+        assert_eq!(queued_ids(&toasts), vec![2]);
+    }
+
+    #[test]
+    fn shows_the_next_queued_toast_when_a_visible_one_is_dismissed() {
+        let toasts = reduce(
+            ToastQueue::new(2),
+            vec![push(0), push(1), push(2), push(3), ToastAction::Dismiss(0)],
+        );
+
+        assert_eq!(ids(&toasts.visible), vec![1, 2]);
+        assert_eq!(queued_ids(&toasts), vec![3]);
+    }
+
+    #[test]
+    fn dismisses_queued_toasts() {
+        let toasts = reduce(
+            ToastQueue::new(1),
+            vec![push(0), push(1), push(2), ToastAction::Dismiss(1)],
+        );
+
+        assert_eq!(ids(&toasts.visible), vec![0]);
+        assert_eq!(queued_ids(&toasts), vec![2]);
+    }
+
+    #[test]
+    fn ignores_unknown_ids() {
+        let toasts = reduce(ToastQueue::new(1), vec![push(0), ToastAction::Dismiss(5)]);
+
+        assert_eq!(ids(&toasts.visible), vec![0]);
+        assert!(toasts.queue.is_empty());
+    }
+
+    #[test]
+    fn shows_queued_toasts_when_the_limit_is_raised() {
+        let toasts = reduce(
+            ToastQueue::new(1),
+            vec![push(0), push(1), push(2), ToastAction::SetMaxVisible(2)],
+        );
+
+        assert_eq!(ids(&toasts.visible), vec![0, 1]);
+        assert_eq!(queued_ids(&toasts), vec![2]);
+    }
+
+    #[test]
+    fn keeps_the_visible_toasts_when_the_limit_is_lowered() {
+        let toasts = reduce(
+            ToastQueue::new(2),
+            vec![push(0), push(1), ToastAction::SetMaxVisible(1), push(2)],
+        );
+
+        assert_eq!(ids(&toasts.visible), vec![0, 1]);
+        assert_eq!(queued_ids(&toasts), vec![2]);
+    }
+}
@@ -1,92 +1,255 @@
-use wasm_bindgen::{prelude::Closure, JsCast};
-use web_sys::MouseEvent;
-use yew::{Callback, Context};
+use gloo_timers::callback::{Interval, Timeout};
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use web_sys::{Element, Node};
+use yew::{hook, use_effect, use_mut_ref, Callback, NodeRef};
 
-use crate::alert::{AutoCloseValue, ClayAlert, Msg};
+use crate::alert::AutoCloseValue;
 
-pub fn close(ctx: &Context<ClayAlert>) {
-    ctx.props()
-        .on_close
-        .as_ref()
-        .and_then(|cb| Some(cb.emit(MouseEvent::new("mousedown").unwrap())));
+/// The time to wait when `auto_close` is `true`.
+const DEFAULT_TIME_TO_CLOSE: u32 = 10_000;
+/// How often `on_progress` is called while the timer is running, in milliseconds.
+const PROGRESS_INTERVAL: u32 = 100;
+
+pub fn get_time_to_close(auto_close: &Option<AutoCloseValue>) -> Option<u32> {
+    match auto_close {
+        Some(AutoCloseValue::Boolean(true)) => Some(DEFAULT_TIME_TO_CLOSE),
+        Some(AutoCloseValue::Number(val)) => Some(*val),
+        _ => None,
+    }
+}
+
+/// A countdown that can be paused and resumed. The pending timeout is cancelled when the timer
+/// is paused or dropped.
+pub struct AutoCloseTimer {
+    duration: u32,
+    remaining: u32,
+    started_time: Option<f64>,
+    timeout: Option<Timeout>,
 }
 
-pub fn pause(
-    time_id: Option<i32>,
-    time_to_close: &Option<u32>,
-    started_time: &Option<f64>,
-) -> Option<u32> {
-    if time_id.is_some() && time_to_close.is_some() {
-        web_sys::window()
-            .unwrap()
-            .clear_timeout_with_handle(time_id.unwrap());
-
-        let time_to_close = time_to_close.unwrap();
-        let started_time = started_time.unwrap_or(0.0);
-        let time_to_close = time_to_close
-            .checked_sub((js_sys::Date::now() - started_time) as u32)
-            .unwrap_or(0);
-
-        if time_to_close > 0 {
-            return Some(time_to_close);
+impl AutoCloseTimer {
+    pub fn new(duration: u32) -> Self {
+        Self {
+            duration,
+            remaining: duration,
+            started_time: None,
+            timeout: None,
+        }
+    }
+
+    pub fn get_duration(&self) -> u32 {
+        self.duration
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.timeout.is_some()
+    }
+
+    /// Starts or resumes the countdown. The callback is called once the remaining time runs
+    /// out, right away if it already has.
+    pub fn start<F: FnOnce() + 'static>(&mut self, on_timeout: F) {
+        if self.is_running() {
+            return;
+        }
+
+        self.started_time = Some(js_sys::Date::now());
+        self.timeout = Some(Timeout::new(self.remaining, on_timeout));
+    }
+
+    /// Stops the countdown, keeping the time that was left so that it can be resumed.
+    pub fn pause(&mut self) {
+        self.remaining = self.get_remaining();
+        self.started_time = None;
+        self.timeout = None;
+    }
+
+    /// The time left before the timeout, in milliseconds.
+    pub fn get_remaining(&self) -> u32 {
+        match self.started_time {
+            Some(started_time) => {
+                let elapsed = (js_sys::Date::now() - started_time).max(0.0) as u32;
+                self.remaining.saturating_sub(elapsed)
+            }
+            None => self.remaining,
         }
     }
-    None
+
+    /// The fraction of the time that is left, going from 1.0 down to 0.0.
+    pub fn get_progress(&self) -> f64 {
+        if self.duration == 0 {
+            return 0.0;
+        }
+        f64::from(self.get_remaining()) / f64::from(self.duration)
+    }
 }
 
-pub fn start(
-    time_to_close: &Option<u32>,
-    ctx: &Context<ClayAlert>,
-) -> Option<(Option<f64>, Option<i32>)> {
-    if let Some(time_to_close) = time_to_close {
-        let started_time = Some(js_sys::Date::now());
-        let link = ctx.link().clone();
-        let callback = Closure::<dyn Fn()>::new(move || link.send_message(Msg::Close));
+/// Lets an alert report to the timer of `use_clay_alert_timer` when the pointer or the focus
+/// enters or leaves it, so that the countdown is paused while the user interacts with it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertTimerHandle {
+    pub set_hovered: Callback<bool>,
+    pub set_focus_within: Callback<bool>,
+}
 
-        let time_id = web_sys::window()
-            .unwrap()
-            .set_timeout_with_callback_and_timeout_and_arguments_0(
-                callback.as_ref().unchecked_ref(),
-                *time_to_close as i32,
-            )
-            .unwrap();
+#[derive(Default)]
+struct AlertTimerState {
+    timer: Option<AutoCloseTimer>,
+    /// Reports the progress of the timer while it is running, if `on_progress` is set.
+    progress_interval: Option<Interval>,
+    hovered: bool,
+    focus_within: bool,
+    initialized: bool,
+    on_close: Option<Callback<()>>,
+    on_progress: Option<Callback<f64>>,
+}
 
-        callback.forget();
+impl AlertTimerState {
+    fn report_progress(&self) {
+        if let (Some(on_progress), Some(timer)) = (&self.on_progress, &self.timer) {
+            on_progress.emit(timer.get_progress());
+        }
+    }
+
+    /// Takes the props of the latest render. The timer restarts from scratch when the duration
+    /// changes.
+    fn update(
+        &mut self,
+        duration: Option<u32>,
+        on_close: Option<Callback<()>>,
+        on_progress: Option<Callback<f64>>,
+    ) {
+        let current = self.timer.as_ref().map(AutoCloseTimer::get_duration);
+        if duration != current {
+            self.timer = duration.map(AutoCloseTimer::new);
+            self.progress_interval = None;
+        }
+        self.on_close = on_close;
+        self.on_progress = on_progress;
+    }
+
+    /// Reads whether the pointer or the focus is already inside of the alert. Markup that is
+    /// hydrated can be hovered or focused before its listeners are attached, in which case
+    /// the enter events never fire.
+    fn read_interaction_state(&mut self, node_ref: &NodeRef) {
+        let element = match node_ref.cast::<Element>() {
+            Some(element) => element,
+            None => return,
+        };
 
-        return Some((started_time, Some(time_id)));
+        self.hovered = element.matches(":hover").unwrap_or(false);
+        self.focus_within = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.active_element())
+            .map(Node::from)
+            .is_some_and(|active| element.contains(Some(&active)));
     }
-    None
 }
 
-pub fn get_time_to_close(auto_close: Option<AutoCloseValue>) -> Option<u32> {
-    if let Some(auto_close) = auto_close {
-        match auto_close {
-            AutoCloseValue::Boolean(val) => match val {
-                true => Some(10_000),
-                false => None,
-            },
-            AutoCloseValue::Number(val) => Some(val),
+/// Starts the timer when nothing is holding it, and pauses it otherwise. The progress is
+/// reported while the timer runs, including when `on_progress` is only set after it started.
+fn sync_timer(state: &Rc<RefCell<AlertTimerState>>) {
+    let mut guard = state.borrow_mut();
+    let state_ref = &mut *guard;
+    let paused = state_ref.hovered || state_ref.focus_within || state_ref.on_close.is_none();
+    let has_progress = state_ref.on_progress.is_some();
+
+    let timer = match &mut state_ref.timer {
+        Some(timer) => timer,
+        None => {
+            state_ref.progress_interval = None;
+            return;
+        }
+    };
+
+    let was_running = timer.is_running();
+    if paused {
+        if was_running {
+            timer.pause();
         }
-    } else {
-        None
+    } else if !was_running {
+        let weak_state = Rc::downgrade(state);
+        timer.start(move || close_alert(&weak_state));
+    }
+
+    if !timer.is_running() || !has_progress {
+        state_ref.progress_interval = None;
+    } else if state_ref.progress_interval.is_none() {
+        let weak_state = Rc::downgrade(state);
+        state_ref.progress_interval = Some(Interval::new(PROGRESS_INTERVAL, move || {
+            if let Some(state) = weak_state.upgrade() {
+                state.borrow().report_progress();
+            }
+        }));
+    }
+
+    if paused && was_running {
+        state_ref.report_progress();
+    }
+}
+
+fn close_alert(state: &Weak<RefCell<AlertTimerState>>) {
+    let on_close = match state.upgrade() {
+        Some(state) => {
+            let mut state = state.borrow_mut();
+            state.timer = None;
+            state.progress_interval = None;
+            state.on_close.clone()
+        }
+        None => return,
+    };
+    if let Some(on_close) = on_close {
+        on_close.emit(());
     }
 }
 
-pub fn initialize_autoclose_callbacks(
-    ctx: &Context<ClayAlert>,
-    time_to_close: Option<u32>,
-) -> (Option<Callback<MouseEvent>>, Option<Callback<MouseEvent>>) {
-    if time_to_close.is_some() {
-        let pause_timer = ctx
-            .link()
-            .callback(move |_event: MouseEvent| Msg::PauseTimer);
-
-        let start_timer = ctx
-            .link()
-            .callback(move |_event: MouseEvent| Msg::StartTimer);
-
-        (Some(pause_timer), Some(start_timer))
-    } else {
-        (None, None)
+/// Hook that dismisses an alert once the time of `auto_close` runs out, calling `on_close`.
+/// The countdown is paused while the alert is hovered or focused and resumes where it left
+/// off. `on_progress` receives the fraction of the time that is left while it runs.
+///
+/// The timer is only started after rendering, so that it doesn't run during server rendering
+/// and only starts counting once the alert is hydrated.
+#[hook]
+pub fn use_clay_alert_timer(
+    node_ref: &NodeRef,
+    auto_close: &Option<AutoCloseValue>,
+    on_close: Option<Callback<()>>,
+    on_progress: Option<Callback<f64>>,
+) -> AlertTimerHandle {
+    let state = use_mut_ref(AlertTimerState::default);
+
+    {
+        let state = state.clone();
+        let node_ref = node_ref.clone();
+        let duration = get_time_to_close(auto_close);
+        use_effect(move || {
+            {
+                let mut state = state.borrow_mut();
+                state.update(duration, on_close, on_progress);
+                if !state.initialized {
+                    state.initialized = true;
+                    state.read_interaction_state(&node_ref);
+                }
+            }
+            sync_timer(&state);
+            || ()
+        });
+    }
+
+    let set_hovered = {
+        let state = state.clone();
+        Callback::from(move |hovered| {
+            state.borrow_mut().hovered = hovered;
+            sync_timer(&state);
+        })
+    };
+    let set_focus_within = Callback::from(move |focus_within| {
+        state.borrow_mut().focus_within = focus_within;
+        sync_timer(&state);
+    });
+
+    AlertTimerHandle {
+        set_hovered,
+        set_focus_within,
     }
 }
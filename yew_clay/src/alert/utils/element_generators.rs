@@ -3,7 +3,7 @@ use crate::alert::AlertDisplayType;
 use crate::alert::AlertVariant;
 use crate::alert::ClayAlertFooter;
 use crate::button::ClayButtonGroup;
-use crate::icon::ClayIcon;
+use crate::icon::{ClayIcon, ClaySymbol};
 use crate::layout::ClayContentCol;
 use crate::layout::ClayContentSection;
 use web_sys::MouseEvent;
@@ -20,7 +20,7 @@ pub fn gen_stripe_alert(
         <ClayContentCol>
             <ClayContentSection>
                 <AlertIndicator
-                    spritemap={spritemap.unwrap_or_default()}
+                    {spritemap}
                     display_type={display_type.clone()} />
             </ClayContentSection>
         </ClayContentCol>
@@ -45,7 +45,7 @@ pub fn gen_default_alert(
         match variant {
             AlertVariant::Stripe => html! {},
             _ => html! {<AlertIndicator
-                spritemap={spritemap.unwrap_or_default()}
+                {spritemap}
                 display_type={display_type.clone()} />
             },
         }
@@ -94,13 +94,15 @@ pub fn gen_inline_footer_element(
 
 pub fn gen_dismiss_button(
     show_dismissible: bool,
-    on_close: Option<Callback<MouseEvent>>,
+    on_close: Option<Callback<()>>,
     spritemap: Option<&'static str>,
+    aria_label: String,
 ) -> Html {
     if show_dismissible {
+        let on_close = on_close.map(|on_close| on_close.reform(|_: MouseEvent| ()));
         html! {
-            <button aria-label={"Close"} class={"close"} onclick={on_close} type="button">
-                <ClayIcon spritemap={spritemap.unwrap_or_default()} symbol={"times"} />
+            <button aria-label={aria_label} class={"close"} onclick={on_close} type="button">
+                <ClayIcon {spritemap} symbol={ClaySymbol::Times} />
             </button>
         }
     } else {
@@ -16,6 +16,17 @@ pub enum AlertDisplayType {
     Warning,
 }
 
+/// The politeness of an ARIA live region, which indicates how urgently screen readers should
+/// announce its updates.
+#[derive(AsRefStr, Debug, PartialEq, Clone, Copy, Default)]
+#[strum(serialize_all = "lowercase")]
+pub enum AriaLive {
+    Off,
+    #[default]
+    Polite,
+    Assertive,
+}
+
 #[derive(AsRefStr, Debug, PartialEq, Clone)]
 #[strum(serialize_all = "lowercase")]
 pub enum AlertVariant {
@@ -1,9 +1,8 @@
-use super::enums::{AlertDisplayType, AlertVariant, AutoCloseValue};
+use super::enums::{AlertDisplayType, AlertVariant, AriaLive, AutoCloseValue};
 use crate::button::ClayButtonGroup;
+use crate::html_props::GlobalProps;
 use std::rc::Rc;
-use web_sys::MouseEvent;
 use yew::{virtual_dom::VChild, Callback, Children, Classes, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
 
 /// Props for ClayAlert. For details, check the docs:
 /// <https://clayui.com/docs/components/alert/api.html#alert>
@@ -16,18 +15,36 @@ pub struct ClayAlertProps {
 
     /// Flag to indicate alert should automatically call `onClose`. It also
     /// accepts a duration (in ms) which indicates how long to wait. If `true`
-    /// is passed in, the timeout will be 10000ms.
+    /// is passed in, the timeout will be 10000ms. The countdown is paused while
+    /// the alert is hovered or has focus within, and restarts when this changes.
     #[prop_or_default]
     pub auto_close: Option<AutoCloseValue>,
 
-    /// Callback function for when the 'x' icon is clicked.
+    /// Callback function for when the 'x' icon is clicked, or when the `auto_close` time runs
+    /// out.
     #[prop_or_default]
-    pub on_close: Option<Callback<MouseEvent>>,
+    pub on_close: Option<Callback<()>>,
+
+    /// Callback that periodically receives the fraction of the `auto_close` time that is
+    /// left, going from 1.0 down to 0.0. Useful to draw a countdown bar.
+    #[prop_or_default]
+    pub on_progress: Option<Callback<f64>>,
 
     /// Determines the style of the alert.
     #[prop_or_default]
     pub display_type: AlertDisplayType,
 
+    /// Overrides how urgently screen readers announce the alert. By default, Danger and
+    /// Warning alerts are assertive (`role="alert"`), while Info and Success alerts are
+    /// polite (`role="status"`). Alerts inside of a ClayToastContainer are announced by the
+    /// container instead.
+    #[prop_or_default]
+    pub aria_live: Option<AriaLive>,
+
+    /// The aria-label of the close button.
+    #[prop_or("Close".into())]
+    pub close_aria_label: String,
+
     /// Flag to indicate if close icon should be show. This prop is used in
     /// conjunction with the `onClose`prop;
     #[prop_or(false)]
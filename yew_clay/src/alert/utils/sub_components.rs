@@ -1,6 +1,6 @@
 use super::enums::{AlertDisplayType, AlertVariant};
-use crate::icon::ClayIcon;
-use yew::{function_component, html, Children, Properties};
+use crate::icon::{ClayIcon, ClaySymbol};
+use yew::{function_component, html, Children, Html, Properties};
 
 #[derive(Properties, PartialEq)]
 pub struct CondCompProps {
@@ -10,13 +10,8 @@ pub struct CondCompProps {
 
 #[function_component(ConditionalContainer)]
 pub fn contiditional_container(props: &CondCompProps) -> Html {
-    if let Some(variant) = props.variant.clone() {
-        match variant {
-            AlertVariant::Stripe => {
-                return html! {<div class={"container"}>{for props.children.iter()}</div> }
-            }
-            _ => (),
-        }
+    if let Some(AlertVariant::Stripe) = props.variant {
+        return html! {<div class={"container"}>{for props.children.iter()}</div> };
     }
 
     html! {<>{for props.children.iter()}</>}
@@ -24,7 +19,7 @@ pub fn contiditional_container(props: &CondCompProps) -> Html {
 
 #[derive(Properties, PartialEq)]
 pub struct AlertIndicatorProps {
-    pub spritemap: &'static str,
+    pub spritemap: Option<&'static str>,
     pub display_type: AlertDisplayType,
 }
 
@@ -32,10 +27,10 @@ pub struct AlertIndicatorProps {
 pub fn alert_indicator(props: &AlertIndicatorProps) -> Html {
     let spritemap = props.spritemap;
     let symbol = match &props.display_type {
-        AlertDisplayType::Danger => "exclamation-full",
-        AlertDisplayType::Info => "info-circle",
-        AlertDisplayType::Success => "check-circle-full",
-        AlertDisplayType::Warning => "warning-full",
+        AlertDisplayType::Danger => ClaySymbol::ExclamationFull,
+        AlertDisplayType::Info => ClaySymbol::InfoCircle,
+        AlertDisplayType::Success => ClaySymbol::CheckCircleFull,
+        AlertDisplayType::Warning => ClaySymbol::WarningFull,
     };
     html! {
         <span class={"alert-indicator"}>
@@ -0,0 +1,569 @@
+use super::types::{AutocompleteFetcher, AutocompleteFilter};
+use crate::dropdown::{ClayDropDownItem, ClayDropDownItemList};
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::provider::ClayProviderContext;
+use crate::shared::keys::{ARROW_DOWN, ARROW_UP, ENTER, ESCAPE, TAB};
+use gloo_events::EventListener;
+use gloo_timers::callback::Timeout;
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlInputElement, InputEvent, KeyboardEvent, MouseEvent, Node};
+use yew::platform::spawn_local;
+use yew::{
+    classes, function_component, html, use_context, use_effect, use_force_update, use_mut_ref,
+    Callback, Classes, Html, NodeRef, Properties, UseForceUpdateHandle,
+};
+
+/// The state of a ClayAutocomplete, kept between renders.
+struct AutocompleteState {
+    /// The props of the latest render.
+    props: ClayAutocompleteProps,
+    provider: Option<ClayProviderContext>,
+    force_update: UseForceUpdateHandle,
+    /// Holds the listener that is only needed while the menu is open.
+    click_outside_listener: Option<EventListener>,
+    internal_value: String,
+    active: bool,
+    active_index: Option<usize>,
+    fetched_items: Vec<String>,
+    fetching: bool,
+    /// The pending request, if the user is still typing. Dropping it cancels the request.
+    debounce_timeout: Option<Timeout>,
+    /// The value to load once the message that requested it has been handled.
+    pending_fetch: Option<String>,
+    /// The callbacks to emit once the message that triggered them has been handled, since
+    /// they may call back into the autocomplete.
+    effects: Vec<Box<dyn FnOnce()>>,
+}
+
+type StateRef = Weak<RefCell<AutocompleteState>>;
+
+/// Props for ClayAutocomplete. For details, check the docs:
+/// <https://clayui.com/docs/components/autocomplete/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayAutocompleteProps {
+    /// The value of the input (controlled).
+    #[prop_or_default]
+    pub value: Option<String>,
+
+    /// Property to set the initial value of the input (uncontrolled).
+    #[prop_or_default]
+    pub default_value: String,
+
+    /// Callback for when the value of the input changes, either because the user typed
+    /// something or because an item was selected.
+    #[prop_or_default]
+    pub on_value_change: Option<Callback<String>>,
+
+    /// Callback for when an item is selected.
+    #[prop_or_default]
+    pub on_select: Option<Callback<String>>,
+
+    /// The items to be suggested. They are ignored when a `fetcher` is set.
+    #[prop_or_default]
+    pub items: Vec<String>,
+
+    /// Decides which items match the value of the input. Defaults to a case insensitive
+    /// `contains`. Items loaded by the `fetcher` are only filtered when this is set.
+    #[prop_or_default]
+    pub filter: Option<AutocompleteFilter>,
+
+    /// Loads the items for the value of the input. The requests go through the DataClient of
+    /// the nearest ClayProvider, so results are cached and concurrent requests for the same
+    /// value are only sent once.
+    #[prop_or_default]
+    pub fetcher: Option<AutocompleteFetcher>,
+
+    /// The prefix of the keys used to cache the results of the `fetcher`.
+    #[prop_or("autocomplete".into())]
+    pub cache_key: String,
+
+    /// The time in milliseconds to wait after the user stops typing before calling the
+    /// `fetcher`.
+    #[prop_or(300)]
+    pub debounce: u32,
+
+    /// Flag to show the loading indicator. It is also shown while the `fetcher` is running.
+    #[prop_or(false)]
+    pub loading: bool,
+
+    /// Flag to disable the input.
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    /// The placeholder of the input.
+    #[prop_or_default]
+    pub placeholder: Option<String>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A reference to the input element.
+    #[prop_or_default]
+    pub input_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+enum Msg {
+    InputChange(String),
+    MoveActive(bool),
+    SelectActive,
+    Select(String),
+    SetActive(bool),
+    Fetch(String),
+    Loaded(String, Vec<String>),
+}
+
+impl ClayAutocomplete {
+    const AUTOCOMPLETE: &'static str = "autocomplete";
+    const INPUT_GROUP: &'static str = "input-group";
+    const INPUT_GROUP_ITEM: &'static str = "input-group-item";
+    const INPUT_GROUP_INSET: &'static str = "input-group-inset";
+    const INPUT_GROUP_INSET_AFTER: &'static str = "input-group-inset-after";
+    const INPUT_GROUP_INSET_ITEM: &'static str = "input-group-inset-item";
+    const INPUT_GROUP_INSET_ITEM_AFTER: &'static str = "input-group-inset-item-after";
+    const FORM_CONTROL: &'static str = "form-control";
+    const DROPDOWN_MENU: &'static str = "dropdown-menu";
+    const AUTOCOMPLETE_DROPDOWN_MENU: &'static str = "autocomplete-dropdown-menu";
+    const SHOW: &'static str = "show";
+
+    fn get_bool_class(boolean: bool, class: &'static str) -> Option<&'static str> {
+        if boolean {
+            Some(class)
+        } else {
+            None
+        }
+    }
+
+    /// Highlights the part of the item that matches the value of the input.
+    fn gen_item_content(item: &str, value: &str) -> Html {
+        let lowercase_item = item.to_lowercase();
+        // Lowercasing may change the length of some characters, in which case the offsets of
+        // the match can't be mapped back to the item.
+        let position = if value.is_empty() || lowercase_item.len() != item.len() {
+            None
+        } else {
+            lowercase_item.find(&value.to_lowercase())
+        };
+
+        match position {
+            Some(start) => {
+                let end = start + value.len();
+                html! {
+                    <>
+                        {&item[..start]}
+                        <strong>{&item[start..end]}</strong>
+                        {&item[end..]}
+                    </>
+                }
+            }
+            None => html! { {item} },
+        }
+    }
+
+    fn create_click_outside_listener(node_ref: NodeRef, state: StateRef) -> Option<EventListener> {
+        let document = web_sys::window().and_then(|window| window.document())?;
+
+        Some(EventListener::new(&document, "mousedown", move |event| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            let inside = match (node_ref.cast::<Node>(), target) {
+                (Some(node), Some(target)) => node.contains(Some(&target)),
+                _ => false,
+            };
+            if !inside {
+                AutocompleteState::send(&state, Msg::SetActive(false));
+            }
+        }))
+    }
+}
+
+impl AutocompleteState {
+    /// Handles a message the way the update of a struct component would, rendering the
+    /// component again if needed. Callbacks are emitted and requests are started after the
+    /// state is released, since they may send messages right away.
+    fn send(state: &StateRef, msg: Msg) {
+        let state_rc = match state.upgrade() {
+            Some(state_rc) => state_rc,
+            None => return,
+        };
+
+        let (should_render, pending_fetch, effects) = {
+            let mut state_mut = state_rc.borrow_mut();
+            let should_render = state_mut.update(state, msg);
+            (
+                should_render,
+                state_mut.pending_fetch.take(),
+                std::mem::take(&mut state_mut.effects),
+            )
+        };
+
+        for effect in effects {
+            effect();
+        }
+
+        if should_render {
+            state_rc.borrow().force_update.force_update();
+        }
+
+        if let Some(value) = pending_fetch {
+            let (fetcher, provider, cache_key) = {
+                let state_ref = state_rc.borrow();
+                (
+                    state_ref.props.fetcher.clone(),
+                    state_ref.provider.clone(),
+                    state_ref.props.cache_key.clone(),
+                )
+            };
+            if let Some(fetcher) = fetcher {
+                Self::fetch(state.clone(), fetcher, provider, cache_key, value);
+            }
+        }
+    }
+
+    fn get_value(&self) -> &str {
+        self.props.value.as_deref().unwrap_or(&self.internal_value)
+    }
+
+    fn is_loading(&self) -> bool {
+        self.props.loading || self.fetching
+    }
+
+    /// Returns the items that should be suggested for the current value.
+    fn get_items(&self) -> Vec<String> {
+        let ClayAutocompleteProps {
+            items,
+            filter,
+            fetcher,
+            ..
+        } = &self.props;
+
+        let value = self.get_value();
+        let (items, filter) = match fetcher {
+            Some(_) => (&self.fetched_items, filter.clone()),
+            None => (items, Some(filter.clone().unwrap_or_default())),
+        };
+
+        match filter {
+            Some(filter) => items
+                .iter()
+                .filter(|item| filter.matches(item, value))
+                .cloned()
+                .collect(),
+            None => items.clone(),
+        }
+    }
+
+    /// Emits the value to the callback once the state is no longer borrowed.
+    fn defer_emit<T: 'static>(&mut self, callback: Option<Callback<T>>, value: T) {
+        if let Some(callback) = callback {
+            self.effects.push(Box::new(move || callback.emit(value)));
+        }
+    }
+
+    fn set_value(&mut self, value: String) {
+        self.defer_emit(self.props.on_value_change.clone(), value.clone());
+        self.internal_value = value;
+    }
+
+    fn schedule_fetch(&mut self, state: &StateRef, value: String) {
+        if self.props.fetcher.is_none() {
+            return;
+        }
+
+        let state = state.clone();
+        self.debounce_timeout = Some(Timeout::new(self.props.debounce, move || {
+            Self::send(&state, Msg::Fetch(value));
+        }));
+    }
+
+    fn fetch(
+        state: StateRef,
+        fetcher: AutocompleteFetcher,
+        provider: Option<ClayProviderContext>,
+        cache_key: String,
+        value: String,
+    ) {
+        let query = value.clone();
+
+        match provider {
+            Some(context) => {
+                let key = format!("{}:{}", cache_key, value);
+                let callback = Callback::from(move |items: Rc<dyn Any>| {
+                    let items = items
+                        .downcast::<Vec<String>>()
+                        .map(|items| items.as_ref().clone())
+                        .unwrap_or_default();
+                    Self::send(&state, Msg::Loaded(query.clone(), items));
+                });
+                context.client.fetch(
+                    key,
+                    move || async move { Rc::new(fetcher.fetch(value).await) as Rc<dyn Any> },
+                    callback,
+                );
+            }
+            None => {
+                spawn_local(async move {
+                    let items = fetcher.fetch(value).await;
+                    Self::send(&state, Msg::Loaded(query, items));
+                });
+            }
+        }
+    }
+
+    fn update(&mut self, state: &StateRef, msg: Msg) -> bool {
+        match msg {
+            Msg::InputChange(value) => {
+                self.active = true;
+                self.active_index = None;
+                self.set_value(value.clone());
+                self.schedule_fetch(state, value);
+                true
+            }
+            Msg::MoveActive(forward) => {
+                let count = self.get_items().len();
+                if count == 0 {
+                    return false;
+                }
+
+                self.active = true;
+                self.active_index = Some(match (self.active_index, forward) {
+                    (None, true) => 0,
+                    (None, false) => count - 1,
+                    (Some(index), true) => (index + 1) % count,
+                    (Some(index), false) => (index + count - 1) % count,
+                });
+                true
+            }
+            Msg::SelectActive => {
+                let item = self
+                    .active_index
+                    .and_then(|index| self.get_items().get(index).cloned());
+                match item {
+                    Some(item) => self.update(state, Msg::Select(item)),
+                    None => false,
+                }
+            }
+            Msg::Select(item) => {
+                self.active = false;
+                self.active_index = None;
+                self.debounce_timeout = None;
+                self.defer_emit(self.props.on_select.clone(), item.clone());
+                self.set_value(item);
+                true
+            }
+            Msg::SetActive(active) => {
+                if self.active == active {
+                    return false;
+                }
+                self.active = active;
+                self.active_index = None;
+                true
+            }
+            Msg::Fetch(value) => {
+                self.debounce_timeout = None;
+                if value != self.get_value() || self.props.fetcher.is_none() {
+                    return false;
+                }
+                self.fetching = true;
+                self.pending_fetch = Some(value);
+                true
+            }
+            Msg::Loaded(value, items) => {
+                // Responses for values that were already replaced are discarded.
+                if value != self.get_value() {
+                    return false;
+                }
+                self.fetching = false;
+                self.fetched_items = items;
+                self.active_index = None;
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, props: &ClayAutocompleteProps) {
+        self.props = props.clone();
+        if props.fetcher.is_none() {
+            self.fetching = false;
+            self.fetched_items.clear();
+        }
+    }
+}
+
+/// A Yew implementation of ClayAutocomplete. For more info about ClayAutocomplete, check the
+/// documentation: <https://clayui.com/docs/components/autocomplete.html>
+#[function_component(ClayAutocomplete)]
+pub fn clay_autocomplete(props: &ClayAutocompleteProps) -> Html {
+    let provider = use_context::<ClayProviderContext>();
+    let force_update = use_force_update();
+    let state = use_mut_ref(|| AutocompleteState {
+        props: props.clone(),
+        provider: None,
+        force_update,
+        click_outside_listener: None,
+        internal_value: props.default_value.clone(),
+        active: false,
+        active_index: None,
+        fetched_items: Vec::new(),
+        fetching: false,
+        debounce_timeout: None,
+        pending_fetch: None,
+        effects: Vec::new(),
+    });
+
+    {
+        let mut state_mut = state.borrow_mut();
+        if state_mut.props != *props {
+            state_mut.changed(props);
+        }
+        state_mut.provider = provider;
+    }
+
+    let state_ref = Rc::downgrade(&state);
+    let dispatch = {
+        let state_ref = state_ref.clone();
+        Callback::from(move |msg: Msg| AutocompleteState::send(&state_ref, msg))
+    };
+
+    {
+        let state = state.clone();
+        let node_ref = props.node_ref.clone();
+        use_effect(move || {
+            let mut state_mut = state.borrow_mut();
+            if !state_mut.active {
+                state_mut.click_outside_listener = None;
+            } else if state_mut.click_outside_listener.is_none() {
+                state_mut.click_outside_listener =
+                    ClayAutocomplete::create_click_outside_listener(node_ref, state_ref);
+            }
+            || ()
+        });
+    }
+
+    let ClayAutocompleteProps {
+        disabled,
+        placeholder,
+        class,
+        node_ref,
+        input_ref,
+        ..
+    } = props.clone();
+
+    let state = state.borrow();
+    let value = state.get_value().to_string();
+    let items = state.get_items();
+    let loading = state.is_loading();
+    let show_menu = state.active && !items.is_empty();
+
+    let oninput = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |event: InputEvent| {
+            let input = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok());
+            if let Some(input) = input {
+                dispatch.emit(Msg::InputChange(input.value()));
+            }
+        })
+    };
+
+    let menu_active = show_menu;
+    let has_active_item = state.active_index.is_some();
+    let onkeydown = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            let msg = match event.key().as_str() {
+                ARROW_DOWN | ARROW_UP => {
+                    event.prevent_default();
+                    Msg::MoveActive(event.key() == ARROW_DOWN)
+                }
+                ENTER if menu_active && has_active_item => {
+                    event.prevent_default();
+                    Msg::SelectActive
+                }
+                ESCAPE | TAB => Msg::SetActive(false),
+                _ => return,
+            };
+            dispatch.emit(msg);
+        })
+    };
+
+    let loading_indicator = if loading {
+        html! {
+            <span class={classes!(ClayAutocomplete::INPUT_GROUP_INSET_ITEM, ClayAutocomplete::INPUT_GROUP_INSET_ITEM_AFTER)}>
+                <span class="inline-item">
+                    <span class="loading-animation loading-animation-sm" />
+                </span>
+            </span>
+        }
+    } else {
+        html! {}
+    };
+
+    let menu = if show_menu {
+        html! {
+            <div
+                class={classes!(ClayAutocomplete::DROPDOWN_MENU, ClayAutocomplete::AUTOCOMPLETE_DROPDOWN_MENU, ClayAutocomplete::SHOW)}
+                role="listbox" >
+                <ClayDropDownItemList>
+                    {items.iter().enumerate().map(|(index, item)| {
+                        let selected = item.clone();
+                        let on_click =
+                            dispatch.reform(move |_: MouseEvent| Msg::Select(selected.clone()));
+                        html! {
+                            <ClayDropDownItem
+                                key={item.clone()}
+                                active={state.active_index == Some(index)}
+                                on_click={Some(on_click)} >
+                                {ClayAutocomplete::gen_item_content(item, &value)}
+                            </ClayDropDownItem>
+                        }
+                    }).collect::<Html>()}
+                </ClayDropDownItemList>
+            </div>
+        }
+    } else {
+        html! {}
+    };
+
+    with_html_props(
+        html! {
+            <div class={classes!(class, ClayAutocomplete::AUTOCOMPLETE)} ref={node_ref}>
+                <div class={ClayAutocomplete::INPUT_GROUP}>
+                    <div class={ClayAutocomplete::INPUT_GROUP_ITEM}>
+                        <input
+                            aria-autocomplete="list"
+                            aria-expanded={show_menu.to_string()}
+                            autocomplete="off"
+                            class={classes!(
+                                ClayAutocomplete::FORM_CONTROL,
+                                ClayAutocomplete::get_bool_class(loading, ClayAutocomplete::INPUT_GROUP_INSET),
+                                ClayAutocomplete::get_bool_class(loading, ClayAutocomplete::INPUT_GROUP_INSET_AFTER)
+                            )}
+                            disabled={disabled}
+                            oninput={oninput}
+                            onkeydown={onkeydown}
+                            placeholder={placeholder}
+                            ref={input_ref}
+                            role="combobox"
+                            type="text"
+                            value={value.clone()} />
+                        {loading_indicator}
+                    </div>
+                </div>
+                {menu}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
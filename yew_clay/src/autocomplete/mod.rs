@@ -1,2 +1,5 @@
-// this one has dependencies on the vanilla inputs
-// will come back to it later
+mod autocomplete;
+mod types;
+
+pub use autocomplete::*;
+pub use types::*;
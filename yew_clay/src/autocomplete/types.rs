@@ -0,0 +1,72 @@
+use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+/// Decides whether an item should be displayed for the current value of the input. The first
+/// argument is the item, the second one is the value of the input.
+#[derive(Clone)]
+pub struct AutocompleteFilter(Rc<FilterFn>);
+
+type FilterFn = dyn Fn(&str, &str) -> bool;
+
+impl AutocompleteFilter {
+    pub fn new<F: Fn(&str, &str) -> bool + 'static>(filter: F) -> Self {
+        Self(Rc::new(filter))
+    }
+
+    pub fn matches(&self, item: &str, value: &str) -> bool {
+        (self.0)(item, value)
+    }
+}
+
+impl Default for AutocompleteFilter {
+    /// Matches the items that contain the value, ignoring case.
+    fn default() -> Self {
+        Self::new(|item, value| item.to_lowercase().contains(&value.to_lowercase()))
+    }
+}
+
+impl PartialEq for AutocompleteFilter {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::as_ptr(&self.0) as *const u8 == Rc::as_ptr(&other.0) as *const u8
+    }
+}
+
+impl Debug for AutocompleteFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AutocompleteFilter")
+    }
+}
+
+type ItemsFuture = Pin<Box<dyn Future<Output = Vec<String>>>>;
+
+/// Loads the items of the autocomplete for the current value of the input.
+#[derive(Clone)]
+pub struct AutocompleteFetcher(Rc<dyn Fn(String) -> ItemsFuture>);
+
+impl AutocompleteFetcher {
+    pub fn new<F, Fut>(fetcher: F) -> Self
+    where
+        F: Fn(String) -> Fut + 'static,
+        Fut: Future<Output = Vec<String>> + 'static,
+    {
+        Self(Rc::new(move |value| Box::pin(fetcher(value))))
+    }
+
+    pub fn fetch(&self, value: String) -> impl Future<Output = Vec<String>> {
+        (self.0)(value)
+    }
+}
+
+impl PartialEq for AutocompleteFetcher {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::as_ptr(&self.0) as *const u8 == Rc::as_ptr(&other.0) as *const u8
+    }
+}
+
+impl Debug for AutocompleteFetcher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("AutocompleteFetcher")
+    }
+}
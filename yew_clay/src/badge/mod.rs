@@ -1,17 +1,6 @@
-use gloo_events::EventListener;
-use std::collections::HashMap;
+use crate::html_props::{with_html_props, GlobalProps};
 use strum::AsRefStr;
-use yew::{classes, html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
-
-/// A Yew implementation of ClayBadge. For more info, check the documentation:
-/// [https://clayui.com/docs/components/badge.html]
-pub struct ClayBadge {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 /// Props for ClayButton. For details, check the docs:
 /// https://clayui.com/docs/components/badge/api.html
@@ -51,41 +40,29 @@ impl ClayBadge {
     }
 }
 
-impl Component for ClayBadge {
-    type Message = ();
-    type Properties = ClayBadgeProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let ClayBadgeProps {
-            class,
-            display_type,
-            label,
-            node_ref,
-            ..
-        } = ctx.props().clone();
-        let display_class = Self::get_display_class(display_type);
+/// A Yew implementation of ClayBadge. For more info, check the documentation:
+/// [https://clayui.com/docs/components/badge.html]
+#[function_component(ClayBadge)]
+pub fn clay_badge(props: &ClayBadgeProps) -> Html {
+    let ClayBadgeProps {
+        class,
+        display_type,
+        label,
+        node_ref,
+        ..
+    } = props.clone();
+    let display_class = ClayBadge::get_display_class(display_type);
 
+    with_html_props(
         html! {
             <span
                 class={classes!(class, "badge", display_class)}
                 ref={node_ref} >
                 {label}
             </span>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(button_props) = &ctx.props().button_html_attributes {
-            let button_props = button_props.clone();
-            button_props.inject(&ctx.props().node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.button_html_attributes.as_ref(),
+    )
 }
 
 #[derive(AsRefStr, Debug, PartialEq, Clone, Default)]
@@ -0,0 +1,143 @@
+use super::ellipses::Ellipsis;
+use super::item::{ClayItem, ClayItemProps};
+use crate::html_props::{with_html_props, GlobalProps};
+#[cfg(feature = "router")]
+use crate::link::LinkRoute;
+use web_sys::MouseEvent;
+use yew::{classes, function_component, html, Callback, Classes, Html, NodeRef, Properties};
+
+/// Describes one of the items of a ClayBreadcrumb.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct BreadcrumbItem {
+    /// Flag to indicate if the item is the current page.
+    pub active: bool,
+
+    /// The target of the link.
+    pub href: Option<String>,
+
+    /// Label of the item.
+    pub label: String,
+
+    /// Callback for when the item is clicked.
+    pub on_click: Option<Callback<MouseEvent>>,
+
+    /// A typed route of the application that the item links to.
+    #[cfg(feature = "router")]
+    pub route: Option<LinkRoute>,
+}
+
+/// Props for ClayBreadcrumb. For details, check the docs:
+/// <https://clayui.com/docs/components/breadcrumb/api.html>
+#[derive(Debug, Properties, PartialEq, Clone, Default)]
+pub struct ClayBreadcrumbProps {
+    /// Defines how many items are kept visible on each side of the Ellipsis. The items in
+    /// the middle are collapsed when there are more than `ellipsis_buffer * 2 + 1` items.
+    #[prop_or(1)]
+    pub ellipsis_buffer: usize,
+
+    /// Props to pass to the Ellipsis element.
+    #[prop_or_default]
+    pub ellipsis_props: Option<GlobalProps>,
+
+    /// The items of the Breadcrumb.
+    pub items: Vec<BreadcrumbItem>,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayBreadcrumb {
+    const BREADCRUMB: &'static str = "breadcrumb";
+
+    fn gen_item(index: usize, item: &BreadcrumbItem) -> Html {
+        #[allow(unused_mut)]
+        let mut props = ClayItemProps {
+            active: item.active,
+            href: item.href.clone(),
+            label: item.label.clone(),
+            on_click: item.on_click.clone(),
+            ..ClayItemProps::default()
+        };
+        #[cfg(feature = "router")]
+        {
+            props.route = item.route.clone();
+        }
+
+        html! {
+            <ClayItem key={index} ..props />
+        }
+    }
+
+    /// Returns the items to render, with the middle ones collapsed into an Ellipsis when there
+    /// are too many of them.
+    fn gen_items(props: &ClayBreadcrumbProps) -> Vec<Html> {
+        let ClayBreadcrumbProps {
+            ellipsis_buffer,
+            ellipsis_props,
+            items,
+            spritemap,
+            ..
+        } = props;
+
+        let total = items.len();
+        if total <= ellipsis_buffer * 2 + 1 {
+            return items
+                .iter()
+                .enumerate()
+                .map(|(index, item)| Self::gen_item(index, item))
+                .collect();
+        }
+
+        let end = total - ellipsis_buffer;
+        let mut nodes = Vec::with_capacity(ellipsis_buffer * 2 + 1);
+        nodes.extend(
+            items[..*ellipsis_buffer]
+                .iter()
+                .enumerate()
+                .map(|(index, item)| Self::gen_item(index, item)),
+        );
+        nodes.push(html! {
+            <Ellipsis
+                key="ellipsis"
+                html_props={ellipsis_props.clone()}
+                items={items[*ellipsis_buffer..end].to_vec()}
+                spritemap={*spritemap} />
+        });
+        nodes.extend(
+            items[end..]
+                .iter()
+                .enumerate()
+                .map(|(index, item)| Self::gen_item(end + index, item)),
+        );
+        nodes
+    }
+}
+
+/// A Yew implementation of ClayBreadcrumb. For more info about ClayBreadcrumb, check the
+/// documentation: <https://clayui.com/docs/components/breadcrumb.html>
+#[function_component(ClayBreadcrumb)]
+pub fn clay_breadcrumb(props: &ClayBreadcrumbProps) -> Html {
+    let ClayBreadcrumbProps {
+        class, node_ref, ..
+    } = props.clone();
+
+    with_html_props(
+        html! {
+            <ol class={classes!(class, ClayBreadcrumb::BREADCRUMB)} ref={node_ref}>
+                {for ClayBreadcrumb::gen_items(props)}
+            </ol>
+        },
+        props.html_props.as_ref(),
+    )
+}
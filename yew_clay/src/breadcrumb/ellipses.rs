@@ -1,40 +1,98 @@
-use std::rc::Rc;
-use yew::{html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-
-// This is on hold until ClayDropDown is finished.
-
-pub struct Ellipsis;
+use super::breadcrumb::BreadcrumbItem;
+use crate::dropdown::{
+    ClayDropDown, ClayDropDownItem, ClayDropDownItemList, ClayDropDownItemProps, OtherProps,
+};
+use crate::html_props::GlobalProps;
+use crate::icon::{ClayIcon, ClaySymbol};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 /// Props for ClayEllipsisProps. For details, check the docs:
 /// <https://clayui.com/docs/components/breadcrumb.html>
 #[derive(Debug, Properties, PartialEq, Clone, Default)]
 pub struct ClayEllipsisProps {
+    /// The items that were collapsed.
     #[prop_or_default]
-    pub class: Classes,
+    pub items: Vec<BreadcrumbItem>,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
 
     #[prop_or_default]
-    pub children: Children,
+    pub class: Classes,
 
     #[prop_or_default]
     pub node_ref: NodeRef,
 
     /// A catchall prop to pass down anything not specified here to the underlying component.
     #[prop_or_default]
-    pub html_props: Option<Rc<GlobalProps>>,
+    pub html_props: Option<GlobalProps>,
 }
 
-pub enum Msg {}
-
-impl Component for Ellipsis {
-    type Message = Msg;
-    type Properties = ClayEllipsisProps;
+impl Ellipsis {
+    const BREADCRUMB_ITEM: &'static str = "breadcrumb-item";
+    const BREADCRUMB_LINK: &'static str = "breadcrumb-link";
+    const BTN: &'static str = "btn";
+    const BTN_UNSTYLED: &'static str = "btn-unstyled";
+    const ELLIPSIS_SYMBOL: ClaySymbol = ClaySymbol::EllipsisH;
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self
+    fn get_item_props(
+        item: &BreadcrumbItem,
+        spritemap: Option<&'static str>,
+    ) -> ClayDropDownItemProps {
+        #[allow(unused_mut)]
+        let mut props = ClayDropDownItemProps {
+            active: item.active,
+            href: item.href.clone(),
+            on_click: item.on_click.clone(),
+            spritemap,
+            children: Children::new(vec![html! { {item.label.clone()} }]),
+            ..ClayDropDownItemProps::default()
+        };
+        #[cfg(feature = "router")]
+        {
+            props.route = item.route.clone();
+        }
+        props
     }
+}
+
+/// The collapsed part of a ClayBreadcrumb. It renders a DropDown that lists the hidden items
+/// as links.
+#[function_component(Ellipsis)]
+pub fn ellipsis(props: &ClayEllipsisProps) -> Html {
+    let ClayEllipsisProps {
+        items,
+        spritemap,
+        class,
+        node_ref,
+        html_props,
+    } = props.clone();
+
+    let trigger = html! {
+        <button
+            class={classes!(Ellipsis::BREADCRUMB_LINK, Ellipsis::BTN, Ellipsis::BTN_UNSTYLED)}
+            type="button" >
+            <ClayIcon {spritemap} symbol={Ellipsis::ELLIPSIS_SYMBOL} />
+        </button>
+    };
 
-    fn view(&self, _ctx: &Context<Self>) -> Html {
-        html! {}
+    html! {
+        <ClayDropDown
+            class={classes!(class, Ellipsis::BREADCRUMB_ITEM)}
+            close_on_click={true}
+            container_element="li"
+            {node_ref}
+            other_props={html_props.map(OtherProps::DivProps)}
+            {trigger} >
+            <ClayDropDownItemList>
+                {items.iter().enumerate().map(|(index, item)| {
+                    let props = Ellipsis::get_item_props(item, spritemap);
+                    html! {
+                        <ClayDropDownItem key={index} ..props />
+                    }
+                }).collect::<Html>()}
+            </ClayDropDownItemList>
+        </ClayDropDown>
     }
 }
@@ -1,25 +1,20 @@
 use crate::button::{ButtonDisplayType, ClayButton};
+use crate::html_props::{with_html_props, GlobalProps};
 use crate::link::ClayLink;
-use domatt::attributes::anchor::Href;
-use domatt::attributes::global::{AriaRole, CustomAttribute, Role, Title};
-use domatt::events::Click;
-use gloo_events::EventListener;
-use std::collections::HashMap;
-use std::rc::Rc;
+#[cfg(feature = "router")]
+use crate::link::LinkRoute;
 use web_sys::MouseEvent;
 use yew::{
-    classes, html, Callback, Children, Classes, Component, Context, Html, NodeRef, Properties,
+    classes, function_component, html, Callback, Children, Classes, Html, NodeRef, Properties,
 };
-use yew_dom_attributes::anchor_props::AnchorProps;
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
-
-pub struct ClayItem {
-    /// This vec holds all the EventListeners defined for this component. They will be automatically
-    /// removed when the component is destroyed.
-    listeners: HashMap<String, EventListener>,
-    html: Html,
-    anchor_props: AnchorProps,
+
+/// The attributes of the li element, which depend on whether the item is active, has an href
+/// or an on_click callback. They are rendered declaratively so that they are also present in
+/// server rendered markup.
+#[derive(Default)]
+struct ItemAttributes {
+    title: Option<String>,
+    onclick: Option<Callback<MouseEvent>>,
 }
 
 /// Props for ClayItem. For details, check the docs:
@@ -28,18 +23,23 @@ pub struct ClayItem {
 pub struct ClayItemProps {
     /// Flag to indicate if the Breadcrumb item is active or not.
     #[prop_or(false)]
-    active: bool,
+    pub active: bool,
 
     /// This value is used to be the target of the link.
     #[prop_or_default]
-    href: Option<String>,
+    pub href: Option<String>,
 
     /// Label of the Breadcrumb item
-    label: String,
+    pub label: String,
 
     ///Callback for when a Breadcrumb item is clicked.
     #[prop_or_default]
-    on_click: Option<Callback<MouseEvent>>,
+    pub on_click: Option<Callback<MouseEvent>>,
+
+    /// A typed route of the application that the item links to.
+    #[cfg(feature = "router")]
+    #[prop_or_default]
+    pub route: Option<LinkRoute>,
 
     #[prop_or_default]
     pub class: Classes,
@@ -55,8 +55,6 @@ pub struct ClayItemProps {
     pub html_props: Option<GlobalProps>,
 }
 
-pub enum Msg {}
-
 impl ClayItem {
     const ACTIVE: &'static str = "active";
     const TESTID_BASE: &'static str = "testid";
@@ -70,94 +68,106 @@ impl ClayItem {
             None
         }
     }
-}
 
-impl Component for ClayItem {
-    type Message = Msg;
-    type Properties = ClayItemProps;
+    #[cfg(feature = "router")]
+    fn has_route(props: &ClayItemProps) -> bool {
+        props.route.is_some()
+    }
 
-    fn create(ctx: &Context<Self>) -> Self {
+    #[cfg(not(feature = "router"))]
+    fn has_route(_props: &ClayItemProps) -> bool {
+        false
+    }
+
+    #[cfg(feature = "router")]
+    fn gen_link(props: &ClayItemProps, content: Html) -> Html {
+        let ClayItemProps { href, route, .. } = props.clone();
+        html! {
+            <ClayLink class={Self::BREADCRUMB_LINK} {href} {route}>{content}</ClayLink>
+        }
+    }
+
+    #[cfg(not(feature = "router"))]
+    fn gen_link(props: &ClayItemProps, content: Html) -> Html {
+        let href = props.href.clone();
+        html! {
+            <ClayLink class={Self::BREADCRUMB_LINK} {href}>{content}</ClayLink>
+        }
+    }
+
+    fn get_testid(label: &str) -> String {
+        let mut testid = String::with_capacity(Self::TESTID_BASE.len() + label.len());
+        testid.push_str(Self::TESTID_BASE);
+        testid.push_str(label);
+        testid
+    }
+
+    /// Builds the content of the item along with the attributes of the li element.
+    fn build_content(props: &ClayItemProps) -> (Html, ItemAttributes) {
         let ClayItemProps {
             active,
             label,
             href,
             on_click,
             ..
-        } = ctx.props().clone();
-
-        let mut anchor_props = AnchorProps::new();
-
-        let mut testid = String::with_capacity(Self::TESTID_BASE.len() + label.len());
-        testid.push_str(Self::TESTID_BASE);
-        testid.push_str(&label);
-        let data_test_id = CustomAttribute::new("data-testid", Some(testid));
-        anchor_props.add_attribute(Box::new(data_test_id));
-
-        let html = if !active && href.is_some() {
-            let href = href.as_ref().expect("there to be an href");
-            let href = Href::new(href.to_owned());
-            anchor_props.add_attribute(Box::new(href));
-
-            let role = Role::new(AriaRole::Button);
-            anchor_props.add_attribute(Box::new(role));
-
-            html! {
-                <ClayLink class={Self::BREADCRUMB_LINK} >
-                    <span class={Self::BREADCRUMB_TEXT_TRUNCATE}>{label}</span>
-                </ClayLink>
-            }
+        } = props.clone();
+
+        if !active && (href.is_some() || Self::has_route(props)) {
+            let html = Self::gen_link(
+                props,
+                html! { <span class={Self::BREADCRUMB_TEXT_TRUNCATE}>{label}</span> },
+            );
+            (html, ItemAttributes::default())
         } else if !active && on_click.is_some() {
-            let on_click = on_click.expect("onclick callback to be there");
-            let on_click = Click::from(on_click);
-            anchor_props.add_listener("breadcrumb-click", Rc::new(on_click));
-
-            let title = Title::new(label.clone());
-            anchor_props.add_attribute(Box::new(title));
-
-            html! {
+            let attributes = ItemAttributes {
+                title: Some(label.clone()),
+                onclick: on_click,
+            };
+            let html = html! {
                 <ClayButton
                     class={Self::BREADCRUMB_LINK}
                     display_type={ButtonDisplayType::Unstyled} >
                     <span class={Self::BREADCRUMB_TEXT_TRUNCATE}>{label}</span>
                 </ClayButton>
-            }
+            };
+            (html, attributes)
         } else {
-            let title = Title::new(label.clone());
-            anchor_props.add_attribute(Box::new(title));
-
-            html! {
+            let attributes = ItemAttributes {
+                title: Some(label.clone()),
+                ..ItemAttributes::default()
+            };
+            let html = html! {
                 <span class={Self::BREADCRUMB_TEXT_TRUNCATE}>{label}</span>
-            }
-        };
-
-        Self {
-            listeners: HashMap::new(),
-            html,
-            anchor_props,
+            };
+            (html, attributes)
         }
     }
+}
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let ClayItemProps {
-            active, node_ref, ..
-        } = ctx.props().clone();
-        let active_class = Self::get_active_class(active);
-
+#[function_component(ClayItem)]
+pub fn clay_item(props: &ClayItemProps) -> Html {
+    let ClayItemProps {
+        active,
+        label,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+    let active_class = ClayItem::get_active_class(active);
+    let (content, attributes) = ClayItem::build_content(props);
+    let ItemAttributes { title, onclick } = attributes;
+
+    with_html_props(
         html! {
-          <li ref={node_ref} class={classes!("breadcrumb-item", active_class)} >
-            {self.html.clone()}
+          <li
+            ref={node_ref}
+            class={classes!(class, "breadcrumb-item", active_class)}
+            data-testid={ClayItem::get_testid(&label)}
+            {onclick}
+            {title} >
+            {content}
           </li>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        let node_ref = &ctx.props().node_ref;
-        let anchor_props = self.anchor_props.clone();
-        anchor_props.inject(node_ref, &mut self.listeners);
-
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            html_props.inject(node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.html_props.as_ref(),
+    )
 }
@@ -1,2 +1,7 @@
-pub mod ellipses;
-pub mod item;
+mod breadcrumb;
+mod ellipses;
+mod item;
+
+pub use breadcrumb::*;
+pub use ellipses::*;
+pub use item::*;
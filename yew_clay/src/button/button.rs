@@ -1,17 +1,6 @@
-use gloo_events::EventListener;
-use std::collections::HashMap;
+use crate::html_props::{with_html_props, ButtonProps};
 use strum::AsRefStr;
-use yew::{classes, html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::button_props::ButtonProps;
-use yew_dom_attributes::DomInjector;
-
-/// A Yew implementation of ClayButton. For more info about ClayButton, check the documentation:
-/// [https://clayui.com/docs/components/button.html]
-pub struct ClayButton {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 /// Props for ClayButton. For details, check the docs:
 /// https://clayui.com/docs/components/button/api.html
@@ -63,7 +52,7 @@ pub struct ClayButtonProps {
 }
 
 impl ClayButton {
-    fn get_classes(&self, props: &ClayButtonProps) -> String {
+    fn get_classes(props: &ClayButtonProps) -> String {
         let mut classes: Vec<String> = vec!["btn".into()];
 
         if props.alert {
@@ -98,22 +87,14 @@ impl ClayButton {
     }
 }
 
-impl Component for ClayButton {
-    type Message = ();
-    type Properties = ClayButtonProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-
-        let btn_classes = self.get_classes(&props);
-        let user_classes = props.class.clone();
+/// A Yew implementation of ClayButton. For more info about ClayButton, check the documentation:
+/// [https://clayui.com/docs/components/button.html]
+#[function_component(ClayButton)]
+pub fn clay_button(props: &ClayButtonProps) -> Html {
+    let btn_classes = ClayButton::get_classes(props);
+    let user_classes = props.class.clone();
 
+    with_html_props(
         html! {
             <button
                 class={classes!(btn_classes, user_classes)}
@@ -121,16 +102,9 @@ impl Component for ClayButton {
                 type={props._type.clone()} >
                 {props.children.clone()}
             </button>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(button_props) = &ctx.props().button_props {
-            let node_ref = &ctx.props().node_ref;
-            let button_props = button_props.clone();
-            button_props.inject(node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.button_props.as_ref(),
+    )
 }
 
 // An enum specifying the different default styles of ClayButton.
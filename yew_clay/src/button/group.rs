@@ -1,19 +1,9 @@
 use super::button::ClayButton;
 use super::ClayButtonWithIcon;
-use gloo_events::EventListener;
-use std::collections::HashMap;
+use crate::html_props::{with_html_props, GlobalProps};
 use yew::virtual_dom::VChild;
 use yew::{classes, Classes, NodeRef};
-use yew::{html, html::ChildrenRenderer, Component, Context, Html, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
-
-/// A wrapper around ClayButton.Group. Only ClayButtons may be passed as children.
-pub struct ClayButtonGroup {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+use yew::{function_component, html, html::ChildrenRenderer, Html, Properties};
 
 /// Props for Button Group. For details, check the docs:
 /// https://clayui.com/docs/components/button/api.html
@@ -52,51 +42,36 @@ impl Into<Html> for ButtonType {
     }
 }
 
-impl Component for ClayButtonGroup {
-    type Message = ();
-    type Properties = ButtonGroupProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(html_props) = &ctx.props().html_element_props {
-            let html_props = html_props.clone();
-            let node_ref = &ctx.props().node_ref;
-            html_props.inject(node_ref, &mut self.listeners);
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props();
-        let user_classes = props.class.clone();
+/// A wrapper around ClayButton.Group. Only ClayButtons may be passed as children.
+#[function_component(ClayButtonGroup)]
+pub fn clay_button_group(props: &ButtonGroupProps) -> Html {
+    let user_classes = props.class.clone();
 
-        let btn_group_class = if props.vertical {
-            "btn-group-vertical".to_string()
-        } else {
-            "btn-group".to_string()
-        };
+    let btn_group_class = if props.vertical {
+        "btn-group-vertical".to_string()
+    } else {
+        "btn-group".to_string()
+    };
 
-        let children = props.children.clone();
+    let children = props.children.clone();
 
+    with_html_props(
         html! {
             <div
-                ref={&ctx.props().node_ref}
+                ref={&props.node_ref}
                 class={classes!(user_classes, btn_group_class)}
                 role={props.role.clone()}
             >
             { if props.spaced {
                 children.into_iter().enumerate().map(|(key, child)| {
+                    let child: Html = child.into();
                     html!{<div class={"btn-group-item"} key={key}>{child}</div>}
                 }).collect::<Html>()
             } else {
                 children.into_iter().collect::<Html>()
             }}
             </div>
-
-        }
-    }
+        },
+        props.html_element_props.as_ref(),
+    )
 }
@@ -1,12 +1,8 @@
 use super::button::ClayButton;
 use super::ClayButtonProps;
-use crate::icon::ClayIcon;
-use yew::{html, Component, Context, NodeRef, Properties};
-use yew_dom_attributes::svg_props::SvgProps;
-
-/// A Yew implementation of ClayButtonWithIcon. For more info about ClayButton, check the documentation:
-/// [https://clayui.com/docs/components/button.html#icon]
-pub struct ClayButtonWithIcon;
+use crate::html_props::SvgProps;
+use crate::icon::{ClayIcon, ClaySymbol};
+use yew::{function_component, html, Html, NodeRef, Properties};
 
 /// Props for ClayButton. For details, check the docs:
 /// [https://clayui.com/docs/components/button/api.html#buttonwithicon]
@@ -14,12 +10,13 @@ pub struct ClayButtonWithIcon;
 pub struct ButtonWithIconProps {
     /// Path to the spritemap that contains your SVG icons. The default Clay SVGs can be found
     /// here [https://github.com/liferay/clay/blob/master/clayui.com/static/images/icons/icons.svg]
+    /// Without it, the icon uses the spritemap or the IconRegistry of ClayProvider.
     #[prop_or_default]
-    pub spritemap: &'static str,
+    pub spritemap: Option<&'static str>,
 
-    /// String identifying the SVG from the spritemap that you want to render.
+    /// The SVG from the spritemap that you want to render.
     #[prop_or_default]
-    pub symbol: String,
+    pub symbol: ClaySymbol,
 
     /// Props to be passed down to the ClayButton component that this element wraps.
     #[prop_or_default]
@@ -35,39 +32,29 @@ pub struct ButtonWithIconProps {
     pub icon_node_ref: NodeRef,
 }
 
-impl Component for ClayButtonWithIcon {
-    type Message = ();
-
-    type Properties = ButtonWithIconProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self
+/// A Yew implementation of ClayButtonWithIcon. For more info about ClayButton, check the documentation:
+/// [https://clayui.com/docs/components/button.html#icon]
+#[function_component(ClayButtonWithIcon)]
+pub fn clay_button_with_icon(props: &ButtonWithIconProps) -> Html {
+    let mut button_props = props.clay_button_props.clone();
+    let monospaced = button_props.monospaced.unwrap_or(true);
+
+    // Workaround for Yew bug that is making the default value an empty string for some reason.
+    if button_props._type.is_empty() {
+        button_props._type = "button".into();
     }
 
-    fn view(&self, ctx: &Context<Self>) -> yew::Html {
-        let props = ctx.props();
-        let mut button_props = props.clay_button_props.clone();
-        let button_ref = button_props.node_ref.clone();
-        let monospaced = button_props.monospaced.unwrap_or(true);
-
-        // Workaround for Yew bug that is making the default value an empty string for some reason.
-        if button_props._type.len() == 0 {
-            button_props._type = "button".into();
-        }
-
-        html! {
-            <ClayButton
-                monospaced={monospaced}
-                ref={button_ref}
-                ..button_props
-            >
-                <ClayIcon
-                    spritemap={props.spritemap}
-                    symbol={props.symbol.clone()}
-                    node_ref={props.icon_node_ref.clone()}
-                    svg_html_attributes={props.icon_svg_props.clone()}
-                     />
-            </ClayButton>
-        }
+    html! {
+        <ClayButton
+            monospaced={monospaced}
+            ..button_props
+        >
+            <ClayIcon
+                spritemap={props.spritemap}
+                symbol={props.symbol.clone()}
+                node_ref={props.icon_node_ref.clone()}
+                svg_html_attributes={props.icon_svg_props.clone()}
+                 />
+        </ClayButton>
     }
 }
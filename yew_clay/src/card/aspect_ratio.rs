@@ -1,6 +1,6 @@
-use super::Interactive;
+use super::{get_tag_name, use_interactive};
 use strum::AsRefStr;
-use yew::{classes, html, Children, Classes, Component, Html, Properties};
+use yew::{classes, function_component, html, Children, Classes, Html, Properties};
 
 #[derive(Debug, PartialEq, AsRefStr, Clone)]
 pub enum ContainerAspectRatioType {
@@ -30,9 +30,6 @@ pub struct Props {
     pub container_aspect_ratio: Option<ContainerAspectRatioType>,
 }
 
-#[derive(Debug)]
-pub struct ClayCardAspectRatio;
-
 impl ClayCardAspectRatio {
     const ASPECT_RATIO: &'static str = "aspect-ratio-";
 
@@ -53,31 +50,20 @@ impl ClayCardAspectRatio {
     }
 }
 
-impl Component for ClayCardAspectRatio {
-    type Message = ();
-
-    type Properties = Props;
+#[function_component(ClayCardAspectRatio)]
+pub fn clay_card_aspect_ratio(props: &Props) -> Html {
+    let interactive = use_interactive();
+    let tag = get_tag_name(interactive);
+    let Props {
+        children,
+        class,
+        container_aspect_ratio,
+    } = props.clone();
+    let aspect_ratio_class = ClayCardAspectRatio::get_apect_ratio_class(&container_aspect_ratio);
 
-    fn create(_ctx: &yew::Context<Self>) -> Self {
-        Self
-    }
-
-    fn view(&self, ctx: &yew::Context<Self>) -> Html {
-        let interactive = Self::get_interactive(ctx);
-        let tag = Self::get_tag_name(interactive);
-        let Props {
-            children,
-            class,
-            container_aspect_ratio,
-        } = ctx.props().clone();
-        let aspect_ratio_class = Self::get_apect_ratio_class(&container_aspect_ratio);
-
-        html! {
-            <@{tag} class={classes!(class, aspect_ratio_class)}>
-                {children.clone()}
-            </@>
-        }
+    html! {
+        <@{tag} class={classes!(class, aspect_ratio_class)}>
+            {children}
+        </@>
     }
 }
-
-impl Interactive for ClayCardAspectRatio {}
@@ -1,10 +1,6 @@
-use std::collections::HashMap;
-
-use gloo_events::EventListener;
-use yew::{classes, html, Children, Classes, Component, Html, NodeRef, Properties};
-use yew_dom_attributes::{global_props::GlobalProps, DomInjector};
-
-use super::Interactive;
+use super::{get_tag_name, use_interactive};
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 #[derive(Debug, Properties, PartialEq, Clone)]
 pub struct Props {
@@ -21,45 +17,24 @@ pub struct Props {
     pub node_ref: NodeRef,
 }
 
-pub struct ClayCardCaption {
-    listeners: HashMap<String, EventListener>,
-}
-
 impl ClayCardCaption {
     const CARD_DETAIL: &'static str = "card-detail";
 }
 
-impl Component for ClayCardCaption {
-    type Message = ();
-
-    type Properties = Props;
-
-    fn create(_ctx: &yew::Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &yew::Context<Self>) -> Html {
-        let interactive = Self::get_interactive(ctx);
-        let tag = Self::get_tag_name(interactive);
-        let Props {
-            class, children, ..
-        } = ctx.props().clone();
+#[function_component(ClayCardCaption)]
+pub fn clay_card_caption(props: &Props) -> Html {
+    let interactive = use_interactive();
+    let tag = get_tag_name(interactive);
+    let Props {
+        class, children, ..
+    } = props.clone();
 
+    with_html_props(
         html! {
-          <@{tag} class={classes!(class, Self::CARD_DETAIL)}>
+          <@{tag} class={classes!(class, ClayCardCaption::CARD_DETAIL)}>
             {children}
           </@>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &yew::Context<Self>, _first_render: bool) {
-        if let Some(other_props) = &ctx.props().other_props {
-            let other_props = other_props.clone();
-            other_props.inject(&ctx.props().node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.other_props.as_ref(),
+    )
 }
-
-impl Interactive for ClayCardCaption {}
@@ -1,10 +1,9 @@
 use super::context::ClayCardContext;
-use gloo_events::EventListener;
-use std::collections::HashMap;
+use crate::html_props::{with_html_props, GlobalProps};
 use yew::{
-    classes, html, Children, Classes, Component, ContextProvider, Html, NodeRef, Properties,
+    classes, function_component, html, Children, Classes, ContextProvider, Html, NodeRef,
+    Properties,
 };
-use yew_dom_attributes::{global_props::GlobalProps, DomInjector};
 
 #[derive(Properties, PartialEq, Clone)]
 pub struct Props {
@@ -29,41 +28,20 @@ pub struct Props {
     children: Children,
 }
 
-pub struct ClayCardHorizontalBody {
-    listeners: HashMap<String, EventListener>,
-}
-
-impl Component for ClayCardHorizontalBody {
-    type Message = ();
-
-    type Properties = Props;
-
-    fn create(_ctx: &yew::Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &yew::Context<Self>) -> Html {
-        let Props {
-            class, children, ..
-        } = ctx.props().clone();
+#[function_component(ClayCardHorizontalBody)]
+pub fn clay_card_horizontal_body(props: &Props) -> Html {
+    let Props {
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+    with_html_props(
         html! {
-          <div class={classes!(class, ClayCardHorizontal::NOT_SELECTABLE)}>{children}</div>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &yew::Context<Self>, _first_render: bool) {
-        if let Some(other_props) = &ctx.props().other_props {
-            let other_props = other_props.clone();
-            other_props.inject(&ctx.props().node_ref, &mut self.listeners);
-        }
-    }
-}
-
-pub struct ClayCardHorizontal {
-    listeners: HashMap<String, EventListener>,
-    context: ClayCardContext,
+          <div class={classes!(class, ClayCardHorizontal::NOT_SELECTABLE)} ref={node_ref}>{children}</div>
+        },
+        props.other_props.as_ref(),
+    )
 }
 
 impl ClayCardHorizontal {
@@ -89,47 +67,38 @@ impl ClayCardHorizontal {
     }
 }
 
-impl Component for ClayCardHorizontal {
-    type Message = ();
-
-    type Properties = Props;
-
-    fn create(_ctx: &yew::Context<Self>) -> Self {
-        let context = ClayCardContext {
-            horizontal: true,
-            interactive: false,
-        };
-        Self {
-            listeners: HashMap::new(),
-            context,
-        }
-    }
-
-    fn view(&self, ctx: &yew::Context<Self>) -> Html {
-        let Props {
-            class,
-            selectable,
-            children,
-            active,
-            ..
-        } = ctx.props().clone();
-
-        let selectable_class = Self::get_selectable_class(selectable);
-        let active_class = Self::get_active_class(active);
-
+#[function_component(ClayCardHorizontal)]
+pub fn clay_card_horizontal(props: &Props) -> Html {
+    let Props {
+        class,
+        selectable,
+        children,
+        active,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let context = ClayCardContext {
+        horizontal: true,
+        interactive: false,
+    };
+    let selectable_class = ClayCardHorizontal::get_selectable_class(selectable);
+    let active_class = ClayCardHorizontal::get_active_class(active);
+
+    let card = with_html_props(
         html! {
-            <ContextProvider<ClayCardContext> context={self.context.clone()}>
-                <div class={classes!(class, selectable_class, Self::CARD_TYPE_DIRECTORY, active_class)}>
-                    {children}
-                </div>
-            </ContextProvider<ClayCardContext>>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &yew::Context<Self>, _first_render: bool) {
-        if let Some(other_props) = &ctx.props().other_props {
-            let other_props = other_props.clone();
-            other_props.inject(&ctx.props().node_ref, &mut self.listeners);
-        }
+            <div
+                class={classes!(class, selectable_class, ClayCardHorizontal::CARD_TYPE_DIRECTORY, active_class)}
+                ref={node_ref} >
+                {children}
+            </div>
+        },
+        props.other_props.as_ref(),
+    );
+
+    html! {
+        <ContextProvider<ClayCardContext> {context}>
+            {card}
+        </ContextProvider<ClayCardContext>>
     }
 }
@@ -1,14 +1,11 @@
+use crate::html_props::{with_html_props, GlobalProps};
+#[cfg(feature = "router")]
+use crate::LinkRoute;
 use crate::{card::ClayCardContext, ClayLink};
-use domatt::{
-    attributes::{anchor::Href, global::AriaRole},
-    events::Click,
-};
-use gloo_events::EventListener;
-use std::collections::HashMap;
 use yew::{
-    classes, html, Children, Classes, Component, ContextProvider, Html, NodeRef, Properties,
+    classes, function_component, html, Children, Classes, ContextProvider, Html, NodeRef,
+    Properties,
 };
-use yew_dom_attributes::{anchor_props::AnchorProps, global_props::GlobalProps, DomInjector};
 
 #[derive(Debug, Properties, PartialEq, Clone)]
 pub struct Props {
@@ -26,11 +23,12 @@ pub struct Props {
 
     #[prop_or_default]
     pub node_ref: NodeRef,
-}
 
-pub struct ClayCardNavigation {
-    context: ClayCardContext,
-    listeners: HashMap<String, EventListener>,
+    /// A typed route of the application. When set, the card is rendered as a ClayLink that
+    /// navigates through the router.
+    #[cfg(feature = "router")]
+    #[prop_or_default]
+    pub route: Option<LinkRoute>,
 }
 
 impl ClayCardNavigation {
@@ -38,6 +36,8 @@ impl ClayCardNavigation {
     const CARD_INTERACTIVE: &'static str = "card-interactive";
     const CARD_INTERACTIVE_PRIMARY: &'static str = "card-interactive-primary";
     const CARD_TYPE_TEMPLATE: &'static str = "card-type-template";
+    const CLICK: &'static str = "click";
+    const HREF: &'static str = "href";
     const TEMPLATE_CARD: &'static str = "template-card";
     const TEMPLATE_CARD_HORIZONTAL: &'static str = "template-card-horizontal";
 
@@ -54,92 +54,98 @@ impl ClayCardNavigation {
         classes: Classes,
         node_ref: NodeRef,
         children: Children,
-        role: Option<String>,
+        role: Option<&'static str>,
     ) -> Html {
-        other_props
-            .and_then(|other_props| {
-                if other_props.has_attribute(Href::KEY) {
-                    Some(html! {
-                        <ClayLink class={classes.clone()} node_ref={&node_ref} anchor_props={AnchorProps::from(other_props)}>
-                            {children.clone()}
-                        </ClayLink>
-                    })
-                } else {
-                    None
-                }
-            })
-            .or_else(|| {
-                Some(html! {
-                    <div class={classes.clone()} ref={&node_ref} role={role}>{children}</div>
-                })
-            }).unwrap()
-    }
-
-    fn get_role(other_props: &Option<GlobalProps>) -> Option<String> {
-        if let Some(other_props) = other_props {
-            if other_props.has_event_type(Click::KEY) {
-                Some(AriaRole::Button.as_ref().to_string()) // TODO: implement into prop value for all domatt values (cfg Yew)
-            } else {
-                None
-            }
-        } else {
-            None
+        match other_props {
+            Some(other_props) if other_props.has_attribute(Self::HREF) => html! {
+                <ClayLink class={classes} {node_ref} anchor_props={other_props}>
+                    {children}
+                </ClayLink>
+            },
+            other_props => with_html_props(
+                html! {
+                    <div class={classes} ref={node_ref} role={role}>{children}</div>
+                },
+                other_props.as_ref(),
+            ),
         }
     }
-}
-
-impl Component for ClayCardNavigation {
-    type Message = ();
 
-    type Properties = Props;
+    #[cfg(feature = "router")]
+    fn has_route(props: &Props) -> bool {
+        props.route.is_some()
+    }
 
-    fn create(ctx: &yew::Context<Self>) -> Self {
-        let context = ClayCardContext {
-            horizontal: ctx.props().horizontal,
-            interactive: true,
-        };
-        Self {
-            context,
-            listeners: HashMap::new(),
-        }
+    #[cfg(not(feature = "router"))]
+    fn has_route(_props: &Props) -> bool {
+        false
     }
 
-    fn view(&self, ctx: &yew::Context<Self>) -> yew::Html {
+    #[cfg(feature = "router")]
+    fn gen_route_link(props: &Props, classes: Classes) -> Html {
         let Props {
             children,
             other_props,
             node_ref,
-            class,
-            horizontal,
+            route,
             ..
-        } = ctx.props().clone();
-
-        let classes = classes!(
-            class,
-            Self::CARD,
-            Self::CARD_INTERACTIVE,
-            Self::CARD_INTERACTIVE_PRIMARY,
-            Self::CARD_TYPE_TEMPLATE,
-            Self::get_horizontal_class(horizontal)
-        );
-
-        let role = Self::get_role(&other_props);
-
-        let content = Self::get_content(other_props, classes, node_ref, children, role);
-
+        } = props.clone();
         html! {
-            <ContextProvider<ClayCardContext> context={self.context.to_owned()}>
-                {content}
-            </ContextProvider<ClayCardContext>>
+            <ClayLink class={classes} {node_ref} anchor_props={other_props} {route}>
+                {children}
+            </ClayLink>
         }
     }
 
-    fn rendered(&mut self, ctx: &yew::Context<Self>, _first_render: bool) {
-        if let Some(other_props) = &ctx.props().other_props {
-            if !other_props.has_attribute(Href::KEY) {
-                let other_props = other_props.clone();
-                other_props.inject(&ctx.props().node_ref, &mut self.listeners);
-            }
-        }
+    #[cfg(not(feature = "router"))]
+    fn gen_route_link(_props: &Props, _classes: Classes) -> Html {
+        html! {}
+    }
+
+    fn get_role(other_props: &Option<GlobalProps>) -> Option<&'static str> {
+        other_props
+            .as_ref()
+            .filter(|other_props| other_props.has_listener(Self::CLICK))
+            .map(|_| "button")
+    }
+}
+
+#[function_component(ClayCardNavigation)]
+pub fn clay_card_navigation(props: &Props) -> Html {
+    let has_route = ClayCardNavigation::has_route(props);
+    let Props {
+        children,
+        other_props,
+        node_ref,
+        class,
+        horizontal,
+        ..
+    } = props.clone();
+
+    let context = ClayCardContext {
+        horizontal,
+        interactive: true,
+    };
+
+    let classes = classes!(
+        class,
+        ClayCardNavigation::CARD,
+        ClayCardNavigation::CARD_INTERACTIVE,
+        ClayCardNavigation::CARD_INTERACTIVE_PRIMARY,
+        ClayCardNavigation::CARD_TYPE_TEMPLATE,
+        ClayCardNavigation::get_horizontal_class(horizontal)
+    );
+
+    let content = if has_route {
+        ClayCardNavigation::gen_route_link(props, classes)
+    } else {
+        let role = ClayCardNavigation::get_role(&other_props);
+        ClayCardNavigation::get_content(other_props, classes, node_ref, children, role)
+    };
+
+    html! {
+        <ContextProvider<ClayCardContext> {context}>
+            {content}
+        </ContextProvider<ClayCardContext>>
     }
 }
@@ -1,5 +1,8 @@
+// Most of the card module isn't exported yet, see the TODO below.
+#![allow(dead_code)]
+
 use self::context::ClayCardContext;
-use yew::{Callback, Component, Context};
+use yew::{hook, use_context};
 
 pub mod aspect_ratio;
 mod body;
@@ -10,23 +13,21 @@ mod context;
 
 // TODO: Finish this once dropdown is finished
 
-trait Interactive: Component {
-    const SPAN: &'static str = "span";
-    const DIV: &'static str = "div";
+const SPAN: &str = "span";
+const DIV: &str = "div";
 
-    fn get_interactive(ctx: &Context<Self>) -> bool {
-        if let Some((context, _)) = ctx.link().context::<ClayCardContext>(Callback::noop()) {
-            context.interactive
-        } else {
-            false
-        }
-    }
+/// Reads from the ClayCardContext whether the card the component is in is interactive.
+#[hook]
+fn use_interactive() -> bool {
+    use_context::<ClayCardContext>()
+        .map(|context| context.interactive)
+        .unwrap_or_default()
+}
 
-    fn get_tag_name(interactive: bool) -> &'static str {
-        if interactive {
-            Self::SPAN
-        } else {
-            Self::DIV
-        }
+fn get_tag_name(interactive: bool) -> &'static str {
+    if interactive {
+        SPAN
+    } else {
+        DIV
     }
 }
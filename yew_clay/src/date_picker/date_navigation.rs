@@ -1,58 +1,142 @@
+use super::helpers::add_months;
+use super::types::{AriaLabels, Years};
+use crate::icon::{ClayIcon, ClaySymbol};
+use chrono::{Datelike, NaiveDate};
 use std::fmt::Debug;
+use std::str::FromStr;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, HtmlSelectElement, MouseEvent};
+use yew::{function_component, html, Callback, Html, Properties};
 
-use super::types::{AriaLabels, Years};
-use chrono::{Month, NaiveDate};
-use web_sys::MouseEvent;
-use yew::{Callback, Component, Context, NodeRef, Properties};
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct Props {
+    pub aria_labels: AriaLabels,
 
-#[derive(Debug, Properties, PartialEq)]
-struct Props {
-    aria_labels: AriaLabels,
-    current_month: Month,
+    /// The first day of the month that is currently displayed.
+    pub current_month: NaiveDate,
 
     #[prop_or_default]
-    disabled: bool,
-    months: Vec<&'static str>,
-    on_dot_clicked: Callback<MouseEvent>,
-    on_month_change: Callback<Month>,
+    pub disabled: bool,
+    pub months: Vec<&'static str>,
+    pub on_dot_clicked: Callback<MouseEvent>,
+    pub on_month_change: Callback<NaiveDate>,
 
     /// Path to the location of the spritemap resource.
     #[prop_or_default]
-    spritemap: &'static str,
+    pub spritemap: Option<&'static str>,
 
-    years: Years,
+    pub years: Years,
 }
 
-struct DateNavigation {
-    month_selector_ref: NodeRef,
-    year_selector_ref: NodeRef,
+const NAV_BUTTON: &str = "btn btn-sm btn-unstyled nav-btn nav-btn-monospaced date-picker-nav-btn";
+const SELECT: &str = "form-control form-control-sm";
+
+fn get_select_value<T: FromStr>(event: Event) -> Option<T> {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<HtmlSelectElement>().ok())
+        .and_then(|select| select.value().parse().ok())
 }
 
-impl DateNavigation {
-    fn handle_change_month(ctx: &Context<Self>, month: Month) {
-        let Props { current_month, .. } = ctx.props();
-        //let new_month = *current_month + month;
-        //let year = date.getFullYear();
+/// The header of the calendar, with the month and year selectors and the buttons that move
+/// between months.
+#[function_component(DateNavigation)]
+pub fn date_navigation(props: &Props) -> Html {
+    // Only the months of the selectable years are navigated to.
+    let change_month = {
+        let years = props.years;
+        let on_month_change = props.on_month_change.clone();
+        Callback::from(move |date: Option<NaiveDate>| {
+            if let Some(date) = date.filter(|date| years.contains(date.year())) {
+                on_month_change.emit(date);
+            }
+        })
+    };
 
-        // if (memoizedYears.find((elem) => elem.value === year)) {
-        // 	onMonthChange(date);
-        // }
-    }
-}
+    let Props {
+        aria_labels,
+        current_month,
+        disabled,
+        months,
+        on_dot_clicked,
+        spritemap,
+        years,
+        ..
+    } = props.clone();
 
-impl Component for DateNavigation {
-    type Message = ();
+    let previous_disabled = disabled || !years.contains(add_months(current_month, -1).year());
+    let next_disabled = disabled || !years.contains(add_months(current_month, 1).year());
 
-    type Properties = Props;
+    let on_month_select = change_month.reform(move |event: Event| {
+        get_select_value(event).and_then(|month| current_month.with_month(month))
+    });
 
-    fn create(_ctx: &yew::Context<Self>) -> Self {
-        Self {
-            month_selector_ref: NodeRef::default(),
-            year_selector_ref: NodeRef::default(),
-        }
-    }
+    let on_year_select = change_month.reform(move |event: Event| {
+        get_select_value(event).and_then(|year| current_month.with_year(year))
+    });
+
+    let on_previous_click =
+        change_month.reform(move |_: MouseEvent| Some(add_months(current_month, -1)));
+    let on_next_click =
+        change_month.reform(move |_: MouseEvent| Some(add_months(current_month, 1)));
 
-    fn view(&self, ctx: &yew::Context<Self>) -> yew::Html {
-        todo!()
+    html! {
+        <div class="date-picker-calendar-header">
+            <div class="date-picker-nav">
+                <div class="date-picker-nav-item input-date-picker-month">
+                    <select class={SELECT} disabled={disabled} onchange={on_month_select}>
+                        {months.iter().enumerate().map(|(index, month)| {
+                            let value = index as u32 + 1;
+                            html! {
+                                <option
+                                    key={value}
+                                    selected={value == current_month.month()}
+                                    value={value.to_string()} >
+                                    {*month}
+                                </option>
+                            }
+                        }).collect::<Html>()}
+                    </select>
+                </div>
+                <div class="date-picker-nav-item input-date-picker-year">
+                    <select class={SELECT} disabled={disabled} onchange={on_year_select}>
+                        {(years.start..=years.end).map(|year| html! {
+                            <option
+                                key={year}
+                                selected={year == current_month.year()}
+                                value={year.to_string()} >
+                                {year}
+                            </option>
+                        }).collect::<Html>()}
+                    </select>
+                </div>
+                <div class="date-picker-nav-item date-picker-nav-item-controls">
+                    <button
+                        aria-label={aria_labels.button_previous_month}
+                        class={NAV_BUTTON}
+                        disabled={previous_disabled}
+                        onclick={on_previous_click}
+                        type="button" >
+                        <ClayIcon {spritemap} symbol={ClaySymbol::AngleLeft} />
+                    </button>
+                    <button
+                        aria-label={aria_labels.button_dot}
+                        class={NAV_BUTTON}
+                        disabled={disabled}
+                        onclick={on_dot_clicked}
+                        type="button" >
+                        <ClayIcon {spritemap} symbol={ClaySymbol::SimpleCircle} />
+                    </button>
+                    <button
+                        aria-label={aria_labels.button_next_month}
+                        class={NAV_BUTTON}
+                        disabled={next_disabled}
+                        onclick={on_next_click}
+                        type="button" >
+                        <ClayIcon {spritemap} symbol={ClaySymbol::AngleRight} />
+                    </button>
+                </div>
+            </div>
+        </div>
     }
 }
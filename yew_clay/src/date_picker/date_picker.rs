@@ -0,0 +1,817 @@
+use super::date_navigation::DateNavigation;
+use super::day_names::DayNames;
+use super::days_table::DaysTable;
+use super::helpers::{
+    add_months, first_of_month, get_date_key, get_weeks_of_month, is_same_month, start_of_week,
+    today, DEFAULT_DATE_FORMAT, DEFAULT_MONTHS, DEFAULT_WEEKDAYS_SHORT,
+};
+use super::time_picker::TimePicker;
+use super::types::{AriaLabels, DateRange, FirstDayOfWeek, TimeFormat, Years};
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::icon::{ClayIcon, ClaySymbol};
+use crate::shared::keys::{ARROW_DOWN, ARROW_LEFT, ARROW_RIGHT, ARROW_UP, END, ESCAPE, HOME};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use gloo_events::EventListener;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{
+    Element, HtmlElement, HtmlInputElement, InputEvent, KeyboardEvent, MouseEvent, Node,
+};
+use yew::{
+    classes, function_component, html, use_effect, use_force_update, use_mut_ref, use_node_ref,
+    Callback, Classes, Html, NodeRef, Properties,
+};
+
+/// The state of a ClayDatePicker, kept between renders.
+struct DatePickerState {
+    /// The props of the latest render.
+    props: ClayDatePickerProps,
+    /// Holds the listener that is only needed while the calendar is open.
+    click_outside_listener: Option<EventListener>,
+    current_month: NaiveDate,
+    focused_date: NaiveDate,
+    selected: Option<NaiveDate>,
+    selected_range: Option<DateRange>,
+    /// The first date that was clicked while selecting a range.
+    range_start: Option<NaiveDate>,
+    hovered: Option<NaiveDate>,
+    time: NaiveTime,
+    input_value: String,
+    internal_expanded: bool,
+    focus_pending: bool,
+    toggle_ref: NodeRef,
+    /// The callbacks to emit and the focus changes to make once the state is no longer
+    /// borrowed, since they may call back into the picker.
+    effects: Vec<Box<dyn FnOnce()>>,
+}
+
+/// Props for ClayDatePicker. For details, check the docs:
+/// <https://clayui.com/docs/components/date-picker/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayDatePickerProps {
+    /// Labels for the aria attributes.
+    #[prop_or_default]
+    pub aria_labels: AriaLabels,
+
+    /// The format of the date, used both to display the value in the input and to parse what
+    /// the user types. Uses the `chrono` strftime syntax.
+    #[prop_or(DEFAULT_DATE_FORMAT.into())]
+    pub date_format: String,
+
+    /// Flag to disable the component, buttons, open the datepicker, etc...
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    /// Determines if the menu of the calendar is expanded or not (controlled).
+    #[prop_or_default]
+    pub expanded: Option<bool>,
+
+    /// Callback for when the expanded state changes (controlled).
+    #[prop_or_default]
+    pub on_expanded_change: Option<Callback<bool>>,
+
+    /// Set the first day of the week.
+    #[prop_or_default]
+    pub first_day_of_week: FirstDayOfWeek,
+
+    /// The month that is displayed when the calendar is first opened. Defaults to the month of
+    /// the value, or the current month.
+    #[prop_or_default]
+    pub initial_month: Option<NaiveDate>,
+
+    /// The names of the months.
+    #[prop_or(DEFAULT_MONTHS.to_vec())]
+    pub months: Vec<&'static str>,
+
+    /// Callback for when the selected date changes. Emits None when the input is cleared or
+    /// contains text that cannot be parsed.
+    #[prop_or_default]
+    pub on_value_change: Option<Callback<Option<NaiveDate>>>,
+
+    /// Flag to select a range of dates instead of a single one. Two consecutive months are
+    /// displayed, and the selection is reported through `on_range_change`.
+    #[prop_or(false)]
+    pub range: bool,
+
+    /// The selected range, when `range` is enabled.
+    #[prop_or_default]
+    pub range_value: Option<DateRange>,
+
+    /// Callback for when the selected range changes.
+    #[prop_or_default]
+    pub on_range_change: Option<Callback<Option<DateRange>>>,
+
+    /// Flag to show a time picker in the footer of the calendar. It has no effect when
+    /// `range` is enabled.
+    #[prop_or(false)]
+    pub time: bool,
+
+    /// Determines if the time picker uses 12 or 24 hours.
+    #[prop_or_default]
+    pub time_format: TimeFormat,
+
+    /// The selected date and time, when `time` is enabled.
+    #[prop_or_default]
+    pub date_time_value: Option<NaiveDateTime>,
+
+    /// Callback for when the selected date or time changes, when `time` is enabled.
+    #[prop_or_default]
+    pub on_date_time_change: Option<Callback<Option<NaiveDateTime>>>,
+
+    /// The placeholder of the input.
+    #[prop_or_default]
+    pub placeholder: Option<String>,
+
+    /// Path to the location of the spritemap resource.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    /// The selected date. Updating it updates the value of the picker.
+    #[prop_or_default]
+    pub value: Option<NaiveDate>,
+
+    /// The short names of the weekdays, starting on Sunday.
+    #[prop_or(DEFAULT_WEEKDAYS_SHORT.to_vec())]
+    pub weekdays_short: Vec<&'static str>,
+
+    /// The range of years that can be navigated to. Defaults to the current year.
+    #[prop_or_default]
+    pub years: Option<Years>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// NodeRef for the underlying input element.
+    #[prop_or_default]
+    pub input_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying input.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+enum Msg {
+    InputChange(String),
+    Toggle,
+    SetExpanded(bool),
+    MonthChange(NaiveDate),
+    DotClicked,
+    Select(NaiveDate),
+    Hover(NaiveDate),
+    MoveFocus(NaiveDate),
+    TimeChange(NaiveTime),
+}
+
+impl ClayDatePicker {
+    const DATE_PICKER: &'static str = "date-picker";
+    const INPUT: &'static str =
+        "form-control date-picker-input input-group-inset input-group-inset-after";
+    const TOGGLE: &'static str = "btn btn-unstyled date-picker-dropdown-toggle";
+    const MENU: &'static str = "dropdown-menu date-picker-dropdown-menu show";
+    const RANGE_SEPARATOR: &'static str = " - ";
+
+    fn get_years(props: &ClayDatePickerProps) -> Years {
+        props.years.unwrap_or_else(|| {
+            let year = today().year();
+            Years::new(year, year)
+        })
+    }
+
+    fn format_date(props: &ClayDatePickerProps, date: NaiveDate) -> String {
+        date.format(&props.date_format).to_string()
+    }
+
+    fn is_time_mode(props: &ClayDatePickerProps) -> bool {
+        props.time && !props.range
+    }
+
+    fn get_date_time_format(props: &ClayDatePickerProps) -> String {
+        let ClayDatePickerProps {
+            date_format,
+            time_format,
+            ..
+        } = props;
+        format!("{} {}", date_format, time_format.as_format())
+    }
+
+    fn parse_range(props: &ClayDatePickerProps, text: &str) -> Option<DateRange> {
+        let date_format = &props.date_format;
+        let (start, end) = text.split_once(Self::RANGE_SEPARATOR)?;
+        let start = NaiveDate::parse_from_str(start.trim(), date_format).ok()?;
+        let end = NaiveDate::parse_from_str(end.trim(), date_format).ok()?;
+        Some(DateRange::new(start, end))
+    }
+
+    fn get_key_down_handler(
+        focused_date: NaiveDate,
+        first_day_of_week: FirstDayOfWeek,
+        dispatch: &Callback<Msg>,
+    ) -> Callback<KeyboardEvent> {
+        let first_day_of_week = first_day_of_week.into();
+
+        let dispatch = dispatch.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            let target = match event.key().as_str() {
+                ARROW_LEFT => focused_date - Duration::days(1),
+                ARROW_RIGHT => focused_date + Duration::days(1),
+                ARROW_UP => focused_date - Duration::days(7),
+                ARROW_DOWN => focused_date + Duration::days(7),
+                HOME => start_of_week(focused_date, first_day_of_week),
+                END => start_of_week(focused_date, first_day_of_week) + Duration::days(6),
+                _ => return,
+            };
+            event.prevent_default();
+            dispatch.emit(Msg::MoveFocus(target));
+        })
+    }
+
+    fn focus_date(calendar_ref: &NodeRef, date: NaiveDate) {
+        let selector = format!("[data-date='{}']", get_date_key(date));
+        let button = calendar_ref
+            .cast::<Element>()
+            .and_then(|calendar| calendar.query_selector(&selector).ok().flatten())
+            .and_then(|button| button.dyn_into::<HtmlElement>().ok());
+
+        if let Some(button) = button {
+            if let Err(err) = button.focus() {
+                gloo_console::error!(err);
+            }
+        }
+    }
+
+    fn create_click_outside_listener(
+        node_ref: NodeRef,
+        dispatch: Callback<Msg>,
+    ) -> Option<EventListener> {
+        let document = web_sys::window().and_then(|window| window.document())?;
+
+        Some(EventListener::new(&document, "mousedown", move |event| {
+            let target = event
+                .target()
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            let inside = match (node_ref.cast::<Node>(), target) {
+                (Some(node), Some(target)) => node.contains(Some(&target)),
+                _ => false,
+            };
+            if !inside {
+                dispatch.emit(Msg::SetExpanded(false));
+            }
+        }))
+    }
+}
+
+impl DatePickerState {
+    fn new(props: &ClayDatePickerProps, toggle_ref: NodeRef) -> Self {
+        let ClayDatePickerProps {
+            range_value,
+            date_time_value,
+            initial_month,
+            expanded,
+            ..
+        } = props;
+
+        let value = Self::get_value(props);
+        let time = date_time_value
+            .map(|value| value.time())
+            .unwrap_or_default();
+        let current_month = first_of_month(
+            initial_month
+                .or(value)
+                .or(range_value.map(|range| range.start))
+                .unwrap_or_else(today),
+        );
+
+        let mut state = Self {
+            props: props.clone(),
+            click_outside_listener: None,
+            current_month,
+            focused_date: value.unwrap_or(current_month),
+            selected: value,
+            selected_range: *range_value,
+            range_start: None,
+            hovered: None,
+            time,
+            input_value: String::new(),
+            internal_expanded: expanded.unwrap_or(false),
+            focus_pending: false,
+            toggle_ref,
+            effects: Vec::new(),
+        };
+        state.input_value = state.format_value();
+        state
+    }
+
+    /// The selected date given by the props, which in time mode can also come from
+    /// `date_time_value`.
+    fn get_value(props: &ClayDatePickerProps) -> Option<NaiveDate> {
+        if ClayDatePicker::is_time_mode(props) {
+            props
+                .date_time_value
+                .map(|value| value.date())
+                .or(props.value)
+        } else {
+            props.value
+        }
+    }
+
+    fn is_expanded(&self) -> bool {
+        self.props.expanded.unwrap_or(self.internal_expanded)
+    }
+
+    /// Formats the current selection for the input, according to the mode of the picker.
+    fn format_value(&self) -> String {
+        let props = &self.props;
+        if props.range {
+            self.selected_range
+                .map(|range| {
+                    let mut value = ClayDatePicker::format_date(props, range.start);
+                    value.push_str(ClayDatePicker::RANGE_SEPARATOR);
+                    value.push_str(&ClayDatePicker::format_date(props, range.end));
+                    value
+                })
+                .unwrap_or_default()
+        } else if ClayDatePicker::is_time_mode(props) {
+            let format = ClayDatePicker::get_date_time_format(props);
+            self.selected
+                .map(|date| date.and_time(self.time).format(&format).to_string())
+                .unwrap_or_default()
+        } else {
+            self.selected
+                .map(|date| ClayDatePicker::format_date(props, date))
+                .unwrap_or_default()
+        }
+    }
+
+    /// Emits the value to the callback once the state is no longer borrowed.
+    fn defer_emit<T: 'static>(&mut self, callback: Option<Callback<T>>, value: T) {
+        if let Some(callback) = callback {
+            self.effects.push(Box::new(move || callback.emit(value)));
+        }
+    }
+
+    fn emit_date_time(&mut self) {
+        let value = self.selected.map(|date| date.and_time(self.time));
+        self.defer_emit(self.props.on_date_time_change.clone(), value);
+    }
+
+    fn set_selected_range(&mut self, selected_range: Option<DateRange>) {
+        if self.selected_range == selected_range {
+            return;
+        }
+
+        self.selected_range = selected_range;
+        self.defer_emit(self.props.on_range_change.clone(), selected_range);
+    }
+
+    fn is_visible(&self, date: NaiveDate) -> bool {
+        is_same_month(date, self.current_month)
+            || (self.props.range && is_same_month(date, add_months(self.current_month, 1)))
+    }
+
+    fn set_expanded(&mut self, expanded: bool) -> bool {
+        if self.is_expanded() == expanded {
+            return false;
+        }
+
+        self.defer_emit(self.props.on_expanded_change.clone(), expanded);
+
+        if self.props.expanded.is_none() {
+            self.internal_expanded = expanded;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn set_selected(&mut self, selected: Option<NaiveDate>) {
+        if self.selected == selected {
+            return;
+        }
+
+        self.selected = selected;
+        self.defer_emit(self.props.on_value_change.clone(), selected);
+        if ClayDatePicker::is_time_mode(&self.props) {
+            self.emit_date_time();
+        }
+    }
+
+    /// Moves the displayed month, as long as it is within the range of years.
+    fn show_month(&mut self, date: NaiveDate) -> bool {
+        if !ClayDatePicker::get_years(&self.props).contains(date.year()) {
+            return false;
+        }
+
+        self.current_month = first_of_month(date);
+        if !is_same_month(self.focused_date, date) {
+            self.focused_date = self.current_month;
+        }
+        true
+    }
+
+    fn focus_toggle(&mut self) {
+        let toggle_ref = self.toggle_ref.clone();
+        self.effects.push(Box::new(move || {
+            if let Some(toggle) = toggle_ref.cast::<HtmlElement>() {
+                if let Err(err) = toggle.focus() {
+                    gloo_console::error!(err);
+                }
+            }
+        }));
+    }
+
+    /// Applies a message and returns whether the picker has to be rendered again. The
+    /// callbacks are not emitted here, but added to `effects`.
+    fn update(&mut self, msg: Msg) -> bool {
+        let props = self.props.clone();
+        match msg {
+            Msg::InputChange(text) => {
+                if props.range {
+                    let parsed = ClayDatePicker::parse_range(&props, &text);
+                    if let Some(range) = parsed {
+                        if self.show_month(range.start) {
+                            self.focused_date = range.start;
+                        }
+                    }
+                    self.range_start = None;
+                    self.set_selected_range(parsed);
+                } else if ClayDatePicker::is_time_mode(&props) {
+                    let format = ClayDatePicker::get_date_time_format(&props);
+                    let parsed = NaiveDateTime::parse_from_str(&text, &format).ok();
+                    if let Some(date_time) = parsed {
+                        if self.show_month(date_time.date()) {
+                            self.focused_date = date_time.date();
+                        }
+                        if self.time != date_time.time() && self.selected == Some(date_time.date())
+                        {
+                            self.time = date_time.time();
+                            self.emit_date_time();
+                        }
+                        self.time = date_time.time();
+                    }
+                    self.set_selected(parsed.map(|date_time| date_time.date()));
+                } else {
+                    let parsed = NaiveDate::parse_from_str(&text, &props.date_format).ok();
+                    if let Some(date) = parsed {
+                        if self.show_month(date) {
+                            self.focused_date = date;
+                        }
+                    }
+                    self.set_selected(parsed);
+                }
+                self.input_value = text;
+                true
+            }
+            Msg::Toggle => {
+                let expanded = !self.is_expanded();
+                self.set_expanded(expanded)
+            }
+            Msg::SetExpanded(expanded) => {
+                let changed = self.set_expanded(expanded);
+                if !expanded && changed {
+                    self.focus_toggle();
+                }
+                changed
+            }
+            Msg::MonthChange(month) => self.show_month(month),
+            Msg::DotClicked => {
+                let today = today();
+                if self.show_month(today) {
+                    self.focused_date = today;
+                }
+                if !props.range {
+                    self.set_selected(Some(today));
+                    self.input_value = self.format_value();
+                }
+                true
+            }
+            Msg::Select(date) => {
+                self.focused_date = date;
+                if props.range {
+                    match self.range_start.take() {
+                        Some(start) => {
+                            self.hovered = None;
+                            self.set_selected_range(Some(DateRange::new(start, date)));
+                            self.input_value = self.format_value();
+                            self.set_expanded(false);
+                        }
+                        None => self.range_start = Some(date),
+                    }
+                } else {
+                    self.set_selected(Some(date));
+                    self.input_value = self.format_value();
+                    // The calendar stays open so that the time can be picked as well.
+                    if !ClayDatePicker::is_time_mode(&props) {
+                        self.set_expanded(false);
+                    }
+                }
+                true
+            }
+            Msg::Hover(date) => {
+                if self.range_start.is_none() || self.hovered == Some(date) {
+                    return false;
+                }
+                self.hovered = Some(date);
+                true
+            }
+            Msg::MoveFocus(date) => {
+                if !self.is_visible(date) && !self.show_month(date) {
+                    return false;
+                }
+                self.focused_date = date;
+                if self.range_start.is_some() {
+                    self.hovered = Some(date);
+                }
+                self.focus_pending = true;
+                true
+            }
+            Msg::TimeChange(time) => {
+                self.time = time;
+                if self.selected.is_some() {
+                    self.input_value = self.format_value();
+                    self.emit_date_time();
+                }
+                true
+            }
+        }
+    }
+
+    /// Takes the props of a new render, syncing the selection with the controlled values that
+    /// changed since the previous render.
+    fn changed(&mut self, props: &ClayDatePickerProps) {
+        let old_props = std::mem::replace(&mut self.props, props.clone());
+        let time_changed = ClayDatePicker::is_time_mode(props)
+            && props.date_time_value != old_props.date_time_value;
+        let value = Self::get_value(props);
+        let value_changed = value != Self::get_value(&old_props);
+        let range_changed = props.range_value != old_props.range_value;
+
+        if time_changed {
+            if let Some(date_time) = props.date_time_value {
+                self.time = date_time.time();
+            }
+        }
+
+        if value_changed {
+            self.selected = value;
+            if let Some(value) = value {
+                if self.show_month(value) {
+                    self.focused_date = value;
+                }
+            }
+        }
+
+        if range_changed {
+            self.selected_range = props.range_value;
+            self.range_start = None;
+            if let Some(range) = props.range_value {
+                self.show_month(range.start);
+            }
+        }
+
+        // Otherwise, the input keeps what the user is typing.
+        if time_changed || value_changed || range_changed {
+            self.input_value = self.format_value();
+        }
+    }
+}
+
+/// A Yew implementation of ClayDatePicker. For more info about ClayDatePicker, check the
+/// documentation: <https://clayui.com/docs/components/date-picker.html>
+#[function_component(ClayDatePicker)]
+pub fn clay_date_picker(props: &ClayDatePickerProps) -> Html {
+    let calendar_ref = use_node_ref();
+    let toggle_ref = use_node_ref();
+    let state = {
+        let toggle_ref = toggle_ref.clone();
+        use_mut_ref(|| DatePickerState::new(props, toggle_ref))
+    };
+    let force_update = use_force_update();
+
+    if state.borrow().props != *props {
+        state.borrow_mut().changed(props);
+    }
+
+    // The dispatcher only holds a weak reference, since the state keeps the listener that uses
+    // it while the calendar is open.
+    let dispatch = {
+        let state = Rc::downgrade(&state);
+        Callback::from(move |msg: Msg| {
+            let (should_render, effects) = match state.upgrade() {
+                Some(state) => {
+                    let mut state = state.borrow_mut();
+                    let should_render = state.update(msg);
+                    (should_render, std::mem::take(&mut state.effects))
+                }
+                None => return,
+            };
+            for effect in effects {
+                effect();
+            }
+            if should_render {
+                force_update.force_update();
+            }
+        })
+    };
+
+    {
+        let state = state.clone();
+        let dispatch = dispatch.clone();
+        let calendar_ref = calendar_ref.clone();
+        let node_ref = props.node_ref.clone();
+        use_effect(move || {
+            let focused_date = {
+                let mut state = state.borrow_mut();
+                if !state.is_expanded() {
+                    state.click_outside_listener = None;
+                } else if state.click_outside_listener.is_none() {
+                    state.click_outside_listener =
+                        ClayDatePicker::create_click_outside_listener(node_ref, dispatch);
+                }
+
+                let focus_pending = state.focus_pending;
+                state.focus_pending = false;
+                focus_pending.then_some(state.focused_date)
+            };
+
+            if let Some(focused_date) = focused_date {
+                ClayDatePicker::focus_date(&calendar_ref, focused_date);
+            }
+            || ()
+        });
+    }
+
+    let ClayDatePickerProps {
+        aria_labels,
+        disabled,
+        first_day_of_week,
+        months,
+        placeholder,
+        spritemap,
+        weekdays_short,
+        class,
+        node_ref,
+        input_ref,
+        range,
+        time_format,
+        ..
+    } = props.clone();
+
+    let state = state.borrow();
+    let expanded = state.is_expanded();
+    let first_weekday = first_day_of_week.into();
+
+    let oninput = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |event: InputEvent| {
+            let input = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok());
+            if let Some(input) = input {
+                dispatch.emit(Msg::InputChange(input.value()));
+            }
+        })
+    };
+
+    let onkeydown = {
+        let dispatch = dispatch.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if event.key() == ESCAPE {
+                dispatch.emit(Msg::SetExpanded(false));
+            }
+        })
+    };
+
+    let highlighted_range = match (state.range_start, state.hovered) {
+        (Some(start), Some(hovered)) => Some(DateRange::new(start, hovered)),
+        (Some(start), None) => Some(DateRange::new(start, start)),
+        _ => state.selected_range,
+    };
+
+    let gen_days_table = |month: NaiveDate| {
+        let (selected, range, on_hover) = if range {
+            (None, highlighted_range, Some(dispatch.reform(Msg::Hover)))
+        } else {
+            (state.selected, None, None)
+        };
+
+        html! {
+            <DaysTable
+                current_month={month}
+                {disabled}
+                focused_date={state.focused_date}
+                {on_hover}
+                on_key_down={ClayDatePicker::get_key_down_handler(state.focused_date, first_day_of_week, &dispatch)}
+                on_select={dispatch.reform(Msg::Select)}
+                {range}
+                {selected}
+                weeks={get_weeks_of_month(month, first_weekday)} />
+        }
+    };
+
+    let second_calendar = if range {
+        let next_month = add_months(state.current_month, 1);
+        let month_name = months
+            .get(next_month.month0() as usize)
+            .copied()
+            .unwrap_or_default();
+
+        html! {
+            <div class="date-picker-calendar">
+                <div class="date-picker-calendar-header">
+                    <div class="date-picker-nav">
+                        <div class="date-picker-nav-item">
+                            {format!("{} {}", month_name, next_month.year())}
+                        </div>
+                    </div>
+                </div>
+                <DayNames {first_day_of_week} weekdays_short={weekdays_short.clone()} />
+                {gen_days_table(next_month)}
+            </div>
+        }
+    } else {
+        html! {}
+    };
+
+    let time_picker = if ClayDatePicker::is_time_mode(props) {
+        html! {
+            <TimePicker
+                aria_labels={aria_labels.clone()}
+                {disabled}
+                on_change={dispatch.reform(Msg::TimeChange)}
+                {time_format}
+                value={state.time} />
+        }
+    } else {
+        html! {}
+    };
+
+    let calendar = if expanded {
+        html! {
+            <div class={ClayDatePicker::MENU}>
+                <div class="date-picker-calendars d-flex" ref={calendar_ref}>
+                    <div class="date-picker-calendar">
+                        <DateNavigation
+                            aria_labels={aria_labels.clone()}
+                            current_month={state.current_month}
+                            {disabled}
+                            months={months.clone()}
+                            on_dot_clicked={dispatch.reform(|_: MouseEvent| Msg::DotClicked)}
+                            on_month_change={dispatch.reform(Msg::MonthChange)}
+                            {spritemap}
+                            years={ClayDatePicker::get_years(props)} />
+                        <DayNames {first_day_of_week} weekdays_short={weekdays_short.clone()} />
+                        {gen_days_table(state.current_month)}
+                        {time_picker}
+                    </div>
+                    {second_calendar}
+                </div>
+            </div>
+        }
+    } else {
+        html! {}
+    };
+
+    let input = with_html_props(
+        html! {
+            <input
+                aria-label={aria_labels.input}
+                class={ClayDatePicker::INPUT}
+                disabled={disabled}
+                oninput={oninput}
+                placeholder={placeholder}
+                ref={input_ref}
+                type="text"
+                value={state.input_value.clone()} />
+        },
+        props.html_props.as_ref(),
+    );
+
+    html! {
+        <div
+            class={classes!(class, ClayDatePicker::DATE_PICKER)}
+            onkeydown={onkeydown}
+            ref={node_ref} >
+            <div class="input-group">
+                <div class="input-group-item">
+                    {input}
+                    <div class="input-group-inset-item input-group-inset-item-after">
+                        <button
+                            aria-expanded={expanded.to_string()}
+                            aria-label={aria_labels.button_choose_date}
+                            class={ClayDatePicker::TOGGLE}
+                            disabled={disabled}
+                            onclick={dispatch.reform(|_: MouseEvent| Msg::Toggle)}
+                            ref={toggle_ref}
+                            type="button" >
+                            <ClayIcon {spritemap} symbol={ClaySymbol::Calendar} />
+                        </button>
+                    </div>
+                </div>
+            </div>
+            {calendar}
+        </div>
+    }
+}
@@ -0,0 +1,25 @@
+use super::helpers::get_weekdays;
+use super::types::FirstDayOfWeek;
+use yew::{function_component, html, Html, Properties};
+
+#[derive(Debug, Properties, PartialEq)]
+pub struct DayNamesProps {
+    pub first_day_of_week: FirstDayOfWeek,
+    pub weekdays_short: Vec<&'static str>,
+}
+
+/// Renders the row with the names of the weekdays.
+#[function_component(DayNames)]
+pub fn day_names(props: &DayNamesProps) -> Html {
+    let weekdays = get_weekdays(&props.weekdays_short, props.first_day_of_week.into());
+
+    html! {
+        <div class="date-picker-days-row date-picker-row">
+            {weekdays.into_iter().enumerate().map(|(index, weekday)| html! {
+                <div class="date-picker-col" key={index}>
+                    <div class="date-picker-day">{weekday}</div>
+                </div>
+            }).collect::<Html>()}
+        </div>
+    }
+}
@@ -0,0 +1,108 @@
+use super::helpers::{get_date_key, is_same_month};
+use super::types::DateRange;
+use chrono::{Datelike, NaiveDate};
+use web_sys::{KeyboardEvent, MouseEvent};
+use yew::{classes, function_component, html, Callback, Html, NodeRef, Properties};
+
+#[derive(Debug, Properties, PartialEq)]
+pub struct DaysTableProps {
+    /// The first day of the month that is currently displayed.
+    pub current_month: NaiveDate,
+
+    #[prop_or_default]
+    pub disabled: bool,
+
+    /// The day that can be reached with the Tab key.
+    pub focused_date: NaiveDate,
+
+    /// Called with the date under the pointer, used to preview a range.
+    #[prop_or_default]
+    pub on_hover: Option<Callback<NaiveDate>>,
+
+    pub on_key_down: Callback<KeyboardEvent>,
+    pub on_select: Callback<NaiveDate>,
+
+    /// The range to highlight, when selecting a range of dates.
+    #[prop_or_default]
+    pub range: Option<DateRange>,
+
+    #[prop_or_default]
+    pub selected: Option<NaiveDate>,
+
+    pub weeks: Vec<Vec<NaiveDate>>,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+}
+
+/// Renders the grid with the days of the displayed month.
+#[function_component(DaysTable)]
+pub fn days_table(props: &DaysTableProps) -> Html {
+    let DaysTableProps {
+        current_month,
+        disabled,
+        focused_date,
+        on_hover,
+        on_key_down,
+        on_select,
+        range,
+        selected,
+        weeks,
+        node_ref,
+    } = props;
+
+    html! {
+        <div class="date-picker-calendar-body" onkeydown={on_key_down.clone()} ref={node_ref.clone()}>
+            {weeks.iter().enumerate().map(|(index, week)| html! {
+                <div class="date-picker-date-row date-picker-row" key={index}>
+                    {week.iter().map(|date| {
+                        let date = *date;
+                        let outside_month = !is_same_month(date, *current_month);
+                        let previous_month = outside_month && date < *current_month;
+                        let next_month = outside_month && date > *current_month;
+                        let in_range = range.map(|range| range.contains(date)).unwrap_or(false);
+                        let range_start = range.map(|range| range.start == date).unwrap_or(false);
+                        let range_end = range.map(|range| range.end == date).unwrap_or(false);
+                        let active = Some(date) == *selected || range_start || range_end;
+                        let tab_index = if date == *focused_date { "0" } else { "-1" };
+                        let on_select = on_select.clone();
+                        let onmouseenter = on_hover
+                            .clone()
+                            .map(|on_hover| Callback::from(move |_: MouseEvent| on_hover.emit(date)));
+                        let key = get_date_key(date);
+
+                        html! {
+                            <div
+                                class={classes!(
+                                    "date-picker-col",
+                                    in_range.then_some("c-selected"),
+                                    range_start.then_some("c-selected-start"),
+                                    range_end.then_some("c-selected-end")
+                                )}
+                                key={key.clone()}
+                                onmouseenter={onmouseenter} >
+                                <button
+                                    aria-label={date.format("%Y %m %d").to_string()}
+                                    aria-selected={active.to_string()}
+                                    class={classes!(
+                                        "date-picker-date",
+                                        "date-picker-calendar-item",
+                                        active.then_some("active"),
+                                        previous_month.then_some("previous-month-date"),
+                                        next_month.then_some("next-month-date")
+                                    )}
+                                    data-date={key}
+                                    disabled={*disabled}
+                                    onclick={Callback::from(move |_| on_select.emit(date))}
+                                    tabindex={tab_index}
+                                    type="button" >
+                                    {date.day()}
+                                </button>
+                            </div>
+                        }
+                    }).collect::<Html>()}
+                </div>
+            }).collect::<Html>()}
+        </div>
+    }
+}
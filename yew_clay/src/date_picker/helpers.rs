@@ -0,0 +1,99 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+pub const DEFAULT_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+pub const DEFAULT_WEEKDAYS_SHORT: [&str; 7] = ["S", "M", "T", "W", "T", "F", "S"];
+
+pub const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Returns the current date according to the browser.
+#[cfg(any(target_arch = "wasm32", not(feature = "ssr")))]
+pub fn today() -> NaiveDate {
+    let now = js_sys::Date::new_0();
+    NaiveDate::from_ymd_opt(
+        now.get_full_year() as i32,
+        now.get_month() + 1,
+        now.get_date(),
+    )
+    .unwrap_or(NaiveDate::MIN)
+}
+
+/// Returns the current date according to the server, since there is no browser to ask.
+#[cfg(all(not(target_arch = "wasm32"), feature = "ssr"))]
+pub fn today() -> NaiveDate {
+    chrono::Local::now().naive_local().date()
+}
+
+pub fn first_of_month(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).expect("every month to have a first day")
+}
+
+pub fn days_in_month(date: NaiveDate) -> u32 {
+    let first_of_next = first_of_month(date) + Duration::days(32);
+    (first_of_month(first_of_next) - Duration::days(1)).day()
+}
+
+/// Adds a number of months to a date. If the day does not exist in the resulting month, the last
+/// day of that month is used instead.
+pub fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let total = date.year() * 12 + date.month0() as i32 + months;
+    let year = total.div_euclid(12);
+    let month = total.rem_euclid(12) as u32 + 1;
+    let first = NaiveDate::from_ymd_opt(year, month, 1).unwrap_or(date);
+    let day = date.day().min(days_in_month(first));
+    first.with_day(day).unwrap_or(first)
+}
+
+/// Returns the first day of the week that contains the date.
+pub fn start_of_week(date: NaiveDate, first_day_of_week: Weekday) -> NaiveDate {
+    let offset =
+        (date.weekday().num_days_from_sunday() + 7 - first_day_of_week.num_days_from_sunday()) % 7;
+    date - Duration::days(offset as i64)
+}
+
+/// Returns the six weeks that are displayed in the calendar for the month of the date.
+pub fn get_weeks_of_month(month: NaiveDate, first_day_of_week: Weekday) -> Vec<Vec<NaiveDate>> {
+    let start = start_of_week(first_of_month(month), first_day_of_week);
+
+    (0..6)
+        .map(|week| {
+            (0..7)
+                .map(|day| start + Duration::days(week * 7 + day))
+                .collect()
+        })
+        .collect()
+}
+
+/// Rotates the names of the weekdays, which start on Sunday, so that they start on the given day.
+pub fn get_weekdays<'a>(weekdays: &[&'a str], first_day_of_week: Weekday) -> Vec<&'a str> {
+    let offset = first_day_of_week.num_days_from_sunday() as usize;
+    weekdays
+        .iter()
+        .cycle()
+        .skip(offset)
+        .take(weekdays.len())
+        .copied()
+        .collect()
+}
+
+pub fn is_same_month(left: NaiveDate, right: NaiveDate) -> bool {
+    left.year() == right.year() && left.month() == right.month()
+}
+
+/// Formats a date so that it can be used to identify the day button in the DOM.
+pub fn get_date_key(date: NaiveDate) -> String {
+    date.format(DEFAULT_DATE_FORMAT).to_string()
+}
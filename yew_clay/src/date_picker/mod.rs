@@ -1,3 +1,10 @@
-// TODO: Finish this mod after creating Select component
 mod date_navigation;
+mod date_picker;
+mod day_names;
+mod days_table;
+mod helpers;
+mod time_picker;
 mod types;
+
+pub use date_picker::*;
+pub use types::*;
@@ -0,0 +1,158 @@
+use super::types::{AriaLabels, TimeFormat};
+use chrono::{NaiveTime, Timelike};
+use wasm_bindgen::JsCast;
+use web_sys::{Event, HtmlInputElement, MouseEvent};
+use yew::{function_component, html, Callback, Html, Properties};
+
+#[derive(Debug, Properties, PartialEq)]
+pub struct TimePickerProps {
+    #[prop_or_default]
+    pub aria_labels: AriaLabels,
+
+    #[prop_or_default]
+    pub disabled: bool,
+
+    pub on_change: Callback<NaiveTime>,
+
+    #[prop_or_default]
+    pub time_format: TimeFormat,
+
+    pub value: NaiveTime,
+}
+
+fn get_input(event: &Event) -> Option<HtmlInputElement> {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<HtmlInputElement>().ok())
+}
+
+fn format_input_value(value: u32) -> String {
+    format!("{:02}", value)
+}
+
+/// Parses the value of the input. When it is not valid, the input is reset to `last_value`,
+/// since the time would not change and the input would not be rendered again.
+fn get_input_value(event: &Event, is_valid: impl Fn(u32) -> bool, last_value: u32) -> Option<u32> {
+    let input = get_input(event)?;
+    match input.value().parse::<u32>() {
+        Ok(value) if is_valid(value) => Some(value),
+        _ => {
+            input.set_value(&format_input_value(last_value));
+            None
+        }
+    }
+}
+
+/// Renders the hours and minutes inputs shown in the footer of the calendar.
+#[function_component(TimePicker)]
+pub fn time_picker(props: &TimePickerProps) -> Html {
+    let TimePickerProps {
+        aria_labels,
+        disabled,
+        on_change,
+        time_format,
+        value,
+    } = props;
+    let value = *value;
+    let (is_pm, hour12) = value.hour12();
+    let twelve_hours = *time_format == TimeFormat::TwelveHours;
+
+    let (displayed_hour, min_hour, max_hour) = if twelve_hours {
+        (hour12, "1", "12")
+    } else {
+        (value.hour(), "0", "23")
+    };
+
+    let on_hours_change = {
+        let on_change = on_change.clone();
+        Callback::from(move |event: Event| {
+            let is_valid = |hour| {
+                if twelve_hours {
+                    (1..=12).contains(&hour)
+                } else {
+                    hour < 24
+                }
+            };
+            let hour = match get_input_value(&event, is_valid, displayed_hour) {
+                Some(hour) if twelve_hours => hour % 12 + if is_pm { 12 } else { 0 },
+                Some(hour) => hour,
+                None => return,
+            };
+            if let Some(time) = value.with_hour(hour) {
+                on_change.emit(time);
+            }
+        })
+    };
+
+    let on_minutes_change = {
+        let on_change = on_change.clone();
+        Callback::from(move |event: Event| {
+            let minute = match get_input_value(&event, |minute| minute < 60, value.minute()) {
+                Some(minute) => minute,
+                None => return,
+            };
+            if let Some(time) = value.with_minute(minute) {
+                on_change.emit(time);
+            }
+        })
+    };
+
+    let on_period_toggle = {
+        let on_change = on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            let hour = (value.hour() + 12) % 24;
+            if let Some(time) = value.with_hour(hour) {
+                on_change.emit(time);
+            }
+        })
+    };
+
+    let period_toggle = if twelve_hours {
+        html! {
+            <div class="input-group-item input-group-item-shrink">
+                <button
+                    class="btn btn-sm btn-secondary"
+                    disabled={*disabled}
+                    onclick={on_period_toggle}
+                    type="button" >
+                    {if is_pm { "PM" } else { "AM" }}
+                </button>
+            </div>
+        }
+    } else {
+        html! {}
+    };
+
+    html! {
+        <div class="date-picker-calendar-footer">
+            <div class="clay-time">
+                <div class="input-group input-group-sm">
+                    <div class="input-group-item">
+                        <input
+                            aria-label={aria_labels.input_hours}
+                            class="form-control form-control-sm"
+                            disabled={*disabled}
+                            max={max_hour}
+                            min={min_hour}
+                            onchange={on_hours_change}
+                            type="number"
+                            value={format_input_value(displayed_hour)} />
+                    </div>
+                    <div class="input-group-item input-group-item-shrink">{":"}</div>
+                    <div class="input-group-item">
+                        <input
+                            aria-label={aria_labels.input_minutes}
+                            class="form-control form-control-sm"
+                            disabled={*disabled}
+                            max="59"
+                            min="0"
+                            onchange={on_minutes_change}
+                            type="number"
+                            value={format_input_value(value.minute())} />
+                    </div>
+                    {period_toggle}
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -1,4 +1,9 @@
-enum FirstDayOfWeek {
+use chrono::{NaiveDate, Weekday};
+
+/// The day that the weeks of the calendar start with.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum FirstDayOfWeek {
+    #[default]
     Sunday = 0,
     Monday = 1,
     Tuesday = 2,
@@ -8,17 +13,105 @@ enum FirstDayOfWeek {
     Saturday = 6,
 }
 
-#[derive(Debug, PartialEq)]
+impl From<FirstDayOfWeek> for Weekday {
+    fn from(first_day_of_week: FirstDayOfWeek) -> Self {
+        match first_day_of_week {
+            FirstDayOfWeek::Sunday => Weekday::Sun,
+            FirstDayOfWeek::Monday => Weekday::Mon,
+            FirstDayOfWeek::Tuesday => Weekday::Tue,
+            FirstDayOfWeek::Wednesday => Weekday::Wed,
+            FirstDayOfWeek::Thursday => Weekday::Thu,
+            FirstDayOfWeek::Friday => Weekday::Fri,
+            FirstDayOfWeek::Saturday => Weekday::Sat,
+        }
+    }
+}
+
+/// Labels for the aria attributes of the date picker.
+#[derive(Debug, PartialEq, Clone)]
 pub struct AriaLabels {
-    button_choose_date: &'static str,
-    button_dot: &'static str,
-    button_next_month: &'static str,
-    button_previous_month: &'static str,
-    input: Option<&'static str>,
+    pub button_choose_date: &'static str,
+    pub button_dot: &'static str,
+    pub button_next_month: &'static str,
+    pub button_previous_month: &'static str,
+    pub input: Option<&'static str>,
+    pub input_hours: &'static str,
+    pub input_minutes: &'static str,
+}
+
+impl Default for AriaLabels {
+    fn default() -> Self {
+        Self {
+            button_choose_date: "Choose date",
+            button_dot: "Select current date",
+            button_next_month: "Select the next month",
+            button_previous_month: "Select the previous month",
+            input: None,
+            input_hours: "Hours",
+            input_minutes: "Minutes",
+        }
+    }
 }
 
-#[derive(Debug, PartialEq)]
+/// The range of years that can be navigated to in the date picker.
+#[derive(Debug, PartialEq, Clone, Copy)]
 pub struct Years {
-    end: u8,
-    start: u8,
+    pub end: i32,
+    pub start: i32,
+}
+
+impl Years {
+    pub fn new(start: i32, end: i32) -> Self {
+        Self { end, start }
+    }
+
+    pub fn contains(&self, year: i32) -> bool {
+        self.start <= year && year <= self.end
+    }
+}
+
+/// A range of dates selected in the date picker. The start is never after the end.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DateRange {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+}
+
+impl DateRange {
+    /// Creates a range between two dates, in whichever order they are given.
+    pub fn new(first: NaiveDate, second: NaiveDate) -> Self {
+        if first <= second {
+            Self {
+                start: first,
+                end: second,
+            }
+        } else {
+            Self {
+                start: second,
+                end: first,
+            }
+        }
+    }
+
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
+/// Determines how hours are displayed in the time picker.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum TimeFormat {
+    #[default]
+    TwentyFourHours,
+    TwelveHours,
+}
+
+impl TimeFormat {
+    /// The `chrono` format string used to display and parse times.
+    pub fn as_format(&self) -> &'static str {
+        match self {
+            TimeFormat::TwentyFourHours => "%H:%M",
+            TimeFormat::TwelveHours => "%I:%M %p",
+        }
+    }
 }
@@ -1,51 +1,44 @@
-use yew::{classes, html, Children, Classes, Component, Html, NodeRef, Properties};
-use yew_dom_attributes::button_props::ButtonProps;
+use crate::html_props::ButtonProps;
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 use crate::ClayButton;
 
+/// Props for ClayDropDownAction. For details, check the docs:
+/// <https://clayui.com/docs/components/drop-down/api.html>
 #[derive(Debug, Properties, PartialEq, Clone)]
-struct Props {
+pub struct ClayDropDownActionProps {
     #[prop_or_default]
-    children: Children,
+    pub children: Children,
 
     #[prop_or_default]
-    class: Classes,
+    pub class: Classes,
 
+    /// A catchall prop to pass down anything not specified here to the underlying component.
     #[prop_or_default]
-    other_props: Option<ButtonProps>,
+    pub other_props: Option<ButtonProps>,
 
     #[prop_or_default]
-    node_ref: NodeRef,
+    pub node_ref: NodeRef,
 }
 
-struct ClayDropDownAction;
-
 impl ClayDropDownAction {
     const DROPDOWN_SECTION: &'static str = "dropdown-section";
 }
 
-impl Component for ClayDropDownAction {
-    type Message = ();
-
-    type Properties = Props;
-
-    fn create(_ctx: &yew::Context<Self>) -> Self {
-        Self
-    }
-
-    fn view(&self, ctx: &yew::Context<Self>) -> Html {
-        let Props {
-            class,
-            children,
-            node_ref,
-            other_props,
-        } = ctx.props().clone();
-        html! {
-            <div class={classes!(class, Self::DROPDOWN_SECTION)}>
-                <ClayButton block={true} node_ref={node_ref} button_props={other_props}>
-                    {children}
-                </ClayButton>
-            </div>
-        }
+/// A Yew implementation of ClayDropDownAction, a button rendered in its own section of the menu.
+#[function_component(ClayDropDownAction)]
+pub fn clay_dropdown_action(props: &ClayDropDownActionProps) -> Html {
+    let ClayDropDownActionProps {
+        class,
+        children,
+        node_ref,
+        other_props,
+    } = props.clone();
+    html! {
+        <div class={classes!(class, ClayDropDownAction::DROPDOWN_SECTION)}>
+            <ClayButton block={true} node_ref={node_ref} button_props={other_props}>
+                {children}
+            </ClayButton>
+        </div>
     }
 }
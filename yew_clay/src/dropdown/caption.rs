@@ -1,61 +1,44 @@
-use std::collections::HashMap;
+use crate::html_props::{with_html_props, GlobalProps};
 
-use gloo_events::EventListener;
-use yew::{classes, html, Children, Classes, Component, Html, NodeRef, Properties};
-use yew_dom_attributes::{global_props::GlobalProps, DomInjector};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
+/// Props for ClayDropDownCaption. For details, check the docs:
+/// <https://clayui.com/docs/components/drop-down/api.html>
 #[derive(Debug, Properties, PartialEq, Clone)]
-struct Props {
+pub struct ClayDropDownCaptionProps {
     #[prop_or_default]
-    children: Children,
+    pub children: Children,
 
     #[prop_or_default]
-    class: Classes,
+    pub class: Classes,
 
+    /// A catchall prop to pass down anything not specified here to the underlying component.
     #[prop_or_default]
-    other_props: Option<GlobalProps>,
+    pub other_props: Option<GlobalProps>,
 
     #[prop_or_default]
-    node_ref: NodeRef,
-}
-
-struct ClayDropDownCaption {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
+    pub node_ref: NodeRef,
 }
 
 impl ClayDropDownCaption {
     const DROPDOWN_CAPTION: &'static str = "dropdown-caption";
 }
 
-impl Component for ClayDropDownCaption {
-    type Message = ();
-
-    type Properties = Props;
-
-    fn create(_ctx: &yew::Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &yew::Context<Self>) -> Html {
-        let Props {
-            class, children, ..
-        } = ctx.props().clone();
+/// A Yew implementation of ClayDropDownCaption.
+#[function_component(ClayDropDownCaption)]
+pub fn clay_dropdown_caption(props: &ClayDropDownCaptionProps) -> Html {
+    let ClayDropDownCaptionProps {
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+    with_html_props(
         html! {
-            <div class={classes!(class, Self::DROPDOWN_CAPTION)}>
+            <div class={classes!(class, ClayDropDownCaption::DROPDOWN_CAPTION)} ref={node_ref}>
                 {children}
             </div>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &yew::Context<Self>, _first_render: bool) {
-        if let Some(button_props) = &ctx.props().other_props {
-            let node_ref = &ctx.props().node_ref;
-            let button_props = button_props.clone();
-            button_props.inject(node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.other_props.as_ref(),
+    )
 }
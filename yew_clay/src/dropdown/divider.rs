@@ -1,24 +1,13 @@
-use domatt::attributes::global::AriaRole;
-use yew::{html, Component, Html};
-
-struct ClayDropDownDivider;
+use yew::{function_component, html, Html};
 
 impl ClayDropDownDivider {
     const DROPDOWN_DIVIDER: &'static str = "dropdown-divider";
 }
 
-impl Component for ClayDropDownDivider {
-    type Message = ();
-
-    type Properties = ();
-
-    fn create(_ctx: &yew::Context<Self>) -> Self {
-        Self
-    }
-
-    fn view(&self, _ctx: &yew::Context<Self>) -> Html {
-        html! {
-            <li class={Self::DROPDOWN_DIVIDER} role={AriaRole::Separator.as_ref()} />
-        }
+/// A Yew implementation of ClayDropDownDivider.
+#[function_component(ClayDropDownDivider)]
+pub fn clay_dropdown_divider() -> Html {
+    html! {
+        <li class={ClayDropDownDivider::DROPDOWN_DIVIDER} role="separator" />
     }
 }
@@ -1,5 +1,14 @@
-use yew::Properties;
-use yew_dom_attributes::{global_props::GlobalProps, li_props::LiProps};
+use super::{use_dropdown_state, DropDownContext};
+use crate::html_props::{with_html_props, GlobalProps, LiProps};
+use crate::shared::keys::ESCAPE;
+use gloo_events::EventListener;
+use strum::AsRefStr;
+use wasm_bindgen::JsCast;
+use web_sys::{KeyboardEvent, MouseEvent, Node};
+use yew::{
+    classes, function_component, html, use_effect_with, use_node_ref, Callback, Children, Classes,
+    ContextProvider, Html, NodeRef, Properties,
+};
 
 #[derive(Debug, PartialEq, Clone)]
 pub enum OtherProps {
@@ -7,94 +16,312 @@ pub enum OtherProps {
     LiProps(LiProps),
 }
 
+/// Props for ClayDropDown. For details, check the docs:
+/// <https://clayui.com/docs/components/drop-down/api.html>
 #[derive(Debug, Properties, PartialEq, Clone)]
-struct Props {
+pub struct ClayDropDownProps {
+    /// Flag to indicate if the DropDown menu is active or not (controlled).
+    ///
+    /// This API is generally used in conjunction with `close_on_click_outside=true`
+    /// since often we are controlling the active state by clicking another element
+    /// within the document.
     #[prop_or_default]
-    other_props: Option<OtherProps>,
+    pub active: Option<bool>,
 
-    /// Flag to indicate if the DropDown menu is active or not (controlled).
+    /// Callback for when the active state changes (controlled).
     ///
-    /// This API is generally used in conjunction with `closeOnClickOutside=true`
+    /// This API is generally used in conjunction with `close_on_click_outside=true`
     /// since often we are controlling the active state by clicking another element
     /// within the document.
     #[prop_or_default]
-    active: bool,
+    pub on_active_change: Option<Callback<bool>>,
+
+    /// Property to set the default value of `active` (uncontrolled).
+    #[prop_or(false)]
+    pub default_active: bool,
+
+    /// Flag that indicates whether to close DropDown when clicking on the item.
+    #[prop_or(false)]
+    pub close_on_click: bool,
+
+    /// Flag that indicates whether to close DropDown when clicking outside of it.
+    #[prop_or(true)]
+    pub close_on_click_outside: bool,
+
+    /// HTML element tag that the container should render.
+    #[prop_or("div".into())]
+    pub container_element: String,
+
+    /// Flag to indicate if menu contains icon symbols on the right side.
+    #[prop_or(false)]
+    pub has_right_symbols: bool,
+
+    /// Flag to indicate if menu contains icon symbols on the left side.
+    #[prop_or(false)]
+    pub has_left_symbols: bool,
+
+    /// Props to pass to the underlying DropDown menu element.
+    #[prop_or_default]
+    pub menu_element_attrs: Option<GlobalProps>,
+
+    /// Sets the height of the menu.
+    #[prop_or_default]
+    pub menu_height: Option<DropDownMenuHeight>,
+
+    /// Sets the width of the menu.
+    #[prop_or_default]
+    pub menu_width: Option<DropDownMenuWidth>,
+
+    /// Flag indicating if the menu should be rendered lazily.
+    #[prop_or(false)]
+    pub render_menu_on_click: bool,
+
+    /// Element that is used as the trigger which will activate the dropdown on click. It should
+    /// be an html element, such as a `<button>`, so that the dropdown can add the
+    /// `dropdown-toggle` class and the aria attributes to it.
+    pub trigger: Html,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub other_props: Option<OtherProps>,
+}
+
+impl ClayDropDown {
+    const DROPDOWN: &'static str = "dropdown";
+    const DROPDOWN_TOGGLE: &'static str = "dropdown-toggle";
+    const DROPDOWN_MENU: &'static str = "dropdown-menu";
+    const DROPDOWN_MENU_INDICATOR_START: &'static str = "dropdown-menu-indicator-start";
+    const DROPDOWN_MENU_INDICATOR_END: &'static str = "dropdown-menu-indicator-end";
+    const DROPDOWN_MENU_HEIGHT: &'static str = "dropdown-menu-height-";
+    const DROPDOWN_MENU_WIDTH: &'static str = "dropdown-menu-width-";
+    const SHOW: &'static str = "show";
+
+    fn get_bool_class(boolean: bool, class: &'static str) -> Option<&'static str> {
+        if boolean {
+            Some(class)
+        } else {
+            None
+        }
+    }
+
+    fn get_prefixed_class<T: AsRef<str>>(value: &Option<T>, prefix: &str) -> Option<String> {
+        value.as_ref().map(|value| {
+            let value = value.as_ref();
+            let mut class = String::with_capacity(prefix.len() + value.len());
+            class.push_str(prefix);
+            class.push_str(value);
+            class
+        })
+    }
+
+    fn is_inside(node_ref: &NodeRef, target: Option<Node>) -> bool {
+        match (node_ref.cast::<Node>(), target) {
+            (Some(node), Some(target)) => node.contains(Some(&target)),
+            _ => false,
+        }
+    }
+
+    fn get_event_target(event: &web_sys::Event) -> Option<Node> {
+        event
+            .target()
+            .and_then(|target| target.dyn_into::<Node>().ok())
+    }
+
+    /// Adds the dropdown-toggle class and the aria attributes to the element that was passed
+    /// as the trigger. Anything else than an html element is returned as is.
+    fn decorate_trigger(trigger: Html, active: bool) -> Html {
+        match trigger {
+            Html::VTag(mut tag) => {
+                let class = tag
+                    .attributes
+                    .iter()
+                    .find(|(key, _)| *key == "class")
+                    .map(|(_, class)| classes!(class.to_string(), Self::DROPDOWN_TOGGLE))
+                    .unwrap_or_else(|| classes!(Self::DROPDOWN_TOGGLE));
+                tag.add_attribute("class", class.to_string());
+                tag.add_attribute("aria-haspopup", "true");
+                tag.add_attribute("aria-expanded", active.to_string());
+                Html::VTag(tag)
+            }
+            trigger => trigger,
+        }
+    }
+
+    /// Creates the listeners that are only needed while the menu is open.
+    fn attach_document_listeners(
+        close_on_click_outside: bool,
+        node_ref: NodeRef,
+        set_active: Callback<bool>,
+    ) -> Vec<EventListener> {
+        let document = match web_sys::window().and_then(|window| window.document()) {
+            Some(document) => document,
+            None => return Vec::new(),
+        };
+
+        let mut listeners = Vec::with_capacity(2);
+
+        if close_on_click_outside {
+            let set_active = set_active.clone();
+            listeners.push(EventListener::new(&document, "mousedown", move |event| {
+                if !Self::is_inside(&node_ref, Self::get_event_target(event)) {
+                    set_active.emit(false);
+                }
+            }));
+        }
+
+        listeners.push(EventListener::new(&document, "keydown", move |event| {
+            if let Some(event) = event.dyn_ref::<KeyboardEvent>() {
+                if event.key() == ESCAPE {
+                    set_active.emit(false);
+                }
+            }
+        }));
+
+        listeners
+    }
 }
 
-// 	/**
-// 	 * Flag to align the DropDown menu within the viewport.
-// 	 */
-// 	alignmentByViewport?: React.ComponentProps<
-// 		typeof Menu
-// 	>['alignmentByViewport'];
-
-// 	/**
-// 	 * Default position of menu element. Values come from `./Menu`.
-// 	 */
-// 	alignmentPosition?: React.ComponentProps<typeof Menu>['alignmentPosition'];
-
-// 	/**
-// 	 * HTML element tag that the container should render.
-// 	 */
-// 	containerElement?: React.JSXElementConstructor<any> | 'div' | 'li';
-
-// 	/**
-// 	 * Flag that indicates whether to close DropDown when clicking on the item.
-// 	 */
-// 	closeOnClick?: boolean;
-
-// 	closeOnClickOutside?: React.ComponentProps<
-// 		typeof Menu
-// 	>['closeOnClickOutside'];
-
-// 	/**
-// 	 *  Property to set the default value of `active` (uncontrolled).
-// 	 */
-// 	defaultActive?: boolean;
-
-// 	/**
-// 	 * Flag to indicate if menu contains icon symbols on the right side.
-// 	 */
-// 	hasRightSymbols?: boolean;
-
-// 	/**
-// 	 * Flag to indicate if menu contains icon symbols on the left side.
-// 	 */
-// 	hasLeftSymbols?: boolean;
-
-// 	/**
-// 	 * Prop to pass DOM element attributes to <DropDown.Menu />.
-// 	 */
-// 	menuElementAttrs?: React.HTMLAttributes<HTMLDivElement> &
-// 		Pick<React.ComponentProps<typeof Menu>, 'containerProps'>;
-
-// 	menuHeight?: React.ComponentProps<typeof Menu>['height'];
-
-// 	menuWidth?: React.ComponentProps<typeof Menu>['width'];
-
-// 	/**
-// 	 * Callback for when the active state changes (controlled).
-// 	 *
-// 	 * This API is generally used in conjunction with `closeOnClickOutside=true`
-// 	 * since often we are controlling the active state by clicking another element
-// 	 * within the document.
-// 	 */
-// 	onActiveChange?: InternalDispatch<boolean>;
-
-// 	/**
-// 	 * Function for setting the offset of the menu from the trigger.
-// 	 */
-// 	offsetFn?: React.ComponentProps<typeof Menu>['offsetFn'];
-
-// 	/**
-// 	 * Flag indicating if the menu should be rendered lazily
-// 	 */
-// 	renderMenuOnClick?: boolean;
-
-// 	/**
-// 	 * Element that is used as the trigger which will activate the dropdown on click.
-// 	 */
-// 	trigger: React.ReactElement & {
-// 		ref?: (node: HTMLButtonElement | null) => void;
-// 	};
-// }
+/// A Yew implementation of ClayDropDown. For more info about ClayDropDown, check the documentation:
+/// <https://clayui.com/docs/components/drop-down.html>
+#[function_component(ClayDropDown)]
+pub fn clay_dropdown(props: &ClayDropDownProps) -> Html {
+    let menu_ref = use_node_ref();
+    let state = use_dropdown_state(
+        props.active,
+        props.default_active,
+        props.on_active_change.clone(),
+        props.render_menu_on_click,
+    );
+
+    {
+        let node_ref = props.node_ref.clone();
+        let set_active = state.set_active.clone();
+        use_effect_with(
+            (state.active, props.close_on_click_outside),
+            move |(active, close_on_click_outside)| {
+                let listeners = if *active {
+                    ClayDropDown::attach_document_listeners(
+                        *close_on_click_outside,
+                        node_ref,
+                        set_active,
+                    )
+                } else {
+                    Vec::new()
+                };
+                move || drop(listeners)
+            },
+        );
+    }
+
+    let ClayDropDownProps {
+        class,
+        children,
+        node_ref,
+        trigger,
+        container_element,
+        close_on_click,
+        has_left_symbols,
+        has_right_symbols,
+        menu_height,
+        menu_width,
+        ..
+    } = props.clone();
+
+    let active = state.active;
+    let trigger = ClayDropDown::decorate_trigger(trigger, active);
+    let show_class = ClayDropDown::get_bool_class(active, ClayDropDown::SHOW);
+    let left_class = ClayDropDown::get_bool_class(
+        has_left_symbols,
+        ClayDropDown::DROPDOWN_MENU_INDICATOR_START,
+    );
+    let right_class =
+        ClayDropDown::get_bool_class(has_right_symbols, ClayDropDown::DROPDOWN_MENU_INDICATOR_END);
+    let height_class =
+        ClayDropDown::get_prefixed_class(&menu_height, ClayDropDown::DROPDOWN_MENU_HEIGHT);
+    let width_class =
+        ClayDropDown::get_prefixed_class(&menu_width, ClayDropDown::DROPDOWN_MENU_WIDTH);
+
+    let context = DropDownContext {
+        close: state.set_active.reform(|_| false),
+        close_on_click,
+    };
+
+    let onclick = {
+        let menu_ref = menu_ref.clone();
+        let set_active = state.set_active.clone();
+        Callback::from(move |event: MouseEvent| {
+            if !ClayDropDown::is_inside(&menu_ref, ClayDropDown::get_event_target(&event)) {
+                set_active.emit(!active);
+            }
+        })
+    };
+
+    let menu = if state.menu_rendered {
+        with_html_props(
+            html! {
+                <div
+                    class={classes!(
+                        ClayDropDown::DROPDOWN_MENU,
+                        show_class,
+                        left_class,
+                        right_class,
+                        height_class,
+                        width_class
+                    )}
+                    ref={menu_ref}
+                    role="presentation" >
+                    <ContextProvider<DropDownContext> {context}>
+                        {children}
+                    </ContextProvider<DropDownContext>>
+                </div>
+            },
+            props.menu_element_attrs.as_ref(),
+        )
+    } else {
+        html! {}
+    };
+
+    let other_props = match &props.other_props {
+        Some(OtherProps::DivProps(div_props)) => Some(div_props),
+        Some(OtherProps::LiProps(li_props)) => Some(li_props),
+        None => None,
+    };
+
+    with_html_props(
+        html! {
+            <@{container_element}
+                class={classes!(class, ClayDropDown::DROPDOWN)}
+                {onclick}
+                ref={node_ref} >
+                {trigger}
+                {menu}
+            </@>
+        },
+        other_props,
+    )
+}
+
+/// An enum specifying the height variants of the DropDown menu.
+#[derive(AsRefStr, Debug, PartialEq, Clone)]
+#[strum(serialize_all = "lowercase")]
+pub enum DropDownMenuHeight {
+    Auto,
+}
+
+/// An enum specifying the width variants of the DropDown menu.
+#[derive(AsRefStr, Debug, PartialEq, Clone)]
+#[strum(serialize_all = "lowercase")]
+pub enum DropDownMenuWidth {
+    Sm,
+    Shrink,
+    Full,
+}
@@ -1,36 +1,9 @@
-use std::{fmt::Debug, rc::Rc};
-
-#[derive(Default)]
-struct DropDownContext {
-    close: Option<Rc<dyn FnOnce() -> ()>>,
-    close_on_click: bool,
-}
-
-impl Clone for DropDownContext {
-    fn clone(&self) -> Self {
-        Self {
-            close: self.close.as_ref().map(|func| func.clone()),
-            close_on_click: self.close_on_click,
-        }
-    }
-}
-
-impl Debug for DropDownContext {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("DropDownContext")
-            .field("close", &"Option<Rc<dyn FnOnce() -> ()>>")
-            .field("closeOnClick", &self.close_on_click)
-            .finish()
-    }
-}
-
-impl PartialEq for DropDownContext {
-    fn eq(&self, other: &Self) -> bool {
-        let funcs_eq = if let (Some(this), Some(other)) = (&self.close, &other.close) {
-            Rc::ptr_eq(&this, &other)
-        } else {
-            false
-        };
-        funcs_eq && self.close_on_click == other.close_on_click
-    }
+use yew::Callback;
+
+/// Context shared by ClayDropDown with the items rendered inside of its menu. It allows
+/// items to close the menu when they are clicked.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DropDownContext {
+    pub close: Callback<()>,
+    pub close_on_click: bool,
 }
@@ -0,0 +1,50 @@
+use yew::{classes, function_component, html, Children, Classes, Html, Properties};
+
+/// Props for ClayDropDownGroup. For details, check the docs:
+/// <https://clayui.com/docs/components/drop-down/api.html#dropdowngroup>
+#[derive(Debug, Properties, PartialEq, Clone, Default)]
+pub struct ClayDropDownGroupProps {
+    /// Value provided is a display component that is a header for the items in the group.
+    #[prop_or_default]
+    pub header: Option<String>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: Children,
+}
+
+impl ClayDropDownGroup {
+    const DROPDOWN_SUBHEADER: &'static str = "dropdown-subheader";
+    const LIST_UNSTYLED: &'static str = "list-unstyled";
+}
+
+/// A Yew implementation of ClayDropDownGroup. Renders a subheader followed by a group of items.
+#[function_component(ClayDropDownGroup)]
+pub fn clay_dropdown_group(props: &ClayDropDownGroupProps) -> Html {
+    let ClayDropDownGroupProps {
+        header,
+        class,
+        children,
+    } = props.clone();
+
+    let header = if let Some(header) = header {
+        html! {
+            <li class={ClayDropDownGroup::DROPDOWN_SUBHEADER} role="presentation">{header}</li>
+        }
+    } else {
+        html! {}
+    };
+
+    html! {
+        <>
+            {header}
+            <li role="none">
+                <ul class={classes!(class, ClayDropDownGroup::LIST_UNSTYLED)} role="group">
+                    {children}
+                </ul>
+            </li>
+        </>
+    }
+}
@@ -0,0 +1,242 @@
+use super::DropDownContext;
+use crate::html_props::{with_html_props, AnchorProps, GlobalProps};
+use crate::icon::{ClayIcon, ClaySymbol};
+use crate::link::ClayLink;
+#[cfg(feature = "router")]
+use crate::link::LinkRoute;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, MouseEvent};
+use yew::{
+    classes, function_component, html, use_context, use_node_ref, Callback, Children, Classes,
+    Html, NodeRef, Properties,
+};
+
+/// Props for ClayDropDownItem. For details, check the docs:
+/// <https://clayui.com/docs/components/drop-down/api.html#dropdownitem>
+#[derive(Debug, Properties, PartialEq, Clone, Default)]
+pub struct ClayDropDownItemProps {
+    /// Flag that indicates if item is selected. The item is marked with `aria-current`.
+    #[prop_or(false)]
+    pub active: bool,
+
+    /// Flag that indicates if item is disabled or not.
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    /// Path for item to link to. When set, the item is rendered as a ClayLink.
+    #[prop_or_default]
+    pub href: Option<String>,
+
+    /// Callback for when the item is clicked.
+    #[prop_or_default]
+    pub on_click: Option<Callback<MouseEvent>>,
+
+    /// A typed route of the application that the item links to. When set, the item is
+    /// rendered as a ClayLink.
+    #[cfg(feature = "router")]
+    #[prop_or_default]
+    pub route: Option<LinkRoute>,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    /// The name of a symbol in the spritemap that is to be rendered on the left side.
+    #[prop_or_default]
+    pub symbol_left: Option<ClaySymbol>,
+
+    /// The name of a symbol in the spritemap that is to be rendered on the right side.
+    #[prop_or_default]
+    pub symbol_right: Option<ClaySymbol>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayDropDownItem {
+    const ACTIVE: &'static str = "active";
+    const ARIA_CURRENT: &'static str = "aria-current";
+    const DISABLED: &'static str = "disabled";
+    const DROPDOWN_ITEM: &'static str = "dropdown-item";
+    const DROPDOWN_ITEM_INDICATOR_START: &'static str = "dropdown-item-indicator-start";
+    const DROPDOWN_ITEM_INDICATOR_END: &'static str = "dropdown-item-indicator-end";
+    const CLICK: &'static str = "click";
+    const HREF: &'static str = "href";
+
+    fn get_bool_class(boolean: bool, class: &'static str) -> Option<&'static str> {
+        if boolean {
+            Some(class)
+        } else {
+            None
+        }
+    }
+
+    fn gen_indicator(
+        symbol: Option<ClaySymbol>,
+        spritemap: Option<&'static str>,
+        class: &'static str,
+    ) -> Html {
+        if let Some(symbol) = symbol {
+            html! {
+                <span class={class}>
+                    <ClayIcon {spritemap} {symbol} />
+                </span>
+            }
+        } else {
+            html! {}
+        }
+    }
+
+    fn get_anchor_props(
+        active: bool,
+        href: Option<String>,
+        on_click: Option<Callback<MouseEvent>>,
+    ) -> AnchorProps {
+        let mut anchor_props = AnchorProps::new();
+        if active {
+            anchor_props.add_attribute(Self::ARIA_CURRENT, "true");
+        }
+
+        if let Some(href) = href {
+            anchor_props.add_attribute(Self::HREF, href);
+        }
+
+        if let Some(on_click) = on_click {
+            let on_click = on_click.reform(|event: Event| event.unchecked_into::<MouseEvent>());
+            anchor_props.add_listener(Self::CLICK, on_click);
+        }
+
+        anchor_props
+    }
+
+    #[cfg(feature = "router")]
+    fn has_route(props: &ClayDropDownItemProps) -> bool {
+        props.route.is_some()
+    }
+
+    #[cfg(not(feature = "router"))]
+    fn has_route(_props: &ClayDropDownItemProps) -> bool {
+        false
+    }
+
+    #[cfg(feature = "router")]
+    fn gen_link(
+        props: &ClayDropDownItemProps,
+        class: Classes,
+        anchor_props: AnchorProps,
+        content: Html,
+    ) -> Html {
+        let ClayDropDownItemProps {
+            node_ref, route, ..
+        } = props.clone();
+        html! {
+            <ClayLink {class} {node_ref} {anchor_props} {route}>
+                {content}
+            </ClayLink>
+        }
+    }
+
+    #[cfg(not(feature = "router"))]
+    fn gen_link(
+        props: &ClayDropDownItemProps,
+        class: Classes,
+        anchor_props: AnchorProps,
+        content: Html,
+    ) -> Html {
+        let node_ref = props.node_ref.clone();
+        html! {
+            <ClayLink {class} {node_ref} {anchor_props}>
+                {content}
+            </ClayLink>
+        }
+    }
+}
+
+/// A Yew implementation of ClayDropDownItem. For more info, check the documentation:
+/// <https://clayui.com/docs/components/drop-down.html>
+#[function_component(ClayDropDownItem)]
+pub fn clay_dropdown_item(props: &ClayDropDownItemProps) -> Html {
+    let li_ref = use_node_ref();
+    let context = use_context::<DropDownContext>();
+
+    let ClayDropDownItemProps {
+        active,
+        disabled,
+        href,
+        on_click,
+        spritemap,
+        symbol_left,
+        symbol_right,
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let class = classes!(
+        class,
+        ClayDropDownItem::DROPDOWN_ITEM,
+        ClayDropDownItem::get_bool_class(active, ClayDropDownItem::ACTIVE),
+        ClayDropDownItem::get_bool_class(disabled, ClayDropDownItem::DISABLED)
+    );
+
+    let content = html! {
+        <>
+            {ClayDropDownItem::gen_indicator(symbol_left, spritemap, ClayDropDownItem::DROPDOWN_ITEM_INDICATOR_START)}
+            {children}
+            {ClayDropDownItem::gen_indicator(symbol_right, spritemap, ClayDropDownItem::DROPDOWN_ITEM_INDICATOR_END)}
+        </>
+    };
+
+    let is_link = href.is_some() || ClayDropDownItem::has_route(props);
+    let item = if is_link && !disabled {
+        let anchor_props = ClayDropDownItem::get_anchor_props(active, href, on_click);
+        ClayDropDownItem::gen_link(props, class, anchor_props, content)
+    } else {
+        html! {
+            <button
+                aria-current={active.then_some("true")}
+                class={class}
+                disabled={disabled}
+                onclick={on_click}
+                ref={node_ref}
+                type="button" >
+                {content}
+            </button>
+        }
+    };
+
+    let onclick = Callback::from(move |_: MouseEvent| {
+        if disabled {
+            return;
+        }
+
+        if let Some(context) = &context {
+            if context.close_on_click {
+                context.close.emit(());
+            }
+        }
+    });
+
+    with_html_props(
+        html! {
+            <li
+                {onclick}
+                ref={li_ref}
+                role="presentation" >
+                {item}
+            </li>
+        },
+        props.html_props.as_ref(),
+    )
+}
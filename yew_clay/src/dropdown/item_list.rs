@@ -0,0 +1,44 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayDropDownItemList. For details, check the docs:
+/// <https://clayui.com/docs/components/drop-down/api.html>
+#[derive(Debug, Properties, PartialEq, Clone, Default)]
+pub struct ClayDropDownItemListProps {
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayDropDownItemList {
+    const LIST_UNSTYLED: &'static str = "list-unstyled";
+}
+
+/// A Yew implementation of ClayDropDownItemList, the list that wraps the items of the menu.
+#[function_component(ClayDropDownItemList)]
+pub fn clay_dropdown_item_list(props: &ClayDropDownItemListProps) -> Html {
+    let ClayDropDownItemListProps {
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    with_html_props(
+        html! {
+            <ul class={classes!(class, ClayDropDownItemList::LIST_UNSTYLED)} ref={node_ref} role="list">
+                {children}
+            </ul>
+        },
+        props.html_props.as_ref(),
+    )
+}
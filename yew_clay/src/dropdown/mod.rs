@@ -3,3 +3,19 @@ mod caption;
 mod divider;
 mod dropdown;
 mod dropdown_context;
+mod group;
+mod item;
+mod item_list;
+mod search;
+mod state;
+
+pub use action::*;
+pub use caption::*;
+pub use divider::*;
+pub use dropdown::*;
+pub use dropdown_context::*;
+pub use group::*;
+pub use item::*;
+pub use item_list::*;
+pub use search::*;
+pub use state::*;
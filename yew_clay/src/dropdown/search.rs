@@ -0,0 +1,102 @@
+use crate::button::{ButtonDisplayType, ClayButton};
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::icon::{ClayIcon, ClaySymbol};
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlInputElement, InputEvent, SubmitEvent};
+use yew::{classes, function_component, html, Callback, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayDropDownSearch. For details, check the docs:
+/// <https://clayui.com/docs/components/drop-down/api.html#dropdownsearch>
+#[derive(Debug, Properties, PartialEq, Clone, Default)]
+pub struct ClayDropDownSearchProps {
+    /// The current value of the input (controlled).
+    #[prop_or_default]
+    pub value: String,
+
+    /// Callback for when the value of the input changes.
+    #[prop_or_default]
+    pub on_change: Callback<String>,
+
+    /// Placeholder text for the input.
+    #[prop_or_default]
+    pub placeholder: Option<String>,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// NodeRef for the underlying input element.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying input.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayDropDownSearch {
+    const DROPDOWN_SECTION: &'static str = "dropdown-section";
+    const INPUT_GROUP: &'static str = "input-group input-group-sm";
+    const INPUT_GROUP_ITEM: &'static str = "input-group-item";
+    const INPUT: &'static str = "form-control input-group-inset input-group-inset-after";
+    const INPUT_INSET_ITEM: &'static str = "input-group-inset-item input-group-inset-item-after";
+    const SEARCH_SYMBOL: ClaySymbol = ClaySymbol::Search;
+}
+
+/// A Yew implementation of ClayDropDownSearch, a search input rendered inside of the menu.
+#[function_component(ClayDropDownSearch)]
+pub fn clay_dropdown_search(props: &ClayDropDownSearchProps) -> Html {
+    let ClayDropDownSearchProps {
+        value,
+        on_change,
+        placeholder,
+        spritemap,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let oninput = Callback::from(move |event: InputEvent| {
+        let input = event
+            .target()
+            .and_then(|target| target.dyn_into::<HtmlInputElement>().ok());
+        if let Some(input) = input {
+            on_change.emit(input.value());
+        }
+    });
+
+    let onsubmit = Callback::from(|event: SubmitEvent| event.prevent_default());
+
+    let input = with_html_props(
+        html! {
+            <input
+                class={ClayDropDownSearch::INPUT}
+                oninput={oninput}
+                placeholder={placeholder}
+                ref={node_ref}
+                type="text"
+                value={value} />
+        },
+        props.html_props.as_ref(),
+    );
+
+    html! {
+        <form class={classes!(class)} onsubmit={onsubmit}>
+            <div class={ClayDropDownSearch::DROPDOWN_SECTION}>
+                <div class={ClayDropDownSearch::INPUT_GROUP}>
+                    <div class={ClayDropDownSearch::INPUT_GROUP_ITEM}>
+                        {input}
+                        <span class={ClayDropDownSearch::INPUT_INSET_ITEM}>
+                            <ClayButton display_type={ButtonDisplayType::Unstyled} _type={"button"}>
+                                <ClayIcon {spritemap} symbol={ClayDropDownSearch::SEARCH_SYMBOL} />
+                            </ClayButton>
+                        </span>
+                    </div>
+                </div>
+            </div>
+        </form>
+    }
+}
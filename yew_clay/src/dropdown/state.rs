@@ -0,0 +1,55 @@
+use yew::{hook, use_mut_ref, use_state_eq, Callback};
+
+/// The open state of a ClayDropDown, as returned by `use_dropdown_state`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropDownState {
+    /// Whether the menu is currently open.
+    pub active: bool,
+
+    /// Whether the menu has been rendered at least once. Menus that are rendered lazily are
+    /// kept in the DOM after they are closed.
+    pub menu_rendered: bool,
+
+    /// Opens or closes the menu. It calls `on_active_change` when the state changes and only
+    /// updates the internal state when `active` is not controlled.
+    pub set_active: Callback<bool>,
+}
+
+/// Hook holding the open state of a dropdown menu. When `active` is set the state is controlled
+/// by the parent, otherwise it starts as `default_active` and is kept internally.
+#[hook]
+pub fn use_dropdown_state(
+    active: Option<bool>,
+    default_active: bool,
+    on_active_change: Option<Callback<bool>>,
+    render_menu_on_click: bool,
+) -> DropDownState {
+    let internal_active = use_state_eq(|| default_active);
+    let menu_rendered = use_mut_ref(|| !render_menu_on_click);
+
+    let is_active = active.unwrap_or(*internal_active);
+    if is_active {
+        *menu_rendered.borrow_mut() = true;
+    }
+
+    let set_active = Callback::from(move |next: bool| {
+        if is_active == next {
+            return;
+        }
+
+        if let Some(on_active_change) = &on_active_change {
+            on_active_change.emit(next);
+        }
+
+        if active.is_none() {
+            internal_active.set(next);
+        }
+    });
+
+    let menu_rendered = *menu_rendered.borrow();
+    DropDownState {
+        active: is_active,
+        menu_rendered,
+        set_active,
+    }
+}
@@ -0,0 +1,160 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use wasm_bindgen::JsCast;
+use web_sys::{Event, HtmlInputElement};
+use yew::{
+    classes, function_component, html, use_effect, Callback, Classes, Html, NodeRef, Properties,
+};
+
+/// Props for ClayCheckbox. For details, check the docs:
+/// <https://clayui.com/docs/components/checkbox/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayCheckboxProps {
+    /// Flag to indicate if the checkbox is checked (controlled).
+    #[prop_or(false)]
+    pub checked: bool,
+
+    /// Flag to display the checkbox as neither checked nor unchecked, usually when only some
+    /// of the items it controls are checked.
+    #[prop_or(false)]
+    pub indeterminate: bool,
+
+    /// Callback for when the checkbox is toggled. It receives the new checked state.
+    #[prop_or_default]
+    pub on_change: Option<Callback<bool>>,
+
+    /// The text displayed next to the checkbox.
+    #[prop_or_default]
+    pub label: Option<Html>,
+
+    /// Flag to display the checkbox inline with its siblings.
+    #[prop_or(false)]
+    pub inline: bool,
+
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    /// The aria-label of the checkbox, for checkboxes without a label.
+    #[prop_or_default]
+    pub aria_label: Option<String>,
+
+    #[prop_or_default]
+    pub id: Option<String>,
+
+    #[prop_or_default]
+    pub name: Option<String>,
+
+    #[prop_or_default]
+    pub value: Option<String>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// NodeRef for the underlying input element.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying input.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayCheckbox {
+    const CUSTOM_CONTROL: &'static str = "custom-control";
+    const CUSTOM_CHECKBOX: &'static str = "custom-checkbox";
+    const CUSTOM_CONTROL_INLINE: &'static str = "custom-control-inline";
+    const CUSTOM_CONTROL_INPUT: &'static str = "custom-control-input";
+    const CUSTOM_CONTROL_LABEL: &'static str = "custom-control-label";
+    const CUSTOM_CONTROL_LABEL_TEXT: &'static str = "custom-control-label-text";
+
+    fn gen_label_text(label: Option<Html>) -> Html {
+        match label {
+            Some(label) => html! {
+                <span class={ClayCheckbox::CUSTOM_CONTROL_LABEL_TEXT}>{label}</span>
+            },
+            None => html! {},
+        }
+    }
+}
+
+/// A Yew implementation of ClayCheckbox. For more info about ClayCheckbox, check the
+/// documentation: <https://clayui.com/docs/components/checkbox.html>
+#[function_component(ClayCheckbox)]
+pub fn clay_checkbox(props: &ClayCheckboxProps) -> Html {
+    {
+        // The indeterminate state is only available as a property of the element. The checked
+        // state is set again after every render, so that it always matches `checked` even when
+        // the click of the user was not accepted.
+        let node_ref = props.node_ref.clone();
+        let indeterminate = props.indeterminate;
+        let checked = props.checked;
+        use_effect(move || {
+            if let Some(input) = node_ref.cast::<HtmlInputElement>() {
+                input.set_indeterminate(indeterminate);
+                input.set_checked(checked);
+            }
+            || ()
+        });
+    }
+
+    let ClayCheckboxProps {
+        checked,
+        on_change,
+        label,
+        inline,
+        disabled,
+        aria_label,
+        id,
+        name,
+        value,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let onchange = on_change.map(|on_change| {
+        Callback::from(move |event: Event| {
+            let input = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok());
+            if let Some(input) = input {
+                on_change.emit(input.checked());
+            }
+        })
+    });
+
+    let input = with_html_props(
+        html! {
+            <input
+                aria-label={aria_label}
+                checked={checked}
+                class={ClayCheckbox::CUSTOM_CONTROL_INPUT}
+                {disabled}
+                {id}
+                {name}
+                {onchange}
+                ref={node_ref}
+                type="checkbox"
+                value={value} />
+        },
+        props.html_props.as_ref(),
+    );
+
+    let inline_class = inline.then_some(ClayCheckbox::CUSTOM_CONTROL_INLINE);
+
+    html! {
+        <div
+            class={classes!(
+                class,
+                ClayCheckbox::CUSTOM_CONTROL,
+                ClayCheckbox::CUSTOM_CHECKBOX,
+                inline_class
+            )} >
+            <label>
+                {input}
+                <span class={ClayCheckbox::CUSTOM_CONTROL_LABEL}>
+                    {ClayCheckbox::gen_label_text(label)}
+                </span>
+            </label>
+        </div>
+    }
+}
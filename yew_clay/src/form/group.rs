@@ -0,0 +1,120 @@
+use super::FormValidation;
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayFormGroup. For details, check the docs:
+/// <https://clayui.com/docs/components/form/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayFormGroupProps {
+    /// The validation state of the fields of the group. It also sets the style of the
+    /// feedback text.
+    #[prop_or_default]
+    pub validation: Option<FormValidation>,
+
+    /// Text that describes the validation state of the fields, like an error message.
+    #[prop_or_default]
+    pub feedback: Option<Html>,
+
+    /// Text that helps filling in the fields of the group.
+    #[prop_or_default]
+    pub help_text: Option<Html>,
+
+    /// Flag to render a small form group.
+    #[prop_or(false)]
+    pub small: bool,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayFormGroup {
+    const FORM_GROUP: &'static str = "form-group";
+    const FORM_GROUP_SM: &'static str = "form-group-sm";
+    const FORM_FEEDBACK_GROUP: &'static str = "form-feedback-group";
+    const FORM_FEEDBACK_ITEM: &'static str = "form-feedback-item";
+    const FORM_TEXT: &'static str = "form-text";
+    const HAS: &'static str = "has-";
+
+    fn get_validation_class(validation: &Option<FormValidation>) -> Option<String> {
+        validation.map(|validation| {
+            let validation = validation.as_ref();
+            let mut class = String::with_capacity(Self::HAS.len() + validation.len());
+            class.push_str(Self::HAS);
+            class.push_str(validation);
+            class
+        })
+    }
+
+    fn gen_feedback(feedback: Option<Html>, help_text: Option<Html>) -> Html {
+        if feedback.is_none() && help_text.is_none() {
+            return html! {};
+        }
+
+        let feedback = match feedback {
+            Some(feedback) => html! {
+                <div class={ClayFormGroup::FORM_FEEDBACK_ITEM}>{feedback}</div>
+            },
+            None => html! {},
+        };
+
+        let help_text = match help_text {
+            Some(help_text) => html! {
+                <div class={ClayFormGroup::FORM_TEXT}>{help_text}</div>
+            },
+            None => html! {},
+        };
+
+        html! {
+            <div class={ClayFormGroup::FORM_FEEDBACK_GROUP}>
+                {feedback}
+                {help_text}
+            </div>
+        }
+    }
+}
+
+/// A Yew implementation of ClayForm.Group. It wraps a label and its fields, and displays the
+/// feedback and help text below them. For more info about ClayForm, check the documentation:
+/// <https://clayui.com/docs/components/form.html>
+#[function_component(ClayFormGroup)]
+pub fn clay_form_group(props: &ClayFormGroupProps) -> Html {
+    let ClayFormGroupProps {
+        validation,
+        feedback,
+        help_text,
+        small,
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let small_class = small.then_some(ClayFormGroup::FORM_GROUP_SM);
+
+    with_html_props(
+        html! {
+            <div
+                class={classes!(
+                    class,
+                    ClayFormGroup::FORM_GROUP,
+                    small_class,
+                    ClayFormGroup::get_validation_class(&validation)
+                )}
+                ref={node_ref} >
+                {children}
+                {ClayFormGroup::gen_feedback(feedback, help_text)}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
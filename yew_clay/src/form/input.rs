@@ -0,0 +1,143 @@
+use super::InputType;
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::layout::Sizing;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlInputElement, InputEvent};
+use yew::{classes, function_component, html, Callback, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayInput. For details, check the docs:
+/// <https://clayui.com/docs/components/input/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayInputProps {
+    /// The value of the input (controlled).
+    #[prop_or_default]
+    pub value: String,
+
+    /// Callback for when the value of the input changes.
+    #[prop_or_default]
+    pub on_change: Option<Callback<String>>,
+
+    /// The type of the input.
+    #[prop_or_default]
+    pub input_type: InputType,
+
+    /// The size of the input. Clay provides styles for the small and large sizes.
+    #[prop_or_default]
+    pub sizing: Option<Sizing>,
+
+    /// Flag to indicate that a ClayInputGroupInsetItem is rendered before the input.
+    #[prop_or(false)]
+    pub inset_before: bool,
+
+    /// Flag to indicate that a ClayInputGroupInsetItem is rendered after the input.
+    #[prop_or(false)]
+    pub inset_after: bool,
+
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    #[prop_or_default]
+    pub placeholder: Option<String>,
+
+    #[prop_or_default]
+    pub id: Option<String>,
+
+    #[prop_or_default]
+    pub name: Option<String>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// NodeRef for the underlying input element.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying input.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayInput {
+    const FORM_CONTROL: &'static str = "form-control";
+    const FORM_CONTROL_SIZE: &'static str = "form-control-";
+    const INPUT_GROUP_INSET: &'static str = "input-group-inset";
+    const INPUT_GROUP_INSET_BEFORE: &'static str = "input-group-inset-before";
+    const INPUT_GROUP_INSET_AFTER: &'static str = "input-group-inset-after";
+
+    fn get_size_class(sizing: &Option<Sizing>) -> Option<String> {
+        sizing.as_ref().map(|sizing| {
+            let sizing = sizing.as_ref();
+            let mut class = String::with_capacity(Self::FORM_CONTROL_SIZE.len() + sizing.len());
+            class.push_str(Self::FORM_CONTROL_SIZE);
+            class.push_str(sizing);
+            class
+        })
+    }
+
+    fn get_inset_classes(inset_before: bool, inset_after: bool) -> Classes {
+        let mut classes = Classes::new();
+        if inset_before || inset_after {
+            classes.push(Self::INPUT_GROUP_INSET);
+        }
+        if inset_before {
+            classes.push(Self::INPUT_GROUP_INSET_BEFORE);
+        }
+        if inset_after {
+            classes.push(Self::INPUT_GROUP_INSET_AFTER);
+        }
+        classes
+    }
+}
+
+/// A Yew implementation of ClayInput. For more info about ClayInput, check the documentation:
+/// <https://clayui.com/docs/components/input.html>
+#[function_component(ClayInput)]
+pub fn clay_input(props: &ClayInputProps) -> Html {
+    let ClayInputProps {
+        value,
+        on_change,
+        input_type,
+        sizing,
+        inset_before,
+        inset_after,
+        disabled,
+        placeholder,
+        id,
+        name,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let oninput = on_change.map(|on_change| {
+        Callback::from(move |event: InputEvent| {
+            let input = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok());
+            if let Some(input) = input {
+                on_change.emit(input.value());
+            }
+        })
+    });
+
+    with_html_props(
+        html! {
+            <input
+                class={classes!(
+                    class,
+                    ClayInput::FORM_CONTROL,
+                    ClayInput::get_size_class(&sizing),
+                    ClayInput::get_inset_classes(inset_before, inset_after)
+                )}
+                {disabled}
+                {id}
+                {name}
+                {oninput}
+                {placeholder}
+                ref={node_ref}
+                type={input_type.as_ref().to_string()}
+                value={value} />
+        },
+        props.html_props.as_ref(),
+    )
+}
@@ -0,0 +1,241 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayInputGroup. For details, check the docs:
+/// <https://clayui.com/docs/components/input/api.html#inputgroup>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayInputGroupProps {
+    /// Flag to render a small input group.
+    #[prop_or(false)]
+    pub small: bool,
+
+    /// Flag to stack the items of the group on small screens.
+    #[prop_or(false)]
+    pub stacked: bool,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayInputGroup {
+    const INPUT_GROUP: &'static str = "input-group";
+    const INPUT_GROUP_SM: &'static str = "input-group-sm";
+    const INPUT_GROUP_STACKED: &'static str = "input-group-stacked-sm-down";
+}
+
+/// A Yew implementation of ClayInput.Group. It lays out ClayInputGroupItems in a row, which
+/// allows adding text, buttons or icons before and after an input.
+#[function_component(ClayInputGroup)]
+pub fn clay_input_group(props: &ClayInputGroupProps) -> Html {
+    let ClayInputGroupProps {
+        small,
+        stacked,
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let small_class = small.then_some(ClayInputGroup::INPUT_GROUP_SM);
+    let stacked_class = stacked.then_some(ClayInputGroup::INPUT_GROUP_STACKED);
+
+    with_html_props(
+        html! {
+            <div
+                class={classes!(class, ClayInputGroup::INPUT_GROUP, small_class, stacked_class)}
+                ref={node_ref} >
+                {children}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
+
+/// Props for ClayInputGroupItem. For details, check the docs:
+/// <https://clayui.com/docs/components/input/api.html#inputgroupitem>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayInputGroupItemProps {
+    /// Flag to indicate that the item is placed before the input.
+    #[prop_or(false)]
+    pub prepend: bool,
+
+    /// Flag to indicate that the item is placed after the input.
+    #[prop_or(false)]
+    pub append: bool,
+
+    /// Flag to make the item only take the space its content needs.
+    #[prop_or(false)]
+    pub shrink: bool,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayInputGroupItem {
+    const INPUT_GROUP_ITEM: &'static str = "input-group-item";
+    const INPUT_GROUP_APPEND: &'static str = "input-group-append";
+    const INPUT_GROUP_PREPEND: &'static str = "input-group-prepend";
+    const INPUT_GROUP_ITEM_SHRINK: &'static str = "input-group-item-shrink";
+}
+
+/// A Yew implementation of ClayInput.GroupItem.
+#[function_component(ClayInputGroupItem)]
+pub fn clay_input_group_item(props: &ClayInputGroupItemProps) -> Html {
+    let ClayInputGroupItemProps {
+        prepend,
+        append,
+        shrink,
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let prepend_class = prepend.then_some(ClayInputGroupItem::INPUT_GROUP_PREPEND);
+    let append_class = append.then_some(ClayInputGroupItem::INPUT_GROUP_APPEND);
+    let shrink_class = shrink.then_some(ClayInputGroupItem::INPUT_GROUP_ITEM_SHRINK);
+
+    with_html_props(
+        html! {
+            <div
+                class={classes!(
+                    class,
+                    ClayInputGroupItem::INPUT_GROUP_ITEM,
+                    prepend_class,
+                    append_class,
+                    shrink_class
+                )}
+                ref={node_ref} >
+                {children}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
+
+/// Props for ClayInputGroupText. For details, check the docs:
+/// <https://clayui.com/docs/components/input/api.html#inputgrouptext>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayInputGroupTextProps {
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayInputGroupText {
+    const INPUT_GROUP_TEXT: &'static str = "input-group-text";
+}
+
+/// A Yew implementation of ClayInput.GroupText, text displayed inside of a ClayInputGroupItem.
+#[function_component(ClayInputGroupText)]
+pub fn clay_input_group_text(props: &ClayInputGroupTextProps) -> Html {
+    let ClayInputGroupTextProps {
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    with_html_props(
+        html! {
+            <div class={classes!(class, ClayInputGroupText::INPUT_GROUP_TEXT)} ref={node_ref}>
+                {children}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
+
+/// Props for ClayInputGroupInsetItem. For details, check the docs:
+/// <https://clayui.com/docs/components/input/api.html#inputgroupinsetitem>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayInputGroupInsetItemProps {
+    /// Flag to place the item before the input. The input needs `inset_before` set.
+    #[prop_or(false)]
+    pub before: bool,
+
+    /// Flag to place the item after the input. The input needs `inset_after` set.
+    #[prop_or(false)]
+    pub after: bool,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayInputGroupInsetItem {
+    const INPUT_GROUP_INSET_ITEM: &'static str = "input-group-inset-item";
+    const INPUT_GROUP_INSET_ITEM_BEFORE: &'static str = "input-group-inset-item-before";
+    const INPUT_GROUP_INSET_ITEM_AFTER: &'static str = "input-group-inset-item-after";
+}
+
+/// A Yew implementation of ClayInput.GroupInsetItem, content rendered inside of the borders
+/// of the input. It has to be placed in the same ClayInputGroupItem as the ClayInput.
+#[function_component(ClayInputGroupInsetItem)]
+pub fn clay_input_group_inset_item(props: &ClayInputGroupInsetItemProps) -> Html {
+    let ClayInputGroupInsetItemProps {
+        before,
+        after,
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let before_class = before.then_some(ClayInputGroupInsetItem::INPUT_GROUP_INSET_ITEM_BEFORE);
+    let after_class = after.then_some(ClayInputGroupInsetItem::INPUT_GROUP_INSET_ITEM_AFTER);
+
+    with_html_props(
+        html! {
+            <span
+                class={classes!(
+                    class,
+                    ClayInputGroupInsetItem::INPUT_GROUP_INSET_ITEM,
+                    before_class,
+                    after_class
+                )}
+                ref={node_ref} >
+                {children}
+            </span>
+        },
+        props.html_props.as_ref(),
+    )
+}
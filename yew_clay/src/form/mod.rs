@@ -0,0 +1,17 @@
+mod checkbox;
+mod group;
+mod input;
+mod input_group;
+mod radio_group;
+mod select;
+mod toggle;
+mod types;
+
+pub use checkbox::*;
+pub use group::*;
+pub use input::*;
+pub use input_group::*;
+pub use radio_group::*;
+pub use select::*;
+pub use toggle::*;
+pub use types::*;
@@ -0,0 +1,132 @@
+use super::types::get_option_index;
+use super::FormOption;
+use crate::html_props::{with_html_props, GlobalProps};
+use std::fmt::Debug;
+use web_sys::Event;
+use yew::{classes, function_component, html, Callback, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayRadioGroup. For details, check the docs:
+/// <https://clayui.com/docs/components/radio-group/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayRadioGroupProps<T>
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    /// The name shared by the radio inputs of the group.
+    pub name: String,
+
+    /// The options to choose from. Each one is rendered as a radio input.
+    #[prop_or_default]
+    pub options: Vec<FormOption<T>>,
+
+    /// The value of the checked option (controlled).
+    #[prop_or_default]
+    pub value: Option<T>,
+
+    /// Callback for when an option is checked. It receives the value of the option.
+    #[prop_or_default]
+    pub on_change: Option<Callback<T>>,
+
+    /// Flag to display the options next to each other.
+    #[prop_or(false)]
+    pub inline: bool,
+
+    /// Flag to disable all of the options.
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// NodeRef for the element that contains the radio inputs.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl<T> ClayRadioGroup<T>
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    const CUSTOM_CONTROL: &'static str = "custom-control";
+    const CUSTOM_RADIO: &'static str = "custom-radio";
+    const CUSTOM_CONTROL_INLINE: &'static str = "custom-control-inline";
+    const CUSTOM_CONTROL_INPUT: &'static str = "custom-control-input";
+    const CUSTOM_CONTROL_LABEL: &'static str = "custom-control-label";
+    const CUSTOM_CONTROL_LABEL_TEXT: &'static str = "custom-control-label-text";
+}
+
+/// A Yew implementation of ClayRadioGroup. For more info about ClayRadioGroup, check the
+/// documentation: <https://clayui.com/docs/components/radio-group.html>
+///
+/// The options hold values of any type, and the value of the checked option is passed to
+/// `on_change`.
+#[function_component(ClayRadioGroup)]
+pub fn clay_radio_group<T>(props: &ClayRadioGroupProps<T>) -> Html
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    let ClayRadioGroupProps {
+        name,
+        options,
+        value,
+        on_change,
+        inline,
+        disabled,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let checked = get_option_index(&options, &value);
+    let inline_class = inline.then_some(ClayRadioGroup::<T>::CUSTOM_CONTROL_INLINE);
+
+    let radios = options
+        .into_iter()
+        .enumerate()
+        .map(|(index, option)| {
+            let onchange = on_change.as_ref().map(|on_change| {
+                let value = option.value.clone();
+                on_change.reform(move |_: Event| value.clone())
+            });
+
+            html! {
+                <div
+                    key={index}
+                    class={classes!(
+                        ClayRadioGroup::<T>::CUSTOM_CONTROL,
+                        ClayRadioGroup::<T>::CUSTOM_RADIO,
+                        inline_class
+                    )} >
+                    <label>
+                        <input
+                            checked={checked == Some(index)}
+                            class={ClayRadioGroup::<T>::CUSTOM_CONTROL_INPUT}
+                            disabled={disabled || option.disabled}
+                            name={name.clone()}
+                            {onchange}
+                            type="radio"
+                            value={index.to_string()} />
+                        <span class={ClayRadioGroup::<T>::CUSTOM_CONTROL_LABEL}>
+                            <span class={ClayRadioGroup::<T>::CUSTOM_CONTROL_LABEL_TEXT}>
+                                {option.label}
+                            </span>
+                        </span>
+                    </label>
+                </div>
+            }
+        })
+        .collect::<Html>();
+
+    with_html_props(
+        html! {
+            <div class={classes!(class)} ref={node_ref} role="radiogroup">
+                {radios}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
@@ -0,0 +1,180 @@
+use super::types::get_option_index;
+use super::FormOption;
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::layout::Sizing;
+use std::fmt::Debug;
+use wasm_bindgen::JsCast;
+use web_sys::{Event, HtmlSelectElement};
+use yew::{
+    classes, function_component, html, use_effect, Callback, Classes, Html, NodeRef, Properties,
+};
+
+/// Props for ClaySelect. For details, check the docs:
+/// <https://clayui.com/docs/components/select/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClaySelectProps<T>
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    /// The options to choose from.
+    #[prop_or_default]
+    pub options: Vec<FormOption<T>>,
+
+    /// The value of the selected option (controlled).
+    #[prop_or_default]
+    pub value: Option<T>,
+
+    /// Callback for when an option is selected. It receives the value of the option.
+    #[prop_or_default]
+    pub on_change: Option<Callback<T>>,
+
+    /// Text of a disabled option that is displayed while no option is selected.
+    #[prop_or_default]
+    pub placeholder: Option<String>,
+
+    /// The size of the select. Clay provides styles for the small and large sizes.
+    #[prop_or_default]
+    pub sizing: Option<Sizing>,
+
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    #[prop_or_default]
+    pub id: Option<String>,
+
+    #[prop_or_default]
+    pub name: Option<String>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// NodeRef for the underlying select element.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying select.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl<T> ClaySelect<T>
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    const FORM_CONTROL: &'static str = "form-control";
+    const FORM_CONTROL_SIZE: &'static str = "form-control-";
+
+    fn get_size_class(sizing: &Option<Sizing>) -> Option<String> {
+        sizing.as_ref().map(|sizing| {
+            let sizing = sizing.as_ref();
+            let mut class = String::with_capacity(Self::FORM_CONTROL_SIZE.len() + sizing.len());
+            class.push_str(Self::FORM_CONTROL_SIZE);
+            class.push_str(sizing);
+            class
+        })
+    }
+
+    /// The index of the selected option element, which is shifted by the placeholder.
+    fn get_selected_index(selected: Option<usize>, has_placeholder: bool) -> i32 {
+        let offset = has_placeholder as i32;
+        match selected {
+            Some(index) => index as i32 + offset,
+            None if has_placeholder => 0,
+            None => -1,
+        }
+    }
+}
+
+/// A Yew implementation of ClaySelect. For more info about ClaySelect, check the documentation:
+/// <https://clayui.com/docs/components/select.html>
+///
+/// The options hold values of any type, and the value of the chosen option is passed to
+/// `on_change`.
+#[function_component(ClaySelect)]
+pub fn clay_select<T>(props: &ClaySelectProps<T>) -> Html
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    let ClaySelectProps {
+        options,
+        value,
+        on_change,
+        placeholder,
+        sizing,
+        disabled,
+        id,
+        name,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let selected = get_option_index(&options, &value);
+    let has_placeholder = placeholder.is_some();
+
+    {
+        // The selection is set on the element after every render, so that it always matches
+        // `value` even when the change of the user was not accepted.
+        let node_ref = node_ref.clone();
+        let selected_index = ClaySelect::<T>::get_selected_index(selected, has_placeholder);
+        use_effect(move || {
+            if let Some(select) = node_ref.cast::<HtmlSelectElement>() {
+                select.set_selected_index(selected_index);
+            }
+            || ()
+        });
+    }
+
+    let onchange = on_change.map(|on_change| {
+        let options = options.clone();
+        Callback::from(move |event: Event| {
+            let option = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlSelectElement>().ok())
+                .and_then(|select| {
+                    usize::try_from(select.selected_index() - has_placeholder as i32).ok()
+                })
+                .and_then(|index| options.get(index));
+            if let Some(option) = option {
+                on_change.emit(option.value.clone());
+            }
+        })
+    });
+
+    let placeholder = match placeholder {
+        Some(placeholder) => html! {
+            <option disabled={true} selected={selected.is_none()} value="">
+                {placeholder}
+            </option>
+        },
+        None => html! {},
+    };
+
+    with_html_props(
+        html! {
+            <select
+                class={classes!(
+                    class,
+                    ClaySelect::<T>::FORM_CONTROL,
+                    ClaySelect::<T>::get_size_class(&sizing)
+                )}
+                {disabled}
+                {id}
+                {name}
+                {onchange}
+                ref={node_ref} >
+                {placeholder}
+                {options.iter().enumerate().map(|(index, option)| html! {
+                    <option
+                        key={index}
+                        disabled={option.disabled}
+                        selected={selected == Some(index)}
+                        value={index.to_string()} >
+                        {option.label.clone()}
+                    </option>
+                }).collect::<Html>()}
+            </select>
+        },
+        props.html_props.as_ref(),
+    )
+}
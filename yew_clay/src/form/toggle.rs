@@ -0,0 +1,165 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::icon::{ClayIcon, ClaySymbol};
+use wasm_bindgen::JsCast;
+use web_sys::{Event, HtmlInputElement};
+use yew::{classes, function_component, html, Callback, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayToggle. For details, check the docs:
+/// <https://clayui.com/docs/components/toggle-switch/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayToggleProps {
+    /// Flag to indicate if the toggle is on (controlled).
+    #[prop_or(false)]
+    pub toggled: bool,
+
+    /// Callback for when the toggle is switched. It receives the new state.
+    #[prop_or_default]
+    pub on_toggle: Option<Callback<bool>>,
+
+    /// The text displayed next to the toggle.
+    #[prop_or_default]
+    pub label: Option<Html>,
+
+    /// The text displayed inside of the toggle while it is on.
+    #[prop_or_default]
+    pub label_on: Option<String>,
+
+    /// The text displayed inside of the toggle while it is off.
+    #[prop_or_default]
+    pub label_off: Option<String>,
+
+    /// The icon displayed on the handle while the toggle is on.
+    #[prop_or_default]
+    pub symbol_on: Option<ClaySymbol>,
+
+    /// The icon displayed on the handle while the toggle is off.
+    #[prop_or_default]
+    pub symbol_off: Option<ClaySymbol>,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    #[prop_or_default]
+    pub id: Option<String>,
+
+    #[prop_or_default]
+    pub name: Option<String>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// NodeRef for the underlying input element.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying input.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayToggle {
+    const TOGGLE_SWITCH: &'static str = "toggle-switch";
+    const TOGGLE_SWITCH_CHECK_BAR: &'static str = "toggle-switch-check-bar";
+    const TOGGLE_SWITCH_CHECK: &'static str = "toggle-switch-check";
+    const TOGGLE_SWITCH_BAR: &'static str = "toggle-switch-bar";
+    const TOGGLE_SWITCH_HANDLE: &'static str = "toggle-switch-handle";
+    const TOGGLE_SWITCH_LABEL: &'static str = "toggle-switch-label";
+    const BUTTON_ICON_ON: &'static str = "button-icon button-icon-on toggle-switch-icon";
+    const BUTTON_ICON_OFF: &'static str = "button-icon button-icon-off toggle-switch-icon";
+    const DISABLED: &'static str = "disabled";
+
+    fn gen_icon(
+        symbol: Option<ClaySymbol>,
+        spritemap: Option<&'static str>,
+        class: &'static str,
+    ) -> Html {
+        match symbol {
+            Some(symbol) => html! {
+                <span {class}>
+                    <ClayIcon {spritemap} {symbol} />
+                </span>
+            },
+            None => html! {},
+        }
+    }
+}
+
+/// A Yew implementation of ClayToggle. For more info about ClayToggle, check the documentation:
+/// <https://clayui.com/docs/components/toggle-switch.html>
+#[function_component(ClayToggle)]
+pub fn clay_toggle(props: &ClayToggleProps) -> Html {
+    let ClayToggleProps {
+        toggled,
+        on_toggle,
+        label,
+        label_on,
+        label_off,
+        symbol_on,
+        symbol_off,
+        spritemap,
+        disabled,
+        id,
+        name,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let onchange = on_toggle.map(|on_toggle| {
+        Callback::from(move |event: Event| {
+            let input = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlInputElement>().ok());
+            if let Some(input) = input {
+                on_toggle.emit(input.checked());
+            }
+        })
+    });
+
+    let label = match label {
+        Some(label) => html! {
+            <span class={ClayToggle::TOGGLE_SWITCH_LABEL}>{label}</span>
+        },
+        None => html! {},
+    };
+
+    let input = with_html_props(
+        html! {
+            <input
+                checked={toggled}
+                class={ClayToggle::TOGGLE_SWITCH_CHECK}
+                {disabled}
+                {id}
+                {name}
+                {onchange}
+                ref={node_ref}
+                role="switch"
+                type="checkbox" />
+        },
+        props.html_props.as_ref(),
+    );
+
+    let disabled_class = disabled.then_some(ClayToggle::DISABLED);
+
+    html! {
+        <label class={classes!(class, ClayToggle::TOGGLE_SWITCH, disabled_class)}>
+            <span class={ClayToggle::TOGGLE_SWITCH_CHECK_BAR}>
+                {input}
+                <span aria-hidden="true" class={ClayToggle::TOGGLE_SWITCH_BAR}>
+                    <span
+                        class={ClayToggle::TOGGLE_SWITCH_HANDLE}
+                        data-label-off={label_off}
+                        data-label-on={label_on} >
+                        {ClayToggle::gen_icon(symbol_on, spritemap, ClayToggle::BUTTON_ICON_ON)}
+                        {ClayToggle::gen_icon(symbol_off, spritemap, ClayToggle::BUTTON_ICON_OFF)}
+                    </span>
+                </span>
+            </span>
+            {label}
+        </label>
+    }
+}
@@ -0,0 +1,58 @@
+use strum::AsRefStr;
+
+/// An option of a ClaySelect or a ClayRadioGroup. The value is emitted as is when the option
+/// is chosen, so it can be of any type.
+#[derive(Debug, PartialEq, Clone)]
+pub struct FormOption<T> {
+    /// The text displayed for the option.
+    pub label: String,
+    pub value: T,
+    pub disabled: bool,
+}
+
+impl<T> FormOption<T> {
+    pub fn new(label: impl Into<String>, value: T) -> Self {
+        Self {
+            label: label.into(),
+            value,
+            disabled: false,
+        }
+    }
+}
+
+/// The type of a ClayInput.
+#[derive(AsRefStr, Debug, PartialEq, Clone, Copy, Default)]
+#[strum(serialize_all = "lowercase")]
+pub enum InputType {
+    Date,
+    #[strum(serialize = "datetime-local")]
+    DateTimeLocal,
+    Email,
+    Number,
+    Password,
+    Search,
+    Tel,
+    #[default]
+    Text,
+    Time,
+    Url,
+}
+
+/// The validation state of a ClayFormGroup.
+#[derive(AsRefStr, Debug, PartialEq, Clone, Copy)]
+#[strum(serialize_all = "lowercase")]
+pub enum FormValidation {
+    Error,
+    Success,
+    Warning,
+}
+
+/// Finds the position of the option with the given value.
+pub(crate) fn get_option_index<T: PartialEq>(
+    options: &[FormOption<T>],
+    value: &Option<T>,
+) -> Option<usize> {
+    value
+        .as_ref()
+        .and_then(|value| options.iter().position(|option| option.value == *value))
+}
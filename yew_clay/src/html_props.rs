@@ -0,0 +1,116 @@
+use std::rc::Rc;
+use web_sys::Event;
+use yew::virtual_dom::{Listener, ListenerKind};
+use yew::{AttrValue, Callback, Html};
+
+/// Attributes and event listeners that a component renders on its underlying element, for
+/// anything that its own props don't cover.
+///
+/// They are part of the rendered html, so the attributes are also included in the output of
+/// server-side rendering. Use the `class` prop of the component instead of a `class`
+/// attribute, which would replace the classes of the component.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct HtmlProps {
+    attributes: Vec<(&'static str, AttrValue)>,
+    listeners: Vec<HtmlListener>,
+}
+
+/// HtmlProps for components rendered as any html element.
+pub type GlobalProps = HtmlProps;
+/// HtmlProps for components rendered as an `<a>`.
+pub type AnchorProps = HtmlProps;
+/// HtmlProps for components rendered as a `<button>`.
+pub type ButtonProps = HtmlProps;
+/// HtmlProps for components rendered as an `<li>`.
+pub type LiProps = HtmlProps;
+/// HtmlProps for components rendered as an `<svg>`.
+pub type SvgProps = HtmlProps;
+
+impl HtmlProps {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the attribute `key`, replacing a previous value.
+    pub fn with_attribute(mut self, key: &'static str, value: impl Into<AttrValue>) -> Self {
+        self.add_attribute(key, value);
+        self
+    }
+
+    /// Adds a listener for the event `event_type`, such as `"click"` or `"keydown"`.
+    pub fn with_listener(mut self, event_type: &'static str, callback: Callback<Event>) -> Self {
+        self.add_listener(event_type, callback);
+        self
+    }
+
+    /// Sets the attribute `key`, replacing a previous value.
+    pub fn add_attribute(&mut self, key: &'static str, value: impl Into<AttrValue>) {
+        let value = value.into();
+        match self.attributes.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, current)) => *current = value,
+            None => self.attributes.push((key, value)),
+        }
+    }
+
+    /// Adds a listener for the event `event_type`, such as `"click"` or `"keydown"`.
+    pub fn add_listener(&mut self, event_type: &'static str, callback: Callback<Event>) {
+        self.listeners.push(HtmlListener {
+            event_type,
+            callback,
+        });
+    }
+
+    pub fn get_attribute(&self, key: &str) -> Option<&AttrValue> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, value)| value)
+    }
+
+    pub fn has_attribute(&self, key: &str) -> bool {
+        self.get_attribute(key).is_some()
+    }
+
+    pub fn has_listener(&self, event_type: &str) -> bool {
+        self.listeners
+            .iter()
+            .any(|listener| listener.event_type == event_type)
+    }
+}
+
+/// Renders the attributes and listeners of `html_props` on `html`, which should be the html
+/// element that the props belong to. Anything else is returned as is.
+pub(crate) fn with_html_props(html: Html, html_props: Option<&HtmlProps>) -> Html {
+    match (html, html_props) {
+        (Html::VTag(mut tag), Some(html_props)) => {
+            for (key, value) in &html_props.attributes {
+                tag.add_attribute(key, value.clone());
+            }
+            for listener in &html_props.listeners {
+                tag.add_listener(Rc::new(listener.clone()));
+            }
+            Html::VTag(tag)
+        }
+        (html, _) => html,
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct HtmlListener {
+    event_type: &'static str,
+    callback: Callback<Event>,
+}
+
+impl Listener for HtmlListener {
+    fn kind(&self) -> ListenerKind {
+        ListenerKind::other(self.event_type.into())
+    }
+
+    fn handle(&self, event: Event) {
+        self.callback.emit(event);
+    }
+
+    fn passive(&self) -> bool {
+        false
+    }
+}
@@ -1,15 +1,19 @@
-use gloo_events::EventListener;
-use std::collections::HashMap;
-use yew::{classes, html, Callback, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::svg_props::SvgProps;
-use yew_dom_attributes::DomInjector;
+#[cfg(feature = "icon-registry")]
+mod registry;
+mod symbol;
 
-/// A Yew implementation of ClayIcon.
-pub struct ClayIcon {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+#[cfg(feature = "icon-registry")]
+pub use registry::*;
+pub use symbol::*;
+
+use crate::html_props::{with_html_props, SvgProps};
+#[cfg(feature = "icon-registry")]
+use std::cell::RefCell;
+use yew::{
+    classes, function_component, hook, html, use_context, Classes, Html, NodeRef, Properties,
+};
+#[cfg(feature = "icon-registry")]
+use yew::{use_effect, use_effect_with, use_mut_ref};
 
 /// Props for ClayIcon. For details, check the docs:
 /// https://clayui.com/docs/components/icon/api.html
@@ -20,8 +24,10 @@ pub struct IconProps {
     /// Path to the location of the spritemap resource.
     #[prop_or_default]
     pub spritemap: Option<&'static str>, // TODO: consider switching this type to URI https://docs.rs/http/latest/http/uri/struct.Uri.html
+    /// The symbol to render. Any string is accepted, ids that are not part of the Lexicon
+    /// spritemap become `ClaySymbol::Custom`.
     #[prop_or_default]
-    pub symbol: String,
+    pub symbol: ClaySymbol,
     #[prop_or_default]
     pub node_ref: NodeRef,
     /// A catchall prop to pass down anything not specified here to the underlying component.
@@ -29,59 +35,135 @@ pub struct IconProps {
     pub svg_html_attributes: Option<SvgProps>,
 }
 
-impl Component for ClayIcon {
-    type Message = ();
-    type Properties = IconProps;
+/// A Yew implementation of ClayIcon.
+#[function_component(ClayIcon)]
+pub fn clay_icon(props: &IconProps) -> Html {
+    let sprite_context = use_context::<ClayIconSpriteContext>();
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
+    let IconProps {
+        class,
+        symbol,
+        spritemap,
+        node_ref,
+        ..
+    } = props.clone();
+    let user_classes = class;
+    let icon_class = format!("lexicon-icon-{}", symbol);
+
+    let class = classes!(user_classes, "lexicon-icon", icon_class);
+    let inline_icon = use_inline_icon(props, class.clone());
+
+    if let Some(inline_icon) = inline_icon {
+        return with_html_props(inline_icon, props.svg_html_attributes.as_ref());
     }
 
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let IconProps {
-            class,
-            symbol,
-            spritemap,
-            node_ref,
-            ..
-        } = ctx.props().clone();
-        let user_classes = class;
-        let icon_class = format!("lexicon-icon-{}", symbol);
-
-        let spritemap_val = if let Some(spritemap) = spritemap {
-            spritemap
-        } else {
-            let (spritemap, _) = ctx
-                .link()
-                .context::<ClayIconSpriteContext>(Callback::noop())
-                .expect("either a ClayIconSpritContext to be set or the spritemap prop to be Some");
-            spritemap.0
-        };
-
-        let xlink_href = format!("{}#{}", spritemap_val, symbol);
+    let spritemap_val = if let Some(spritemap) = spritemap {
+        spritemap
+    } else {
+        sprite_context
+            .expect("either a ClayProvider or ClayIconSpriteContext to be set, or the spritemap prop to be Some")
+            .0
+    };
+
+    let xlink_href = format!("{}#{}", spritemap_val, symbol);
 
+    with_html_props(
         html! {
             <svg
-                class={classes!(user_classes, "lexicon-icon", icon_class)}
-                key={symbol}
+                class={class}
+                key={symbol.to_string()}
                 ref={node_ref}
                 role="presentation"
             >
                 <use href={xlink_href} />
             </svg>
+        },
+        props.svg_html_attributes.as_ref(),
+    )
+}
+
+/// Renders the svg element of an inline icon when an IconRegistry is provided and no spritemap
+/// was passed explicitly. The paths of the symbol are set as the inner HTML of the svg element,
+/// so that the browser parses them in the SVG namespace.
+#[cfg(feature = "icon-registry")]
+#[hook]
+fn use_inline_icon(props: &IconProps, class: Classes) -> Option<Html> {
+    let registry = use_context::<IconRegistry>().filter(|_| props.spritemap.is_none());
+    // The last symbol that was reported as missing, so that it is only reported once.
+    let missing_symbol = use_mut_ref(|| None::<String>);
+
+    {
+        let registry = registry.clone();
+        let symbol = props.symbol.clone();
+        use_effect(move || {
+            if let Some(registry) = registry {
+                ClayIcon::report_missing_symbol(&registry, symbol.as_ref(), &missing_symbol);
+            }
+            || ()
+        });
+    }
+
+    let IconProps {
+        symbol, node_ref, ..
+    } = props.clone();
+
+    {
+        let content = registry
+            .as_ref()
+            .and_then(|registry| registry.resolve(symbol.as_ref()))
+            .map(|icon_symbol| icon_symbol.content.clone());
+        use_effect_with((content, node_ref.clone()), |(content, node_ref)| {
+            if let Some(content) = content {
+                ClayIcon::render_inline_symbol(node_ref, content);
+            }
+            || ()
+        });
+    }
+
+    let registry = registry?;
+    let view_box = registry
+        .resolve(symbol.as_ref())
+        .and_then(|icon_symbol| icon_symbol.view_box.clone());
+
+    Some(html! {
+        <svg
+            class={class}
+            key={symbol.to_string()}
+            ref={node_ref}
+            role="presentation"
+            viewBox={view_box} />
+    })
+}
+
+#[cfg(not(feature = "icon-registry"))]
+#[hook]
+fn use_inline_icon(_props: &IconProps, _class: Classes) -> Option<Html> {
+    None
+}
+
+#[cfg(feature = "icon-registry")]
+impl ClayIcon {
+    fn report_missing_symbol(
+        registry: &IconRegistry,
+        symbol: &str,
+        missing_symbol: &RefCell<Option<String>>,
+    ) {
+        let mut missing_symbol = missing_symbol.borrow_mut();
+        if !registry.contains(symbol) && missing_symbol.as_deref() != Some(symbol) {
+            registry.report_missing(symbol);
+            *missing_symbol = Some(symbol.to_string());
         }
     }
 
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(svg_props) = &ctx.props().svg_html_attributes {
-            let svg_props = svg_props.clone();
-            let node_ref = &ctx.props().node_ref;
-            svg_props.inject(&node_ref, &mut self.listeners);
+    /// The markup of the symbol comes from the registry, which is trusted like the spritemap.
+    fn render_inline_symbol(node_ref: &NodeRef, content: &str) {
+        if let Some(svg) = node_ref.cast::<web_sys::Element>() {
+            svg.set_inner_html(content);
         }
     }
 }
 
+/// Context that sets the spritemap for every ClayIcon below it. It is usually set through
+/// ClayProvider, but can also be provided directly.
 #[derive(Debug, Clone, PartialEq)]
-struct ClayIconSpriteContext(&'static str);
+pub struct ClayIconSpriteContext(pub &'static str);
@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::Response;
+use yew::Callback;
+
+const SYMBOL_OPEN: &str = "<symbol";
+const SYMBOL_CLOSE: &str = "</symbol>";
+
+/// A symbol parsed from a spritemap.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct IconSymbol {
+    pub view_box: Option<String>,
+    /// The inner markup of the symbol (its paths, groups, etc.).
+    pub content: String,
+}
+
+/// Determines what happens when a ClayIcon references a symbol that is not in the registry.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct IconFallback {
+    /// A symbol of the registry to render instead of the missing one.
+    pub symbol: Option<String>,
+
+    /// Called with the name of the missing symbol. When not set, a warning is logged to the
+    /// console.
+    pub on_missing: Option<Callback<String>>,
+}
+
+/// The symbols of a spritemap, indexed by their id. When an IconRegistry is provided through
+/// the context (usually with ClayProvider), ClayIcon renders the paths of the symbol inline
+/// instead of referencing the spritemap, which also works for cross-origin spritemaps.
+///
+/// The spritemap can be embedded at compile time:
+///
+/// ```ignore
+/// let registry = IconRegistry::parse(include_str!("../static/icons.svg"));
+/// ```
+///
+/// or loaded at runtime with [`IconRegistry::fetch`].
+#[derive(Clone, Default)]
+pub struct IconRegistry {
+    symbols: Rc<HashMap<String, IconSymbol>>,
+    fallback: IconFallback,
+}
+
+impl IconRegistry {
+    /// Parses the `<symbol>` elements of a spritemap.
+    pub fn parse(spritemap: &str) -> Self {
+        let mut symbols = HashMap::new();
+        let mut rest = spritemap;
+
+        while let Some(start) = rest.find(SYMBOL_OPEN) {
+            let element = &rest[start..];
+            let tag_end = match element.find('>') {
+                Some(tag_end) => tag_end,
+                None => break,
+            };
+            let open_tag = &element[..tag_end];
+            let body_start = tag_end + 1;
+
+            let (content, next) = if open_tag.ends_with('/') {
+                ("", body_start)
+            } else {
+                match element[body_start..].find(SYMBOL_CLOSE) {
+                    Some(body_len) => (
+                        &element[body_start..body_start + body_len],
+                        body_start + body_len + SYMBOL_CLOSE.len(),
+                    ),
+                    None => break,
+                }
+            };
+
+            if let Some(id) = Self::get_attribute(open_tag, "id") {
+                let symbol = IconSymbol {
+                    view_box: Self::get_attribute(open_tag, "viewBox"),
+                    content: content.trim().to_string(),
+                };
+                symbols.insert(id, symbol);
+            }
+
+            rest = &element[next..];
+        }
+
+        Self {
+            symbols: Rc::new(symbols),
+            fallback: IconFallback::default(),
+        }
+    }
+
+    /// Downloads a spritemap and parses it.
+    pub async fn fetch(url: &str) -> Result<Self, JsValue> {
+        let window = web_sys::window().ok_or_else(|| JsValue::from_str("no window available"))?;
+        let response: Response = JsFuture::from(window.fetch_with_str(url))
+            .await?
+            .dyn_into()?;
+
+        if !response.ok() {
+            return Err(JsValue::from_str(&format!(
+                "failed to load the spritemap {}: {}",
+                url,
+                response.status()
+            )));
+        }
+
+        let text = JsFuture::from(response.text()?).await?;
+        let text = text.as_string().unwrap_or_default();
+        Ok(Self::parse(&text))
+    }
+
+    /// Sets what to do when a symbol is missing.
+    pub fn with_fallback(mut self, fallback: IconFallback) -> Self {
+        self.fallback = fallback;
+        self
+    }
+
+    pub fn get(&self, symbol: &str) -> Option<&IconSymbol> {
+        self.symbols.get(symbol)
+    }
+
+    pub fn contains(&self, symbol: &str) -> bool {
+        self.symbols.contains_key(symbol)
+    }
+
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Returns the symbol, or the fallback symbol when it is missing.
+    pub fn resolve(&self, symbol: &str) -> Option<&IconSymbol> {
+        self.get(symbol).or_else(|| {
+            self.fallback
+                .symbol
+                .as_deref()
+                .and_then(|fallback| self.get(fallback))
+        })
+    }
+
+    /// Reports a symbol that is not in the registry.
+    pub fn report_missing(&self, symbol: &str) {
+        match &self.fallback.on_missing {
+            Some(on_missing) => on_missing.emit(symbol.to_string()),
+            None => {
+                gloo_console::warn!(format!("the symbol {} is not in the icon registry", symbol))
+            }
+        }
+    }
+
+    fn get_attribute(tag: &str, name: &str) -> Option<String> {
+        ['"', '\''].iter().find_map(|quote| {
+            let pattern = format!("{}={}", name, quote);
+            tag.match_indices(&pattern)
+                .find(|(index, _)| {
+                    tag[..*index]
+                        .chars()
+                        .last()
+                        .is_some_and(char::is_whitespace)
+                })
+                .and_then(|(index, _)| {
+                    let value_start = index + pattern.len();
+                    let value_len = tag[value_start..].find(*quote)?;
+                    Some(tag[value_start..value_start + value_len].to_string())
+                })
+        })
+    }
+}
+
+impl PartialEq for IconRegistry {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.symbols, &other.symbols) && self.fallback == other.fallback
+    }
+}
+
+impl Debug for IconRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IconRegistry")
+            .field("symbols", &self.symbols.len())
+            .field("fallback", &self.fallback)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_the_symbols_of_a_spritemap() {
+        let registry = IconRegistry::parse(
+            r#"<svg xmlns="http://www.w3.org/2000/svg">
+                <symbol id="angle-left" viewBox="0 0 512 512">
+                    <path d="M114 256"></path>
+                </symbol>
+                <symbol viewBox='0 0 16 16' id='times'><path d="M1 1"/></symbol>
+            </svg>"#,
+        );
+
+        assert_eq!(registry.len(), 2);
+        assert_eq!(
+            registry.get("angle-left"),
+            Some(&IconSymbol {
+                view_box: Some("0 0 512 512".to_string()),
+                content: r#"<path d="M114 256"></path>"#.to_string(),
+            })
+        );
+        assert_eq!(
+            registry.get("times"),
+            Some(&IconSymbol {
+                view_box: Some("0 0 16 16".to_string()),
+                content: r#"<path d="M1 1"/>"#.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_self_closing_symbols_and_skips_symbols_without_id() {
+        let registry = IconRegistry::parse(
+            r#"<symbol id="empty" /><symbol viewBox="0 0 1 1"><path/></symbol>"#,
+        );
+
+        assert_eq!(registry.len(), 1);
+        assert_eq!(registry.get("empty"), Some(&IconSymbol::default()));
+    }
+
+    #[test]
+    fn does_not_match_attributes_that_end_with_the_name() {
+        let registry = IconRegistry::parse(r#"<symbol data-id="wrong" id="right"></symbol>"#);
+
+        assert!(registry.contains("right"));
+        assert!(!registry.contains("wrong"));
+    }
+
+    #[test]
+    fn stops_at_an_unterminated_symbol() {
+        let registry = IconRegistry::parse(r#"<symbol id="ok"></symbol><symbol id="broken">"#);
+
+        assert!(registry.contains("ok"));
+        assert!(!registry.contains("broken"));
+    }
+
+    #[test]
+    fn resolves_missing_symbols_to_the_fallback() {
+        let registry =
+            IconRegistry::parse(r#"<symbol id="question"></symbol>"#).with_fallback(IconFallback {
+                symbol: Some("question".to_string()),
+                on_missing: None,
+            });
+
+        assert_eq!(registry.resolve("missing"), registry.get("question"));
+        assert!(IconRegistry::parse("").resolve("missing").is_none());
+    }
+}
@@ -0,0 +1,42 @@
+use std::fmt::Display;
+use yew::html::IntoPropValue;
+
+// The ClaySymbol enum is generated by the build script from `icons/icons.svg`.
+include!(concat!(env!("OUT_DIR"), "/clay_symbol.rs"));
+
+impl Default for ClaySymbol {
+    fn default() -> Self {
+        Self::Custom(String::new())
+    }
+}
+
+impl Display for ClaySymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// Ids that are not in the Lexicon spritemap become `Custom` symbols.
+impl From<&str> for ClaySymbol {
+    fn from(id: &str) -> Self {
+        Self::from_lexicon(id).unwrap_or_else(|| Self::Custom(id.to_string()))
+    }
+}
+
+impl From<String> for ClaySymbol {
+    fn from(id: String) -> Self {
+        Self::from_lexicon(&id).unwrap_or(Self::Custom(id))
+    }
+}
+
+impl IntoPropValue<ClaySymbol> for &'static str {
+    fn into_prop_value(self) -> ClaySymbol {
+        self.into()
+    }
+}
+
+impl IntoPropValue<ClaySymbol> for String {
+    fn into_prop_value(self) -> ClaySymbol {
+        self.into()
+    }
+}
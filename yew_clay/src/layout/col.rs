@@ -1,17 +1,6 @@
-use gloo_events::EventListener;
-use std::collections::HashMap;
+use crate::html_props::{with_html_props, GlobalProps};
 use strum::Display;
-use yew::{classes, html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
-
-/// A Yew implementation of ClayCol. For more info about ClayCol, check the documentation:
-/// <https://clayui.com/docs/components/layout.html>
-pub struct ClayCol {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 /// Props for ClayContainer. For details, check the docs:
 /// <https://clayui.com/docs/components/layout/api.html#container>
@@ -60,7 +49,7 @@ pub struct ClayColProps {
 }
 
 impl ClayCol {
-    fn get_col_class(&self, props: &ClayColProps) -> Option<String> {
+    fn get_col_class(props: &ClayColProps) -> Option<String> {
         let ClayColProps {
             lg,
             md,
@@ -84,22 +73,12 @@ impl ClayCol {
         }
     }
 
-    fn get_xs(&self, props: &ClayColProps) -> Option<ColSize> {
+    fn get_xs(props: &ClayColProps) -> Option<ColSize> {
         let ClayColProps { xs, size, .. } = props;
-        if let Some(xs) = xs {
-            Some(xs.to_owned())
-        } else if let Some(size) = size {
-            Some(size.to_owned())
-        } else {
-            None
-        }
+        xs.as_ref().or(size.as_ref()).cloned()
     }
 
-    fn get_col_size_class(
-        &self,
-        size: &Option<ColSize>,
-        screen_size: ScreenSize,
-    ) -> Option<String> {
+    fn get_col_size_class(size: &Option<ColSize>, screen_size: ScreenSize) -> Option<String> {
         if let Some(size) = size {
             match size {
                 ColSize::Boolean(val) => {
@@ -110,7 +89,7 @@ impl ClayCol {
                     }
                 }
                 ColSize::Number(val) => {
-                    if *val > 12 || *val <= 0 {
+                    if *val > 12 || *val == 0 {
                         panic!(
                             "The value of ColSize::Number cannot be greater than 12 or less than 1"
                         )
@@ -126,55 +105,42 @@ impl ClayCol {
     }
 }
 
-impl Component for ClayCol {
-    type Message = ();
-    type Properties = ClayColProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let ClayColProps {
-            class,
-            container_element,
-            children,
-            lg,
-            md,
-            sm,
-            xl,
-            node_ref,
-            ..
-        } = ctx.props().clone();
-
-        let col_class = self.get_col_class(&ctx.props());
-
-        let xs = self.get_xs(&ctx.props());
-
-        let lg_class = self.get_col_size_class(&lg, ScreenSize::Lg);
-        let md_class = self.get_col_size_class(&md, ScreenSize::Md);
-        let xs_class = self.get_col_size_class(&xs, ScreenSize::Xs);
-        let sm_class = self.get_col_size_class(&sm, ScreenSize::Sm);
-        let xl_class = self.get_col_size_class(&xl, ScreenSize::Xl);
-
+/// A Yew implementation of ClayCol. For more info about ClayCol, check the documentation:
+/// <https://clayui.com/docs/components/layout.html>
+#[function_component(ClayCol)]
+pub fn clay_col(props: &ClayColProps) -> Html {
+    let ClayColProps {
+        class,
+        container_element,
+        children,
+        lg,
+        md,
+        sm,
+        xl,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let col_class = ClayCol::get_col_class(props);
+
+    let xs = ClayCol::get_xs(props);
+
+    let lg_class = ClayCol::get_col_size_class(&lg, ScreenSize::Lg);
+    let md_class = ClayCol::get_col_size_class(&md, ScreenSize::Md);
+    let xs_class = ClayCol::get_col_size_class(&xs, ScreenSize::Xs);
+    let sm_class = ClayCol::get_col_size_class(&sm, ScreenSize::Sm);
+    let xl_class = ClayCol::get_col_size_class(&xl, ScreenSize::Xl);
+
+    with_html_props(
         html! {
             <@{container_element}
                 class={classes!(class, col_class, lg_class, md_class, xs_class, sm_class, xl_class)}
                 ref={node_ref} >
                 {children}
             </@>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            let node_ref = &ctx.props().node_ref;
-            html_props.inject(node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.html_props.as_ref(),
+    )
 }
 
 #[derive(Display, Debug, PartialEq, Clone)]
@@ -1,17 +1,6 @@
 use super::Sizing;
-use gloo_events::EventListener;
-use std::collections::HashMap;
-use yew::{classes, html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
-
-/// A Yew implementation of ClayContainer. For more info about ClayContainer, check the documentation:
-/// <https://clayui.com/docs/components/layout.html>
-pub struct ClayContainer {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 /// Props for ClayContainer. For details, check the docs:
 /// <https://clayui.com/docs/components/layout/api.html#container>
@@ -60,12 +49,12 @@ impl ClayContainer {
     const VIEW: &'static str = "view";
     const MAX: &'static str = "max";
 
-    fn get_container_type_class(&self, fluid: bool) -> String {
+    fn get_container_type_class(fluid: bool) -> String {
         if fluid {
             let mut container_class =
                 String::with_capacity(Self::CONTAINER.len() + Self::FLUID.len() + 1);
             container_class.push_str(Self::CONTAINER);
-            container_class.push_str("-");
+            container_class.push('-');
             container_class.push_str(Self::FLUID);
             container_class
         } else {
@@ -73,26 +62,26 @@ impl ClayContainer {
         }
     }
 
-    fn get_container_form_size_class(&self, form_size: Option<FormSize>) -> Option<String> {
+    fn get_container_form_size_class(form_size: Option<FormSize>) -> Option<String> {
         form_size.map(|size| {
             let size = size.as_ref();
             let mut form_class =
                 String::with_capacity(Self::CONTAINER.len() + Self::FLUID.len() + size.len() + 2);
             form_class.push_str(Self::CONTAINER);
-            form_class.push_str("-");
+            form_class.push('-');
             form_class.push_str(Self::FORM);
-            form_class.push_str("-");
+            form_class.push('-');
             form_class.push_str(size);
             form_class
         })
     }
 
-    fn get_container_view_class(&self, view: bool) -> Option<String> {
+    fn get_container_view_class(view: bool) -> Option<String> {
         if view {
             let mut view_class =
                 String::with_capacity(Self::CONTAINER.len() + Self::VIEW.len() + 1);
             view_class.push_str(Self::CONTAINER);
-            view_class.push_str("-");
+            view_class.push('-');
             view_class.push_str(Self::VIEW);
             Some(view_class)
         } else {
@@ -100,9 +89,8 @@ impl ClayContainer {
         }
     }
 
-    fn get_fluid_max_class(&self, fluid: bool, fluid_size: Option<FluidSize>) -> Option<String> {
-        if fluid && fluid_size.is_some() {
-            let fluid_size = fluid_size.unwrap();
+    fn get_fluid_max_class(fluid: bool, fluid_size: Option<FluidSize>) -> Option<String> {
+        if let (true, Some(fluid_size)) = (fluid, fluid_size) {
             let fluid_size_ref = fluid_size.as_ref();
             let mut fluid_max_class = String::with_capacity(
                 Self::CONTAINER.len()
@@ -112,11 +100,11 @@ impl ClayContainer {
                     + 3,
             );
             fluid_max_class.push_str(Self::CONTAINER);
-            fluid_max_class.push_str("-");
+            fluid_max_class.push('-');
             fluid_max_class.push_str(Self::FLUID);
-            fluid_max_class.push_str("-");
+            fluid_max_class.push('-');
             fluid_max_class.push_str(Self::MAX);
-            fluid_max_class.push_str("-");
+            fluid_max_class.push('-');
             fluid_max_class.push_str(fluid_size_ref);
             Some(fluid_max_class)
         } else {
@@ -125,51 +113,38 @@ impl ClayContainer {
     }
 }
 
-impl Component for ClayContainer {
-    type Message = ();
-    type Properties = ClayContainerProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let ClayContainerProps {
-            container_element,
-            class,
-            fluid,
-            form_size,
-            view,
-            fluid_size,
-            node_ref,
-            children,
-            ..
-        } = ctx.props().clone();
-
-        let container_type = self.get_container_type_class(fluid);
-        let container_form_size = self.get_container_form_size_class(form_size);
-        let container_view = self.get_container_view_class(view);
-        let fluid_max = self.get_fluid_max_class(fluid, fluid_size);
-        let tag_name = container_element;
-
+/// A Yew implementation of ClayContainer. For more info about ClayContainer, check the documentation:
+/// <https://clayui.com/docs/components/layout.html>
+#[function_component(ClayContainer)]
+pub fn clay_container(props: &ClayContainerProps) -> Html {
+    let ClayContainerProps {
+        container_element,
+        class,
+        fluid,
+        form_size,
+        view,
+        fluid_size,
+        node_ref,
+        children,
+        html_props,
+    } = props.clone();
+
+    let container_type = ClayContainer::get_container_type_class(fluid);
+    let container_form_size = ClayContainer::get_container_form_size_class(form_size);
+    let container_view = ClayContainer::get_container_view_class(view);
+    let fluid_max = ClayContainer::get_fluid_max_class(fluid, fluid_size);
+    let tag_name = container_element;
+
+    with_html_props(
         html! {
             <@{tag_name}
                 class={classes!(class, container_type, container_form_size, container_view, fluid_max)}
                 ref={node_ref} >
                 {children}
             </@>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            let node_ref = &ctx.props().node_ref;
-            html_props.inject(node_ref, &mut self.listeners);
-        }
-    }
+        },
+        html_props.as_ref(),
+    )
 }
 
 /// Type alias for [Sizing].
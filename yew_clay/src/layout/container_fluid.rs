@@ -1,18 +1,7 @@
 use super::container::ClayContainer;
 use super::Sizing;
-use gloo_events::EventListener;
-use std::collections::HashMap;
-use yew::{html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
-
-/// A Yew implementation of ClayContainer. For more info about ClayContainer, check the documentation:
-/// <https://clayui.com/docs/components/layout.html>
-pub struct ClayContainerFluid {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+use crate::html_props::GlobalProps;
+use yew::{function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 /// Props for ClayContainer. For details, check the docs:
 /// <https://clayui.com/docs/components/layout/api.html#container>
@@ -49,48 +38,33 @@ pub struct ClayContainerFluidProps {
     pub html_props: Option<GlobalProps>,
 }
 
-impl Component for ClayContainerFluid {
-    type Message = ();
-    type Properties = ClayContainerFluidProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let ClayContainerFluidProps {
-            container_element,
-            class,
-            node_ref,
-            children,
-            form_size,
-            view,
-            size,
-            ..
-        } = ctx.props().clone();
-
-        html! {
-            <ClayContainer
-                class={class}
-                container_element={container_element}
-                form_size={form_size}
-                view={view}
-                fluid={true}
-                fluid_size={size}
-                ref={node_ref} >
-                {children}
-            </ClayContainer>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            let node_ref = &ctx.props().node_ref;
-            html_props.inject(node_ref, &mut self.listeners);
-        }
+/// A Yew implementation of ClayContainer. For more info about ClayContainer, check the documentation:
+/// <https://clayui.com/docs/components/layout.html>
+#[function_component(ClayContainerFluid)]
+pub fn clay_container_fluid(props: &ClayContainerFluidProps) -> Html {
+    let ClayContainerFluidProps {
+        container_element,
+        class,
+        node_ref,
+        children,
+        form_size,
+        view,
+        size,
+        html_props,
+    } = props.clone();
+
+    html! {
+        <ClayContainer
+            class={class}
+            container_element={container_element}
+            form_size={form_size}
+            view={view}
+            fluid={true}
+            fluid_size={size}
+            {html_props}
+            node_ref={node_ref} >
+            {children}
+        </ClayContainer>
     }
 }
 
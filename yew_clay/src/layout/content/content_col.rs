@@ -1,17 +1,6 @@
+use crate::html_props::{with_html_props, GlobalProps};
 use crate::HasBoolClass;
-use gloo_events::EventListener;
-use std::collections::HashMap;
-use yew::{classes, html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
-
-/// A Yew implementation of ClayContentCol. For more info about ClayContentCol, check the documentation:
-/// <https://clayui.com/docs/components/layout.html>
-pub struct ClayContentCol {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 /// Props for ClayContentCol. For details, check the docs:
 /// <https://clayui.com/docs/components/layout/api.html#contentcol>
@@ -62,48 +51,35 @@ impl ClayContentCol {
 
 impl HasBoolClass for ClayContentCol {}
 
-impl Component for ClayContentCol {
-    type Message = ();
-    type Properties = ClayContentColProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props().clone();
-        let ClayContentColProps {
-            class,
-            container_element,
-            children,
-            expand,
-            shrink,
-            float_end,
-            node_ref,
-            ..
-        } = props;
-
-        let expand_class = self.get_bool_class(expand, Self::AUTOFIT_COL_EXPAND);
-        let gutter_class = self.get_bool_class(expand, Self::AUTOFIT_COL_GUTTER);
-        let shrink_class = self.get_bool_class(shrink, Self::AUTOFIT_COL_SHRINK);
-        let float_class = self.get_bool_class(float_end, Self::AUTOFIT_COL_END);
-
+/// A Yew implementation of ClayContentCol. For more info about ClayContentCol, check the documentation:
+/// <https://clayui.com/docs/components/layout.html>
+#[function_component(ClayContentCol)]
+pub fn clay_content_col(props: &ClayContentColProps) -> Html {
+    let props = props.clone();
+    let ClayContentColProps {
+        class,
+        container_element,
+        children,
+        expand,
+        shrink,
+        float_end,
+        node_ref,
+        ..
+    } = props;
+
+    let expand_class = ClayContentCol::get_bool_class(expand, ClayContentCol::AUTOFIT_COL_EXPAND);
+    let gutter_class = ClayContentCol::get_bool_class(expand, ClayContentCol::AUTOFIT_COL_GUTTER);
+    let shrink_class = ClayContentCol::get_bool_class(shrink, ClayContentCol::AUTOFIT_COL_SHRINK);
+    let float_class = ClayContentCol::get_bool_class(float_end, ClayContentCol::AUTOFIT_COL_END);
+
+    with_html_props(
         html! {
             <@{container_element}
-                class={classes!(class, Self::AUTOFIT_COL, expand_class, gutter_class, shrink_class, float_class)}
+                class={classes!(class, ClayContentCol::AUTOFIT_COL, expand_class, gutter_class, shrink_class, float_class)}
                 ref={node_ref} >
                 {children.clone()}
             </@>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            let node_ref = &ctx.props().node_ref;
-            html_props.inject(node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.html_props.as_ref(),
+    )
 }
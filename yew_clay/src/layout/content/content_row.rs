@@ -1,20 +1,9 @@
-use gloo_events::EventListener;
-use std::collections::HashMap;
+use crate::html_props::{with_html_props, GlobalProps};
 use strum::Display;
-use yew::{classes, html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 use crate::HasBoolClass;
 
-/// A Yew implementation of ClayContent. For more info about ClayContent, check the documentation:
-/// <https://clayui.com/docs/components/layout.html>
-pub struct ClayContentRow {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
-
 /// Props for ClayContainer. For details, check the docs:
 /// <https://clayui.com/docs/components/layout/api.html#container>
 #[derive(Debug, Properties, PartialEq, Clone, Default)]
@@ -60,21 +49,21 @@ impl ClayContentRow {
     const AUTOFIT_PADDED_NO_GUTTERS: &'static str = "autofit-padded-no-gutters";
     const AUTOFIT_ROW: &'static str = "autofit-row";
 
-    fn get_float_class(&self, float: &Option<ContentFloat>) -> Option<String> {
+    fn get_float_class(float: &Option<ContentFloat>) -> Option<String> {
         if let Some(float) = float {
             match float {
                 ContentFloat::Boolean(val) => match val {
                     true => Some(Self::AUTOFIT_FLOAT.into()),
                     false => None,
                 },
-                _ => Some(format!("{}-{}", Self::AUTOFIT_FLOAT, float.to_string())),
+                _ => Some(format!("{}-{}", Self::AUTOFIT_FLOAT, float)),
             }
         } else {
             None
         }
     }
 
-    fn get_no_gutters_class(&self, no_gutters: &Option<ContentNoGutters>) -> Option<String> {
+    fn get_no_gutters_class(no_gutters: &Option<ContentNoGutters>) -> Option<String> {
         if let Some(no_gutters) = no_gutters {
             match no_gutters {
                 ContentNoGutters::Boolean(val) => match val {
@@ -84,7 +73,7 @@ impl ClayContentRow {
                 _ => Some(format!(
                     "{}-{}",
                     Self::AUTOFIT_PADDED_NO_GUTTERS,
-                    no_gutters.to_string()
+                    no_gutters
                 )),
             }
         } else {
@@ -92,69 +81,47 @@ impl ClayContentRow {
         }
     }
 
-    fn get_vertical_align_class(
-        &self,
-        vertical_align: &Option<ContentVerticalAlign>,
-    ) -> Option<String> {
-        if let Some(vertical_align) = vertical_align {
-            Some(format!(
-                "{}-{}",
-                Self::AUTOFIT_ROW,
-                vertical_align.to_string()
-            ))
-        } else {
-            None
-        }
+    fn get_vertical_align_class(vertical_align: &Option<ContentVerticalAlign>) -> Option<String> {
+        vertical_align
+            .as_ref()
+            .map(|vertical_align| format!("{}-{}", Self::AUTOFIT_ROW, vertical_align))
     }
 }
 
 impl HasBoolClass for ClayContentRow {}
 
-impl Component for ClayContentRow {
-    type Message = ();
-    type Properties = ClayContentRowProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props().clone();
-        let ClayContentRowProps {
-            class,
-            container_element,
-            children,
-            float,
-            padded,
-            no_gutters,
-            vertical_align,
-            node_ref,
-            ..
-        } = props;
-
-        let float_class = self.get_float_class(&float);
-        let padded_class = self.get_bool_class(padded, Self::AUTOFIT_PADDED);
-        let no_gutters_class = self.get_no_gutters_class(&no_gutters);
-        let vertical_align_class = self.get_vertical_align_class(&vertical_align);
-
+/// A Yew implementation of ClayContent. For more info about ClayContent, check the documentation:
+/// <https://clayui.com/docs/components/layout.html>
+#[function_component(ClayContentRow)]
+pub fn clay_content_row(props: &ClayContentRowProps) -> Html {
+    let props = props.clone();
+    let ClayContentRowProps {
+        class,
+        container_element,
+        children,
+        float,
+        padded,
+        no_gutters,
+        vertical_align,
+        node_ref,
+        ..
+    } = props;
+
+    let float_class = ClayContentRow::get_float_class(&float);
+    let padded_class = ClayContentRow::get_bool_class(padded, ClayContentRow::AUTOFIT_PADDED);
+    let no_gutters_class = ClayContentRow::get_no_gutters_class(&no_gutters);
+    let vertical_align_class = ClayContentRow::get_vertical_align_class(&vertical_align);
+
+    with_html_props(
         html! {
             <@{container_element}
                 class={classes!(class, "autofit-row", float_class, padded_class, no_gutters_class, vertical_align_class)}
                 ref={node_ref} >
                 {children.clone()}
             </@>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            let node_ref = &ctx.props().node_ref;
-            html_props.inject(node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.html_props.as_ref(),
+    )
 }
 
 #[derive(Display, Debug, PartialEq, Clone)]
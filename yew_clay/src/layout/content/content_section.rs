@@ -1,16 +1,5 @@
-use gloo_events::EventListener;
-use std::collections::HashMap;
-use yew::{classes, html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
-
-/// A Yew implementation of ClayContentSection. For more info about ClayContentSection, check the documentation:
-/// <https://clayui.com/docs/components/layout.html>
-pub struct ClayContentSection {
-    /// This vec holds all the EventListeners defined for this component. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 /// Props for ClayContentCol. For details, check the docs:
 /// <https://clayui.com/docs/components/layout/api.html#contentcol>
@@ -38,40 +27,27 @@ impl ClayContentSection {
     const AUTOFIT_SECTION: &'static str = "autofit-section";
 }
 
-impl Component for ClayContentSection {
-    type Message = ();
-    type Properties = ClayContentSectionProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props().clone();
-        let ClayContentSectionProps {
-            class,
-            container_element,
-            children,
-            node_ref,
-            ..
-        } = props;
-
+/// A Yew implementation of ClayContentSection. For more info about ClayContentSection, check the documentation:
+/// <https://clayui.com/docs/components/layout.html>
+#[function_component(ClayContentSection)]
+pub fn clay_content_section(props: &ClayContentSectionProps) -> Html {
+    let props = props.clone();
+    let ClayContentSectionProps {
+        class,
+        container_element,
+        children,
+        node_ref,
+        ..
+    } = props;
+
+    with_html_props(
         html! {
             <@{container_element}
-                class={classes!(class, Self::AUTOFIT_SECTION)}
+                class={classes!(class, ClayContentSection::AUTOFIT_SECTION)}
                 ref={node_ref} >
                 {children.clone()}
             </@>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            let node_ref = &ctx.props().node_ref;
-            html_props.inject(node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.html_props.as_ref(),
+    )
 }
@@ -1,18 +1,7 @@
+use crate::html_props::{with_html_props, GlobalProps};
 use crate::HasBoolClass;
-use gloo_events::EventListener;
-use std::collections::HashMap;
 use strum::AsRefStr;
-use yew::{classes, html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
-
-/// A Yew implementation of ClayRow. For more info about ClayRow, check the documentation:
-/// <https://clayui.com/docs/components/layout.html>
-pub struct ClayRow {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 /// Props for ClayRow. For details, check the docs:
 /// <https://clayui.com/docs/components/layout/api.html#row>
@@ -50,13 +39,13 @@ impl ClayRow {
     const NO_GUTTERS: &'static str = "no-gutters";
     const JUSTIFY_CONTENT: &'static str = "justify-content";
 
-    fn get_justify_class(&self, justify: Option<RowJustify>) -> Option<String> {
+    fn get_justify_class(justify: Option<RowJustify>) -> Option<String> {
         if let Some(justify) = justify {
             let justify = justify.as_ref();
             let mut justify_class =
                 String::with_capacity(justify.len() + Self::JUSTIFY_CONTENT.len() + 1);
             justify_class.push_str(Self::JUSTIFY_CONTENT);
-            justify_class.push_str("-");
+            justify_class.push('-');
             justify_class.push_str(justify);
             Some(justify_class)
         } else {
@@ -67,47 +56,34 @@ impl ClayRow {
 
 impl HasBoolClass for ClayRow {}
 
-impl Component for ClayRow {
-    type Message = ();
-    type Properties = ClayRowProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let props = ctx.props().clone();
-        let ClayRowProps {
-            class,
-            container_element,
-            children,
-            gutters,
-            justify,
-            node_ref,
-            ..
-        } = props;
-
-        let gutters_class = self.match_bool(!gutters, Self::NO_GUTTERS);
-        let justify_class = self.get_justify_class(justify);
-
+/// A Yew implementation of ClayRow. For more info about ClayRow, check the documentation:
+/// <https://clayui.com/docs/components/layout.html>
+#[function_component(ClayRow)]
+pub fn clay_row(props: &ClayRowProps) -> Html {
+    let props = props.clone();
+    let ClayRowProps {
+        class,
+        container_element,
+        children,
+        gutters,
+        justify,
+        node_ref,
+        ..
+    } = props;
+
+    let gutters_class = ClayRow::match_bool(!gutters, ClayRow::NO_GUTTERS);
+    let justify_class = ClayRow::get_justify_class(justify);
+
+    with_html_props(
         html! {
             <@{container_element}
-                class={classes!(class, Self::ROW, gutters_class, justify_class)}
+                class={classes!(class, ClayRow::ROW, gutters_class, justify_class)}
                 ref={node_ref} >
                 {children}
             </@>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            let node_ref = &ctx.props().node_ref;
-            html_props.inject(node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.html_props.as_ref(),
+    )
 }
 
 #[derive(AsRefStr, Debug, PartialEq, Clone)]
@@ -1,6 +1,6 @@
+use crate::html_props::GlobalProps;
 use std::rc::Rc;
 use yew::{Children, Classes, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
 
 mod sheet;
 pub use sheet::*;
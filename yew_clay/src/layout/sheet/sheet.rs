@@ -1,15 +1,5 @@
-use gloo_events::EventListener;
-use std::collections::HashMap;
-use yew::{classes, html, Children, Classes, Component, Context, Html, NodeRef, Properties};
-use yew_dom_attributes::global_props::GlobalProps;
-use yew_dom_attributes::DomInjector;
-
-/// A Yew implementation of ClaySheet.
-pub struct ClaySheet {
-    /// This vec holds all the EventListeners defined for this button. They will be automatically
-    /// removed when the button is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
 
 /// A generic set of props for container elements.
 #[derive(Debug, Properties, PartialEq, Clone, Default)]
@@ -38,42 +28,28 @@ pub struct SheetProps {
     pub html_props: Option<GlobalProps>,
 }
 
-impl Component for ClaySheet {
-    type Message = ();
-    type Properties = SheetProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let SheetProps {
-            container_element,
-            class,
-            node_ref,
-            children,
-            large,
-            ..
-        } = ctx.props().clone();
-        let size = if large { Some("sheet-lg") } else { None };
-        let tag_name = container_element;
-
+/// A Yew implementation of ClaySheet.
+#[function_component(ClaySheet)]
+pub fn clay_sheet(props: &SheetProps) -> Html {
+    let SheetProps {
+        container_element,
+        class,
+        node_ref,
+        children,
+        large,
+        ..
+    } = props.clone();
+    let size = if large { Some("sheet-lg") } else { None };
+    let tag_name = container_element;
+
+    with_html_props(
         html! {
             <@{tag_name}
                 class={classes!(class, "sheet", "sheet-section", size)}
                 ref={node_ref} >
                 {children}
             </@>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some(html_props) = &ctx.props().html_props {
-            let html_props = html_props.clone();
-            let node_ref = &ctx.props().node_ref;
-            html_props.inject(node_ref, &mut self.listeners);
-        }
-    }
+        },
+        props.html_props.as_ref(),
+    )
 }
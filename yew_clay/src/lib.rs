@@ -1,8 +1,16 @@
+// Components live in a file named after their module, e.g. `button/button.rs`.
+#![allow(clippy::module_inception)]
+
 #[cfg(feature = "alert")]
 mod alert;
 #[cfg(feature = "alert")]
 pub use alert::*;
 
+#[cfg(feature = "autocomplete")]
+mod autocomplete;
+#[cfg(feature = "autocomplete")]
+pub use autocomplete::*;
+
 #[cfg(feature = "badge")]
 mod badge;
 #[cfg(feature = "badge")]
@@ -23,6 +31,11 @@ mod card;
 #[cfg(feature = "card")]
 pub use card::aspect_ratio::{ContainerAspectRatioType, Props};
 
+#[cfg(feature = "form")]
+mod form;
+#[cfg(feature = "form")]
+pub use form::*;
+
 #[cfg(feature = "icon")]
 mod icon;
 #[cfg(feature = "icon")]
@@ -48,22 +61,61 @@ mod dropdown;
 #[cfg(feature = "dropdown")]
 pub use dropdown::*;
 
-#[cfg(any(feature = "dropdown"))]
+#[cfg(feature = "modal")]
+mod modal;
+#[cfg(feature = "modal")]
+pub use modal::*;
+
+#[cfg(feature = "pagination")]
+mod pagination;
+#[cfg(feature = "pagination")]
+pub use pagination::*;
+
+#[cfg(feature = "panel")]
+mod panel;
+#[cfg(feature = "panel")]
+pub use panel::*;
+
+#[cfg(feature = "provider")]
 mod provider;
+#[cfg(feature = "provider")]
+pub use provider::*;
+
+#[cfg(feature = "table")]
+mod table;
+#[cfg(feature = "table")]
+pub use table::*;
+
+#[cfg(feature = "tabs")]
+mod tabs;
+#[cfg(feature = "tabs")]
+pub use tabs::*;
 
-#[cfg(any(feature = "dropdown"))]
+#[cfg(feature = "virtual-list")]
+mod virtual_list;
+#[cfg(feature = "virtual-list")]
+pub use virtual_list::*;
+
+#[cfg(feature = "focus-scope")]
 mod shared;
+#[cfg(feature = "focus-scope")]
+pub use shared::focus_management::{FocusDirection, FocusManager};
+#[cfg(feature = "focus-scope")]
+pub use shared::focus_scope::*;
+
+mod html_props;
+pub use html_props::{AnchorProps, ButtonProps, GlobalProps, HtmlProps, LiProps, SvgProps};
 
 trait HasBoolClass {
-    fn get_bool_class(&self, boolean: Option<bool>, class_name: &'static str) -> Option<String> {
+    fn get_bool_class(boolean: Option<bool>, class_name: &'static str) -> Option<String> {
         if let Some(boolean) = boolean {
-            self.match_bool(boolean, class_name)
+            Self::match_bool(boolean, class_name)
         } else {
             None
         }
     }
 
-    fn match_bool(&self, boolean: bool, class_name: &'static str) -> Option<String> {
+    fn match_bool(boolean: bool, class_name: &'static str) -> Option<String> {
         match boolean {
             true => Some(class_name.into()),
             false => None,
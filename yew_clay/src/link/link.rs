@@ -1,27 +1,16 @@
 use super::LinkContext;
-use gloo_events::EventListener;
-use std::collections::HashMap;
+#[cfg(feature = "router")]
+use super::LinkRoute;
+use crate::html_props::{with_html_props, AnchorProps};
+#[cfg(feature = "router")]
 use web_sys::Element;
+use web_sys::MouseEvent;
 use yew::{
-    classes, html, Callback, Children, Classes, Component, Context, Html, NodeRef, Properties,
+    classes, function_component, hook, html, use_context, Callback, Children, Classes, Html,
+    NodeRef, Properties,
 };
-use yew_dom_attributes::{anchor_props::AnchorProps, DomInjector};
-
-/// Yew implementation of ClayLink.
-/// The type parameter T: LinkContext indicates which link context applies for this link.
-/// (The link context allows customizing link tags throughout the app).
-/// If you don't need a context, set T to DefaultLinkContext.
-///
-/// <https://clayui.com/docs/components/link.html>
-///
-/// A note on the ClayLinkContext. ClayLinkContext allows you to pass arbitrary props to every
-/// single ClayLink. However, any props defined directly on the ClayLink take precedence and will
-/// override thos set on the ClayLinkContext.
-pub struct ClayLink {
-    /// This vec holds all the EventListeners defined for this component. They will be automatically
-    /// removed when the component is destroyed.
-    listeners: HashMap<String, EventListener>,
-}
+#[cfg(feature = "router")]
+use yew_router::hooks::use_navigator;
 
 impl ClayLink {
     const BTN: &'static str = "btn";
@@ -44,6 +33,11 @@ impl ClayLink {
     const LINK_OUTLINE_PRIMARY: &'static str = "link-outline-primary";
     const LINK_OUTLINE_SECONDARY: &'static str = "link-outline-secondary";
     const LINK_OUTLINE_UNSTYLED: &'static str = "link-outline-unstyled";
+    const NOREFERRER_NOOPENER: &'static str = "noreferrer noopener";
+    const REL: &'static str = "rel";
+    const TARGET: &'static str = "target";
+    #[cfg(feature = "router")]
+    const BLANK: &'static str = "_blank";
 
     fn get_btn_class(button: &LinkButton) -> Option<&'static str> {
         match button {
@@ -180,10 +174,74 @@ impl ClayLink {
     }
 }
 
+/// Returns the href and the click handler that pushes the route through the navigator of the
+/// router. Clicks with a modifier key, or on links that open in a new tab, keep the native
+/// behavior.
+#[cfg(feature = "router")]
+#[hook]
+fn use_route_attributes(props: &ClayLinkProps) -> (Option<String>, Option<Callback<MouseEvent>>) {
+    let navigator = use_navigator();
+
+    let route = match &props.route {
+        Some(route) => route.clone(),
+        None => return (None, None),
+    };
+    // Prefixed with the basename of the router like the Link of yew_router, so that the href
+    // matches the URL that the navigator pushes.
+    let href = match navigator
+        .as_ref()
+        .and_then(|navigator| navigator.basename())
+    {
+        Some(basename) => Some(format!("{}{}", basename, route.path())),
+        None => Some(route.path().to_string()),
+    };
+
+    let navigator = match navigator {
+        Some(navigator) => navigator,
+        None => return (href, None),
+    };
+
+    let node_ref = props.node_ref.clone();
+    let onclick = Callback::from(move |event: MouseEvent| {
+        let modified = event.ctrl_key()
+            || event.meta_key()
+            || event.shift_key()
+            || event.alt_key()
+            || event.button() != 0;
+        let blank = node_ref
+            .cast::<Element>()
+            .and_then(|element| element.get_attribute("target"))
+            .is_some_and(|target| target == ClayLink::BLANK);
+
+        if modified || blank || event.default_prevented() {
+            return;
+        }
+
+        event.prevent_default();
+        route.navigate(&navigator);
+    });
+
+    (href, Some(onclick))
+}
+
+#[cfg(not(feature = "router"))]
+#[hook]
+fn use_route_attributes(_props: &ClayLinkProps) -> (Option<String>, Option<Callback<MouseEvent>>) {
+    (None, None)
+}
+
 /// Props for ClayLink. For details, check the docs:
 /// <https://clayui.com/docs/components/link/api.html>
 #[derive(Debug, Properties, PartialEq, Clone, Default)]
 pub struct ClayLinkProps {
+    /// The aria-current attribute of the link, e.g. `"page"`.
+    #[prop_or_default]
+    pub aria_current: Option<String>,
+
+    /// The aria-label attribute of the link.
+    #[prop_or_default]
+    pub aria_label: Option<String>,
+
     /// Renders the button as a block element.
     #[prop_or_default]
     pub block: Option<bool>,
@@ -204,6 +262,15 @@ pub struct ClayLinkProps {
     #[prop_or(false)]
     pub monospaced: bool,
 
+    /// The URL that the link points to. A `route` takes precedence over it.
+    #[prop_or_default]
+    pub href: Option<String>,
+
+    /// Callback for when the link is clicked. It is called before a `route` is navigated to,
+    /// so it can prevent the navigation.
+    #[prop_or_default]
+    pub on_click: Option<Callback<MouseEvent>>,
+
     /// Flag to indicate if link need have an outline.
     #[prop_or(false)]
     pub outline: bool,
@@ -212,6 +279,12 @@ pub struct ClayLinkProps {
     #[prop_or_default]
     pub small: Option<bool>,
 
+    /// A typed route of the application. When set, the link navigates through the history
+    /// of the router instead of doing a full page load.
+    #[cfg(feature = "router")]
+    #[prop_or_default]
+    pub route: Option<LinkRoute>,
+
     #[prop_or_default]
     pub class: Classes,
 
@@ -226,106 +299,114 @@ pub struct ClayLinkProps {
     pub anchor_props: Option<AnchorProps>,
 }
 
-pub enum Msg {}
-
-impl Component for ClayLink {
-    type Message = Msg;
-    type Properties = ClayLinkProps;
-
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
-            listeners: HashMap::new(),
-        }
-    }
-
-    fn view(&self, ctx: &Context<Self>) -> Html {
-        let ClayLinkProps {
+/// Yew implementation of ClayLink.
+/// The type parameter T: LinkContext indicates which link context applies for this link.
+/// (The link context allows customizing link tags throughout the app).
+/// If you don't need a context, set T to DefaultLinkContext.
+///
+/// <https://clayui.com/docs/components/link.html>
+///
+/// A note on the ClayLinkContext. ClayLinkContext allows you to pass arbitrary props to every
+/// single ClayLink. However, any props defined directly on the ClayLink take precedence and will
+/// override thos set on the ClayLinkContext.
+#[function_component(ClayLink)]
+pub fn clay_link(props: &ClayLinkProps) -> Html {
+    let context = use_context::<LinkContext>();
+    let (route_href, route_onclick) = use_route_attributes(props);
+    let ClayLinkProps {
+        aria_current,
+        aria_label,
+        class,
+        children,
+        node_ref,
+        button,
+        block,
+        monospaced,
+        borderless,
+        href,
+        on_click,
+        small,
+        outline,
+        display_type,
+        ..
+    } = props.clone();
+    let href = route_href.or(href);
+    let onclick = match (on_click, route_onclick) {
+        (Some(on_click), Some(route_onclick)) => Some(Callback::from(move |event: MouseEvent| {
+            on_click.emit(event.clone());
+            route_onclick.emit(event);
+        })),
+        (on_click, route_onclick) => on_click.or(route_onclick),
+    };
+
+    let class = if let Some(button) = button {
+        let btn_class = ClayLink::get_btn_class(&button);
+        let btn_block_class = ClayLink::get_btn_block_class(&button, block);
+        let btn_monospaced_class = ClayLink::get_btn_monospaced_class(&button, monospaced);
+        let borderless_class = ClayLink::get_borderless_class(borderless);
+        let btn_small_class = ClayLink::get_btn_small_class(&button, small);
+        let display_class = ClayLink::get_btn_display_class(&display_type, outline, borderless);
+        let outline_display_class =
+            ClayLink::get_btn_outline_display_class(&display_type, outline, borderless);
+
+        classes!(
             class,
-            children,
-            node_ref,
-            button,
-            block,
-            monospaced,
-            borderless,
-            small,
-            outline,
-            display_type,
-            ..
-        } = ctx.props().clone();
-
-        let class = if let Some(button) = button {
-            let btn_class = Self::get_btn_class(&button);
-            let btn_block_class = Self::get_btn_block_class(&button, block);
-            let btn_monospaced_class = Self::get_btn_monospaced_class(&button, monospaced);
-            let borderless_class = Self::get_borderless_class(borderless);
-            let btn_small_class = Self::get_btn_small_class(&button, small);
-            let display_class = Self::get_btn_display_class(&display_type, outline, borderless);
-            let outline_display_class =
-                Self::get_btn_outline_display_class(&display_type, outline, borderless);
-
-            classes!(
-                class,
-                btn_class,
-                btn_block_class,
-                btn_monospaced_class,
-                borderless_class,
-                btn_small_class,
-                display_class,
-                outline_display_class
-            )
-        } else {
-            let link_monospaced_class = Self::get_bool_class(monospaced, Self::LINK_MONOSPACED);
-            let link_outline_class = Self::get_bool_class(outline, Self::LINK_OUTLINE);
-            let link_borderless = Self::get_bool_class(borderless, Self::LINK_BORDERLESS);
-            let link_display_class = Self::get_link_display_class(&display_type, outline);
-            let link_outline_display_class =
-                Self::get_link_outline_display_class(&display_type, outline);
-
-            classes!(
-                class,
-                link_monospaced_class,
-                link_outline_class,
-                link_borderless,
-                link_display_class,
-                link_outline_display_class
-            )
-        };
-
-        let tag = if let Some((context, _)) = ctx.link().context::<LinkContext>(Callback::noop()) {
-            context.tag
-        } else {
-            "a".into()
-        };
-
-        html! {
-            <@{tag} class={class} ref={node_ref}>{children}</@>
-        }
-    }
-
-    fn rendered(&mut self, ctx: &Context<Self>, _first_render: bool) {
-        if let Some((context, _)) = ctx.link().context::<LinkContext>(Callback::noop()) {
-            let context_props = context.props.clone();
-            let node_ref = &ctx.props().node_ref;
-            context_props.inject(node_ref, &mut self.listeners);
-        }
-
-        if let Some(custom_props) = &ctx.props().anchor_props {
-            let custom_props = custom_props.clone();
-            let node_ref = &ctx.props().node_ref;
-            custom_props.inject(node_ref, &mut self.listeners);
-        }
-
-        if let Some(elem) = &ctx.props().node_ref.cast::<Element>() {
-            let target = elem.has_attribute("target");
-            let rel = elem.has_attribute("rel");
-            if target && !rel {
-                match elem.set_attribute("rel", "noreferrer noopener") {
-                    Ok(_) => (),
-                    Err(err) => gloo_console::error!(err),
-                }
-            }
-        }
-    }
+            btn_class,
+            btn_block_class,
+            btn_monospaced_class,
+            borderless_class,
+            btn_small_class,
+            display_class,
+            outline_display_class
+        )
+    } else {
+        let link_monospaced_class = ClayLink::get_bool_class(monospaced, ClayLink::LINK_MONOSPACED);
+        let link_outline_class = ClayLink::get_bool_class(outline, ClayLink::LINK_OUTLINE);
+        let link_borderless = ClayLink::get_bool_class(borderless, ClayLink::LINK_BORDERLESS);
+        let link_display_class = ClayLink::get_link_display_class(&display_type, outline);
+        let link_outline_display_class =
+            ClayLink::get_link_outline_display_class(&display_type, outline);
+
+        classes!(
+            class,
+            link_monospaced_class,
+            link_outline_class,
+            link_borderless,
+            link_display_class,
+            link_outline_display_class
+        )
+    };
+
+    let context_props = context.as_ref().map(|context| &*context.props);
+    let anchor_props = props.anchor_props.as_ref();
+    let has_attribute = |key: &str| {
+        [context_props, anchor_props]
+            .into_iter()
+            .flatten()
+            .any(|props| props.has_attribute(key))
+    };
+    // Links that open in another browsing context shouldn't get access to this one.
+    let rel = (has_attribute(ClayLink::TARGET) && !has_attribute(ClayLink::REL))
+        .then_some(ClayLink::NOREFERRER_NOOPENER);
+
+    let tag = context
+        .as_ref()
+        .map_or_else(|| "a".into(), |context| context.tag.clone());
+
+    // The props of the link take precedence over the props of the context.
+    let link = html! {
+        <@{tag}
+            aria-current={aria_current}
+            aria-label={aria_label}
+            class={class}
+            href={href}
+            onclick={onclick}
+            ref={node_ref}
+            {rel}>
+            {children}
+        </@>
+    };
+    with_html_props(with_html_props(link, context_props), anchor_props)
 }
 
 #[derive(Debug, PartialEq, Clone)]
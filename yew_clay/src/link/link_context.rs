@@ -1,5 +1,5 @@
+use crate::html_props::AnchorProps;
 use std::rc::Rc;
-use yew_dom_attributes::anchor_props::AnchorProps;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct LinkContext {
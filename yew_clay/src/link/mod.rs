@@ -1,5 +1,9 @@
 mod link;
 mod link_context;
+#[cfg(feature = "router")]
+mod route;
 
 pub use link::*;
 pub use link_context::*;
+#[cfg(feature = "router")]
+pub use route::*;
@@ -0,0 +1,44 @@
+use std::fmt::Debug;
+use std::rc::Rc;
+use yew_router::navigator::Navigator;
+use yew_router::Routable;
+
+/// A typed route for ClayLink. The link renders the path of the route as its href, and a click
+/// pushes the route through the navigator of the router instead of loading a new page.
+#[derive(Clone)]
+pub struct LinkRoute {
+    path: String,
+    push: Rc<dyn Fn(&Navigator)>,
+}
+
+impl LinkRoute {
+    pub fn new<R: Routable + 'static>(route: R) -> Self {
+        Self {
+            path: route.to_path(),
+            push: Rc::new(move |navigator| navigator.push(&route)),
+        }
+    }
+
+    /// The path of the route, as rendered in the href of the link.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    pub fn navigate(&self, navigator: &Navigator) {
+        (self.push)(navigator)
+    }
+}
+
+impl PartialEq for LinkRoute {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+    }
+}
+
+impl Debug for LinkRoute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LinkRoute")
+            .field("path", &self.path)
+            .finish()
+    }
+}
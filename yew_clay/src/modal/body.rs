@@ -0,0 +1,55 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayModalBody. For details, check the docs:
+/// <https://clayui.com/docs/components/modal/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayModalBodyProps {
+    /// Flag indicating that the body should scroll when its content is taller than the
+    /// viewport, keeping the header and footer visible.
+    #[prop_or(false)]
+    pub scrollable: bool,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+}
+
+impl ClayModalBody {
+    const MODAL_BODY: &'static str = "modal-body";
+    const MODAL_BODY_SCROLLABLE: &'static str = "modal-body-scrollable";
+}
+
+/// A Yew implementation of ClayModal.Body.
+#[function_component(ClayModalBody)]
+pub fn clay_modal_body(props: &ClayModalBodyProps) -> Html {
+    let ClayModalBodyProps {
+        scrollable,
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let scrollable_class = scrollable.then_some(ClayModalBody::MODAL_BODY_SCROLLABLE);
+
+    with_html_props(
+        html! {
+            <div
+                class={classes!(class, ClayModalBody::MODAL_BODY, scrollable_class)}
+                ref={node_ref} >
+                {children}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
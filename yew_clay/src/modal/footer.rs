@@ -0,0 +1,67 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayModalFooter. For details, check the docs:
+/// <https://clayui.com/docs/components/modal/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayModalFooterProps {
+    /// Content rendered at the start of the footer.
+    #[prop_or_default]
+    pub first: Option<Html>,
+
+    /// Content rendered in the middle of the footer.
+    #[prop_or_default]
+    pub middle: Option<Html>,
+
+    /// Content rendered at the end of the footer, usually the buttons of the modal.
+    #[prop_or_default]
+    pub last: Option<Html>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+}
+
+impl ClayModalFooter {
+    const MODAL_FOOTER: &'static str = "modal-footer";
+    const MODAL_ITEM_FIRST: &'static str = "modal-item-first";
+    const MODAL_ITEM: &'static str = "modal-item";
+    const MODAL_ITEM_LAST: &'static str = "modal-item-last";
+
+    fn gen_item(content: Option<Html>, class: &'static str) -> Html {
+        match content {
+            Some(content) => html! { <div {class}>{content}</div> },
+            None => html! {},
+        }
+    }
+}
+
+/// A Yew implementation of ClayModal.Footer.
+#[function_component(ClayModalFooter)]
+pub fn clay_modal_footer(props: &ClayModalFooterProps) -> Html {
+    let ClayModalFooterProps {
+        first,
+        middle,
+        last,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    with_html_props(
+        html! {
+            <div class={classes!(class, ClayModalFooter::MODAL_FOOTER)} ref={node_ref}>
+                {ClayModalFooter::gen_item(first, ClayModalFooter::MODAL_ITEM_FIRST)}
+                {ClayModalFooter::gen_item(middle, ClayModalFooter::MODAL_ITEM)}
+                {ClayModalFooter::gen_item(last, ClayModalFooter::MODAL_ITEM_LAST)}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
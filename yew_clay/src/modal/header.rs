@@ -0,0 +1,87 @@
+use super::ModalContext;
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::icon::{ClayIcon, ClaySymbol};
+use yew::{
+    classes, function_component, html, use_context, Children, Classes, Html, NodeRef, Properties,
+};
+
+/// Props for ClayModalHeader. For details, check the docs:
+/// <https://clayui.com/docs/components/modal/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayModalHeaderProps {
+    /// The title of the modal. It is used as the accessible name of the dialog.
+    #[prop_or_default]
+    pub children: Children,
+
+    /// Flag to hide the button that closes the modal.
+    #[prop_or(false)]
+    pub hide_close_button: bool,
+
+    /// The aria-label of the close button.
+    #[prop_or("Close".into())]
+    pub close_aria_label: String,
+
+    /// Path to the spritemap that Icon should use when referencing symbols. Defaults to the
+    /// spritemap of the ClayModal.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+}
+
+impl ClayModalHeader {
+    const MODAL_HEADER: &'static str = "modal-header";
+    const MODAL_TITLE: &'static str = "modal-title";
+    const CLOSE: &'static str = "close";
+}
+
+/// A Yew implementation of ClayModal.Header. It renders the title of the modal along with a
+/// button that closes it.
+#[function_component(ClayModalHeader)]
+pub fn clay_modal_header(props: &ClayModalHeaderProps) -> Html {
+    let context = use_context::<ModalContext>().unwrap_or_default();
+    let ClayModalHeaderProps {
+        children,
+        hide_close_button,
+        close_aria_label,
+        spritemap,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let close_button = if hide_close_button {
+        html! {}
+    } else {
+        let spritemap = spritemap.or(context.spritemap);
+        html! {
+            <button
+                aria-label={close_aria_label}
+                class={ClayModalHeader::CLOSE}
+                onclick={context.close.reform(|_| ())}
+                type="button" >
+                <ClayIcon {spritemap} symbol={ClaySymbol::Times} />
+            </button>
+        }
+    };
+
+    with_html_props(
+        html! {
+            <div class={classes!(class, ClayModalHeader::MODAL_HEADER)} ref={node_ref}>
+                <div class={ClayModalHeader::MODAL_TITLE} id={context.title_id}>
+                    {children}
+                </div>
+                {close_button}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
@@ -0,0 +1,13 @@
+mod body;
+mod footer;
+mod header;
+mod modal;
+mod modal_context;
+mod state;
+
+pub use body::*;
+pub use footer::*;
+pub use header::*;
+pub use modal::*;
+pub use modal_context::*;
+pub use state::*;
@@ -0,0 +1,316 @@
+use super::ModalContext;
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::layout::Sizing;
+use crate::shared::focus_scope::{use_focus_trap, FocusScopeHandle};
+use crate::shared::keys::ESCAPE;
+use std::cell::Cell;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, KeyboardEvent, MouseEvent, Node};
+use yew::{
+    classes, create_portal, function_component, html, use_effect_with, use_memo, use_node_ref,
+    use_state_eq, Callback, Children, Classes, ContextProvider, Html, NodeRef, Properties,
+};
+
+thread_local! {
+    static NEXT_TITLE_ID: Cell<usize> = const { Cell::new(0) };
+    static OPEN_MODALS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Props for ClayModal. For details, check the docs:
+/// <https://clayui.com/docs/components/modal/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayModalProps {
+    /// Flag to indicate if the modal is open. The `use_modal` hook can be used to hold this
+    /// state.
+    #[prop_or(false)]
+    pub open: bool,
+
+    /// Callback for when the modal requests to be closed, either with the close button of the
+    /// header, the Escape key or a click outside of the dialog.
+    #[prop_or_default]
+    pub on_close: Option<Callback<()>>,
+
+    /// Flag that indicates whether to close the modal when clicking outside of the dialog.
+    #[prop_or(true)]
+    pub close_on_click_outside: bool,
+
+    /// The width of the dialog.
+    #[prop_or_default]
+    pub size: Option<Sizing>,
+
+    /// Flag to center the dialog vertically.
+    #[prop_or(false)]
+    pub center: bool,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayModal {
+    const MODAL: &'static str = "modal";
+    const MODAL_BACKDROP: &'static str = "modal-backdrop";
+    const MODAL_CONTENT: &'static str = "modal-content";
+    const MODAL_DIALOG: &'static str = "modal-dialog";
+    const MODAL_DIALOG_CENTERED: &'static str = "modal-dialog-centered";
+    const MODAL_OPEN: &'static str = "modal-open";
+    const MODAL_SIZE: &'static str = "modal-";
+    const D_BLOCK: &'static str = "d-block";
+    const SHOW: &'static str = "show";
+    const TITLE_ID: &'static str = "clay-modal-title-";
+
+    fn next_title_id() -> String {
+        let id = NEXT_TITLE_ID.with(|next_id| {
+            let id = next_id.get();
+            next_id.set(id + 1);
+            id
+        });
+        format!("{}{}", Self::TITLE_ID, id)
+    }
+
+    fn get_size_class(size: &Option<Sizing>) -> Option<String> {
+        size.as_ref().map(|size| {
+            let size = size.as_ref();
+            let mut class = String::with_capacity(Self::MODAL_SIZE.len() + size.len());
+            class.push_str(Self::MODAL_SIZE);
+            class.push_str(size);
+            class
+        })
+    }
+
+    /// Checks whether the target is one of the elements that surround the content of the
+    /// dialog. Clicks on the content of nested modals never match, since they are rendered in
+    /// their own portal.
+    fn is_outside_content(node_refs: &[&NodeRef], target: Option<Node>) -> bool {
+        match target {
+            Some(target) => node_refs.iter().any(|node_ref| {
+                node_ref
+                    .cast::<Node>()
+                    .is_some_and(|node| node.is_same_node(Some(&target)))
+            }),
+            None => false,
+        }
+    }
+
+    fn get_event_target(event: &web_sys::Event) -> Option<Node> {
+        event
+            .target()
+            .and_then(|target| target.dyn_into::<Node>().ok())
+    }
+
+    fn get_active_element() -> Option<HtmlElement> {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.active_element())
+            .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+    }
+
+    fn focus(element: &HtmlElement) {
+        if let Err(err) = element.focus() {
+            gloo_console::error!(err);
+        }
+    }
+
+    /// Adds the modal-open class to the body, which prevents it from scrolling. The class is
+    /// only removed once every open modal has been closed.
+    fn lock_scroll() {
+        let open_modals = OPEN_MODALS.with(|open_modals| {
+            open_modals.set(open_modals.get() + 1);
+            open_modals.get()
+        });
+
+        if open_modals == 1 {
+            Self::toggle_body_class(true);
+        }
+    }
+
+    fn unlock_scroll() {
+        let open_modals = OPEN_MODALS.with(|open_modals| {
+            open_modals.set(open_modals.get().saturating_sub(1));
+            open_modals.get()
+        });
+
+        if open_modals == 0 {
+            Self::toggle_body_class(false);
+        }
+    }
+
+    fn toggle_body_class(force: bool) {
+        let body = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.body());
+
+        if let Some(body) = body {
+            if let Err(err) = body.class_list().toggle_with_force(Self::MODAL_OPEN, force) {
+                gloo_console::error!(err);
+            }
+        }
+    }
+}
+
+/// A Yew implementation of ClayModal. For more info about ClayModal, check the documentation:
+/// <https://clayui.com/docs/components/modal.html>
+///
+/// The modal is rendered through a portal at the end of the document body. While it is open,
+/// the focus is trapped inside of the dialog and the body does not scroll. When it closes, the
+/// focus returns to the element that had it when the modal was opened.
+#[function_component(ClayModal)]
+pub fn clay_modal(props: &ClayModalProps) -> Html {
+    let dialog_ref = use_node_ref();
+    let content_ref = use_node_ref();
+    let title_id = use_memo((), |_| ClayModal::next_title_id());
+    // The portal is only created once the component is mounted, so nothing is rendered on the
+    // server.
+    let mounted = use_state_eq(|| false);
+    let FocusScopeHandle {
+        focus_manager,
+        onkeydown: trap_focus,
+    } = use_focus_trap(&content_ref);
+
+    {
+        let mounted = mounted.clone();
+        use_effect_with((), move |_| {
+            mounted.set(true);
+            || ()
+        });
+    }
+
+    {
+        let node_ref = props.node_ref.clone();
+        use_effect_with((props.open, *mounted), move |(open, mounted)| {
+            let opener = if *open && *mounted {
+                let opener = ClayModal::get_active_element();
+                ClayModal::lock_scroll();
+                if focus_manager.focus_first().is_none() {
+                    if let Some(modal) = node_ref.cast::<HtmlElement>() {
+                        ClayModal::focus(&modal);
+                    }
+                }
+                Some(opener)
+            } else {
+                None
+            };
+
+            move || {
+                if let Some(opener) = opener {
+                    ClayModal::unlock_scroll();
+                    if let Some(opener) = opener {
+                        ClayModal::focus(&opener);
+                    }
+                }
+            }
+        });
+    }
+
+    let ClayModalProps {
+        open,
+        on_close,
+        close_on_click_outside,
+        size,
+        center,
+        spritemap,
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let body = if open && *mounted {
+        web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.body())
+    } else {
+        None
+    };
+
+    let body = match body {
+        Some(body) => body,
+        None => return html! {},
+    };
+
+    let close = on_close.unwrap_or_default();
+
+    let onkeydown = {
+        let close = close.clone();
+        Callback::from(move |event: KeyboardEvent| {
+            // Key downs of nested modals bubble up to this one, so the ones that were already
+            // handled are ignored.
+            if event.key() != ESCAPE {
+                trap_focus.emit(event);
+            } else if !event.default_prevented() {
+                event.prevent_default();
+                close.emit(());
+            }
+        })
+    };
+
+    let onmousedown = {
+        let close = close.clone();
+        let node_ref = node_ref.clone();
+        let dialog_ref = dialog_ref.clone();
+        Callback::from(move |event: MouseEvent| {
+            let target = ClayModal::get_event_target(&event);
+            if close_on_click_outside
+                && ClayModal::is_outside_content(&[&node_ref, &dialog_ref], target)
+            {
+                close.emit(());
+            }
+        })
+    };
+
+    let context = ModalContext {
+        close,
+        title_id: (*title_id).clone(),
+        spritemap,
+    };
+
+    let size_class = ClayModal::get_size_class(&size);
+    let center_class = center.then_some(ClayModal::MODAL_DIALOG_CENTERED);
+
+    let modal = with_html_props(
+        html! {
+            <div
+                class={classes!(class, ClayModal::MODAL, ClayModal::D_BLOCK, ClayModal::SHOW)}
+                {onkeydown}
+                {onmousedown}
+                ref={node_ref}
+                tabindex="-1" >
+                <div
+                    aria-labelledby={(*title_id).clone()}
+                    aria-modal="true"
+                    class={classes!(ClayModal::MODAL_DIALOG, size_class, center_class)}
+                    ref={dialog_ref}
+                    role="dialog" >
+                    <div class={ClayModal::MODAL_CONTENT} ref={content_ref}>
+                        <ContextProvider<ModalContext> {context}>
+                            {children}
+                        </ContextProvider<ModalContext>>
+                    </div>
+                </div>
+            </div>
+        },
+        props.html_props.as_ref(),
+    );
+
+    let modal = html! {
+        <>
+            <div class={classes!(ClayModal::MODAL_BACKDROP, ClayModal::SHOW)} />
+            {modal}
+        </>
+    };
+
+    create_portal(modal, body.into())
+}
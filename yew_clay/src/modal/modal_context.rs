@@ -0,0 +1,10 @@
+use yew::Callback;
+
+/// Context shared by ClayModal with its sections. It allows the header to close the modal and
+/// to label the dialog with its title.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ModalContext {
+    pub close: Callback<()>,
+    pub title_id: String,
+    pub spritemap: Option<&'static str>,
+}
@@ -0,0 +1,31 @@
+use yew::{hook, use_state_eq, Callback};
+
+/// The open state of a ClayModal, as returned by `use_modal`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModalState {
+    /// Whether the modal is currently open.
+    pub open: bool,
+
+    /// Opens or closes the modal.
+    pub set_open: Callback<bool>,
+
+    /// Closes the modal. Meant to be passed as the `on_close` of ClayModal.
+    pub on_close: Callback<()>,
+}
+
+/// Hook holding the open state of a ClayModal. The modal starts as `default_open`.
+#[hook]
+pub fn use_modal(default_open: bool) -> ModalState {
+    let open = use_state_eq(|| default_open);
+
+    let set_open = {
+        let open = open.clone();
+        Callback::from(move |next: bool| open.set(next))
+    };
+
+    ModalState {
+        open: *open,
+        on_close: set_open.reform(|_| false),
+        set_open,
+    }
+}
@@ -0,0 +1,112 @@
+use super::PaginationHref;
+use crate::dropdown::{ClayDropDown, ClayDropDownItem, ClayDropDownItemList, OtherProps};
+use crate::html_props::GlobalProps;
+use crate::icon::{ClayIcon, ClaySymbol};
+use web_sys::MouseEvent;
+use yew::{classes, function_component, html, Callback, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayPaginationEllipsis.
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayPaginationEllipsisProps {
+    /// The pages that were collapsed.
+    #[prop_or_default]
+    pub pages: Vec<usize>,
+
+    /// The pages that can not be selected.
+    #[prop_or_default]
+    pub disabled_pages: Vec<usize>,
+
+    /// Builds the href of each page.
+    #[prop_or_default]
+    pub href_constructor: Option<PaginationHref>,
+
+    /// Callback for when one of the pages is selected.
+    #[prop_or_default]
+    pub on_page_change: Option<Callback<usize>>,
+
+    /// The aria-label of the button that opens the list of pages.
+    #[prop_or_default]
+    pub aria_label: Option<String>,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayPaginationEllipsis {
+    const PAGE_ITEM: &'static str = "page-item";
+    const PAGE_LINK: &'static str = "page-link";
+    const ELLIPSIS_SYMBOL: ClaySymbol = ClaySymbol::EllipsisH;
+}
+
+/// The collapsed pages of a ClayPagination. It renders a DropDown that lists the hidden pages.
+#[function_component(ClayPaginationEllipsis)]
+pub fn clay_pagination_ellipsis(props: &ClayPaginationEllipsisProps) -> Html {
+    let ClayPaginationEllipsisProps {
+        pages,
+        disabled_pages,
+        href_constructor,
+        on_page_change,
+        aria_label,
+        spritemap,
+        class,
+        node_ref,
+        html_props,
+    } = props.clone();
+
+    let trigger = html! {
+        <button
+            aria-label={aria_label}
+            class={ClayPaginationEllipsis::PAGE_LINK}
+            type="button" >
+            <ClayIcon {spritemap} symbol={ClayPaginationEllipsis::ELLIPSIS_SYMBOL} />
+        </button>
+    };
+
+    let items = pages
+        .into_iter()
+        .map(|page| {
+            let href = href_constructor
+                .as_ref()
+                .map(|href_constructor| href_constructor.get(page));
+            let on_click = on_page_change
+                .as_ref()
+                .map(|on_page_change| on_page_change.reform(move |_: MouseEvent| page));
+
+            html! {
+                <ClayDropDownItem
+                    key={page}
+                    disabled={disabled_pages.contains(&page)}
+                    {href}
+                    {on_click}
+                    {spritemap} >
+                    {page}
+                </ClayDropDownItem>
+            }
+        })
+        .collect::<Html>();
+
+    html! {
+        <ClayDropDown
+            class={classes!(class, ClayPaginationEllipsis::PAGE_ITEM)}
+            close_on_click={true}
+            container_element="li"
+            {node_ref}
+            other_props={html_props.map(OtherProps::DivProps)}
+            {trigger} >
+            <ClayDropDownItemList>
+                {items}
+            </ClayDropDownItemList>
+        </ClayDropDown>
+    }
+}
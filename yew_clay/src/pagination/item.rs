@@ -0,0 +1,120 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::link::ClayLink;
+use web_sys::MouseEvent;
+use yew::{
+    classes, function_component, html, use_node_ref, Callback, Children, Classes, Html, NodeRef,
+    Properties,
+};
+
+/// Props for ClayPaginationItem. For details, check the docs:
+/// <https://clayui.com/docs/components/pagination/api.html>
+#[derive(Debug, Properties, PartialEq, Clone, Default)]
+pub struct ClayPaginationItemProps {
+    /// Flag to indicate if the item is the current page.
+    #[prop_or(false)]
+    pub active: bool,
+
+    /// Flag to indicate if the item is disabled.
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    /// Path for the item to link to. When set, the item is rendered as a ClayLink.
+    #[prop_or_default]
+    pub href: Option<String>,
+
+    /// Callback for when the item is clicked.
+    #[prop_or_default]
+    pub on_click: Option<Callback<MouseEvent>>,
+
+    /// The aria-label of the link or button.
+    #[prop_or_default]
+    pub aria_label: Option<String>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    /// NodeRef for the link or button of the item.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the list item.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayPaginationItem {
+    const ACTIVE: &'static str = "active";
+    const DISABLED: &'static str = "disabled";
+    const PAGE: &'static str = "page";
+    const PAGE_ITEM: &'static str = "page-item";
+    const PAGE_LINK: &'static str = "page-link";
+}
+
+/// A Yew implementation of ClayPagination.Item. Items with an href are rendered as a ClayLink,
+/// the rest as a button.
+#[function_component(ClayPaginationItem)]
+pub fn clay_pagination_item(props: &ClayPaginationItemProps) -> Html {
+    let li_ref = use_node_ref();
+
+    let ClayPaginationItemProps {
+        active,
+        disabled,
+        href,
+        on_click,
+        aria_label,
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let aria_current = active.then_some(ClayPaginationItem::PAGE);
+
+    let item = match href {
+        Some(href) if !disabled => {
+            let aria_current = aria_current.map(String::from);
+            html! {
+                <ClayLink
+                    {aria_current}
+                    {aria_label}
+                    class={ClayPaginationItem::PAGE_LINK}
+                    href={Some(href)}
+                    {node_ref}
+                    {on_click} >
+                    {children}
+                </ClayLink>
+            }
+        }
+        _ => html! {
+            <button
+                aria-current={aria_current}
+                aria-label={aria_label}
+                class={ClayPaginationItem::PAGE_LINK}
+                {disabled}
+                onclick={on_click}
+                ref={node_ref}
+                type="button" >
+                {children}
+            </button>
+        },
+    };
+
+    with_html_props(
+        html! {
+            <li
+                class={classes!(
+                    class,
+                    ClayPaginationItem::PAGE_ITEM,
+                    active.then_some(ClayPaginationItem::ACTIVE),
+                    disabled.then_some(ClayPaginationItem::DISABLED)
+                )}
+                ref={li_ref} >
+                {item}
+            </li>
+        },
+        props.html_props.as_ref(),
+    )
+}
@@ -0,0 +1,11 @@
+mod ellipsis;
+mod item;
+mod pagination;
+mod pagination_bar;
+mod types;
+
+pub use ellipsis::*;
+pub use item::*;
+pub use pagination::*;
+pub use pagination_bar::*;
+pub use types::*;
@@ -0,0 +1,202 @@
+use super::{
+    get_pagination_slots, ClayPaginationEllipsis, ClayPaginationItem, ClayPaginationItemProps,
+    PaginationAriaLabels, PaginationHref, PaginationSlot,
+};
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::icon::{ClayIcon, ClaySymbol};
+use crate::layout::Sizing;
+use yew::{
+    classes, function_component, html, Callback, Children, Classes, Html, NodeRef, Properties,
+};
+
+/// Props for ClayPagination. For details, check the docs:
+/// <https://clayui.com/docs/components/pagination/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayPaginationProps {
+    /// The page that is displayed, starting at 1.
+    #[prop_or(1)]
+    pub active_page: usize,
+
+    /// Callback for when a different page is selected.
+    #[prop_or_default]
+    pub on_page_change: Option<Callback<usize>>,
+
+    /// The total amount of pages.
+    pub total_pages: usize,
+
+    /// The pages that can not be selected.
+    #[prop_or_default]
+    pub disabled_pages: Vec<usize>,
+
+    /// The amount of pages displayed on each side of the active page. The pages further away
+    /// are collapsed into an ellipsis, except for the first and the last one.
+    #[prop_or(2)]
+    pub ellipsis_buffer: usize,
+
+    /// Builds the href of each page. When set, the pages are rendered as links.
+    #[prop_or_default]
+    pub href_constructor: Option<PaginationHref>,
+
+    /// Labels for the aria attributes of the pagination.
+    #[prop_or_default]
+    pub aria_labels: PaginationAriaLabels,
+
+    /// The size of the pagination. Only `Sizing::Small` and `Sizing::Large` have styles.
+    #[prop_or_default]
+    pub size: Option<Sizing>,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayPagination {
+    const PAGINATION: &'static str = "pagination";
+    const PAGINATION_SIZE: &'static str = "pagination-";
+    const PREVIOUS_SYMBOL: ClaySymbol = ClaySymbol::AngleLeft;
+    const NEXT_SYMBOL: ClaySymbol = ClaySymbol::AngleRight;
+
+    fn get_size_class(size: &Option<Sizing>) -> Option<String> {
+        size.as_ref().map(|size| {
+            let size = size.as_ref();
+            let mut class = String::with_capacity(Self::PAGINATION_SIZE.len() + size.len());
+            class.push_str(Self::PAGINATION_SIZE);
+            class.push_str(size);
+            class
+        })
+    }
+
+    fn get_item_props(
+        page: usize,
+        props: &ClayPaginationProps,
+        aria_label: &str,
+        content: Html,
+        disabled: bool,
+    ) -> ClayPaginationItemProps {
+        // Disabled items lead nowhere, and a disabled previous or next item would point to a
+        // page that doesn't exist.
+        let (href, on_click, aria_label) = if disabled {
+            (None, None, None)
+        } else {
+            let href = props
+                .href_constructor
+                .as_ref()
+                .map(|href_constructor| href_constructor.get(page));
+            let on_click = props
+                .on_page_change
+                .as_ref()
+                .map(|on_page_change| on_page_change.reform(move |_| page));
+            let aria_label = PaginationAriaLabels::format(aria_label, page);
+            (href, on_click, Some(aria_label))
+        };
+
+        ClayPaginationItemProps {
+            active: page == props.active_page,
+            aria_label,
+            disabled,
+            href,
+            on_click,
+            children: Children::new(vec![content]),
+            ..ClayPaginationItemProps::default()
+        }
+    }
+}
+
+/// A Yew implementation of ClayPagination. For more info about ClayPagination, check the
+/// documentation: <https://clayui.com/docs/components/pagination.html>
+///
+/// The active page is controlled through `active_page` and `on_page_change`, so it can be kept
+/// in sync with the items displayed in a table.
+#[function_component(ClayPagination)]
+pub fn clay_pagination(props: &ClayPaginationProps) -> Html {
+    let ClayPaginationProps {
+        active_page,
+        total_pages,
+        disabled_pages,
+        ellipsis_buffer,
+        href_constructor,
+        on_page_change,
+        aria_labels,
+        size,
+        spritemap,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let previous_page = active_page.saturating_sub(1);
+    let previous = ClayPagination::get_item_props(
+        previous_page,
+        props,
+        aria_labels.previous,
+        html! { <ClayIcon {spritemap} symbol={ClayPagination::PREVIOUS_SYMBOL} /> },
+        previous_page < 1,
+    );
+
+    let next_page = active_page + 1;
+    let next = ClayPagination::get_item_props(
+        next_page,
+        props,
+        aria_labels.next,
+        html! { <ClayIcon {spritemap} symbol={ClayPagination::NEXT_SYMBOL} /> },
+        next_page > total_pages,
+    );
+
+    let pages = get_pagination_slots(active_page, total_pages, ellipsis_buffer)
+        .into_iter()
+        .map(|slot| match slot {
+            PaginationSlot::Page(page) => {
+                let item_props = ClayPagination::get_item_props(
+                    page,
+                    props,
+                    aria_labels.page,
+                    html! { {page} },
+                    disabled_pages.contains(&page),
+                );
+                html! {
+                    <ClayPaginationItem key={page} ..item_props />
+                }
+            }
+            PaginationSlot::Ellipsis(pages) => {
+                let key = format!("ellipsis-{}", pages[0]);
+                html! {
+                    <ClayPaginationEllipsis
+                        {key}
+                        aria_label={aria_labels.select.to_string()}
+                        disabled_pages={disabled_pages.clone()}
+                        href_constructor={href_constructor.clone()}
+                        on_page_change={on_page_change.clone()}
+                        {pages}
+                        {spritemap} />
+                }
+            }
+        })
+        .collect::<Html>();
+
+    with_html_props(
+        html! {
+            <ul
+                class={classes!(
+                    class,
+                    ClayPagination::PAGINATION,
+                    ClayPagination::get_size_class(&size)
+                )}
+                ref={node_ref} >
+                <ClayPaginationItem ..previous />
+                {pages}
+                <ClayPaginationItem ..next />
+            </ul>
+        },
+        props.html_props.as_ref(),
+    )
+}
@@ -0,0 +1,256 @@
+use super::{ClayPagination, PaginationAriaLabels, PaginationBarLabels, PaginationHref};
+use crate::dropdown::{ClayDropDown, ClayDropDownItem, ClayDropDownItemList};
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::icon::{ClayIcon, ClaySymbol};
+use crate::layout::Sizing;
+use web_sys::MouseEvent;
+use yew::{
+    classes, function_component, html, use_effect_with, use_state_eq, Callback, Classes, Html,
+    NodeRef, Properties,
+};
+
+/// Props for ClayPaginationBar. For details, check the docs:
+/// <https://clayui.com/docs/components/pagination-bar/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayPaginationBarProps {
+    /// The page that is displayed, starting at 1.
+    #[prop_or(1)]
+    pub active_page: usize,
+
+    /// Callback for when a different page is selected. It is also called with the first page
+    /// when the amount of items per page changes, and with the last page when `total_items`
+    /// shrinks below the active page.
+    #[prop_or_default]
+    pub on_page_change: Option<Callback<usize>>,
+
+    /// The total amount of items, across all of the pages.
+    pub total_items: usize,
+
+    /// The amount of items per page (controlled).
+    #[prop_or_default]
+    pub delta: Option<usize>,
+
+    /// Property to set the initial amount of items per page (uncontrolled).
+    #[prop_or(20)]
+    pub default_delta: usize,
+
+    /// Callback for when a different amount of items per page is selected.
+    #[prop_or_default]
+    pub on_delta_change: Option<Callback<usize>>,
+
+    /// The amounts of items per page that can be selected in the dropdown.
+    #[prop_or(vec![10, 20, 30, 50])]
+    pub deltas: Vec<usize>,
+
+    /// Flag to render the dropdown that selects the amount of items per page.
+    #[prop_or(true)]
+    pub show_delta_dropdown: bool,
+
+    /// The pages that can not be selected.
+    #[prop_or_default]
+    pub disabled_pages: Vec<usize>,
+
+    /// The amount of pages displayed on each side of the active page.
+    #[prop_or(2)]
+    pub ellipsis_buffer: usize,
+
+    /// Builds the href of each page. When set, the pages are rendered as links.
+    #[prop_or_default]
+    pub href_constructor: Option<PaginationHref>,
+
+    /// Labels for the aria attributes of the pagination.
+    #[prop_or_default]
+    pub aria_labels: PaginationAriaLabels,
+
+    /// Labels for the results and the amount of items per page.
+    #[prop_or_default]
+    pub labels: PaginationBarLabels,
+
+    /// The size of the pagination bar. Only `Sizing::Small` and `Sizing::Large` have styles.
+    #[prop_or_default]
+    pub size: Option<Sizing>,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayPaginationBar {
+    const BTN_UNSTYLED: &'static str = "btn btn-unstyled";
+    const PAGINATION_BAR: &'static str = "pagination-bar";
+    const PAGINATION_BAR_SIZE: &'static str = "pagination-bar-";
+    const PAGINATION_ITEMS_PER_PAGE: &'static str = "pagination-items-per-page";
+    const PAGINATION_RESULTS: &'static str = "pagination-results";
+    const DROPDOWN_SYMBOL: ClaySymbol = ClaySymbol::CaretDoubleL;
+
+    fn get_size_class(size: &Option<Sizing>) -> Option<String> {
+        size.as_ref().map(|size| {
+            let size = size.as_ref();
+            let mut class = String::with_capacity(Self::PAGINATION_BAR_SIZE.len() + size.len());
+            class.push_str(Self::PAGINATION_BAR_SIZE);
+            class.push_str(size);
+            class
+        })
+    }
+
+    fn get_total_pages(total_items: usize, delta: usize) -> usize {
+        match delta {
+            0 => 0,
+            _ => total_items.div_ceil(delta),
+        }
+    }
+
+    /// The position of the first and the last item of the page, starting at 1.
+    fn get_results(active_page: usize, delta: usize, total_items: usize) -> (usize, usize) {
+        let start = active_page.saturating_sub(1) * delta;
+        if start >= total_items {
+            return (0, 0);
+        }
+        (start + 1, (start + delta).min(total_items))
+    }
+}
+
+/// A Yew implementation of ClayPaginationBar. For more info about ClayPaginationBar, check the
+/// documentation: <https://clayui.com/docs/components/pagination-bar.html>
+///
+/// It combines a dropdown to select the amount of items per page, the results of the active
+/// page and a ClayPagination with the pages needed for `total_items`.
+#[function_component(ClayPaginationBar)]
+pub fn clay_pagination_bar(props: &ClayPaginationBarProps) -> Html {
+    let internal_delta = use_state_eq(|| props.default_delta);
+    let delta = props.delta.unwrap_or(*internal_delta);
+    let total_pages = ClayPaginationBar::get_total_pages(props.total_items, delta);
+
+    {
+        // Keeps the active page inside of the pages that exist, for example after the last
+        // items are removed.
+        let on_page_change = props.on_page_change.clone();
+        use_effect_with(
+            (props.active_page, total_pages),
+            move |(active_page, total_pages)| {
+                if *total_pages > 0 && active_page > total_pages {
+                    if let Some(on_page_change) = on_page_change {
+                        on_page_change.emit(*total_pages);
+                    }
+                }
+                || ()
+            },
+        );
+    }
+
+    let set_delta = {
+        let controlled = props.delta.is_some();
+        let on_delta_change = props.on_delta_change.clone();
+        let on_page_change = props.on_page_change.clone();
+        Callback::from(move |delta: usize| {
+            if let Some(on_delta_change) = &on_delta_change {
+                on_delta_change.emit(delta);
+            }
+
+            if !controlled {
+                internal_delta.set(delta);
+            }
+
+            if let Some(on_page_change) = &on_page_change {
+                on_page_change.emit(1);
+            }
+        })
+    };
+
+    let ClayPaginationBarProps {
+        active_page,
+        on_page_change,
+        total_items,
+        deltas,
+        show_delta_dropdown,
+        disabled_pages,
+        ellipsis_buffer,
+        href_constructor,
+        aria_labels,
+        labels,
+        size,
+        spritemap,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let delta_dropdown = if show_delta_dropdown {
+        let trigger = html! {
+            <button class={ClayPaginationBar::BTN_UNSTYLED} type="button">
+                {PaginationBarLabels::format(labels.per_page_items, &[delta])}
+                <ClayIcon {spritemap} symbol={ClayPaginationBar::DROPDOWN_SYMBOL} />
+            </button>
+        };
+
+        let items = deltas
+            .into_iter()
+            .map(|item_delta| {
+                html! {
+                    <ClayDropDownItem
+                        key={item_delta}
+                        active={item_delta == delta}
+                        on_click={set_delta.reform(move |_: MouseEvent| item_delta)}
+                        {spritemap} >
+                        {PaginationBarLabels::format(labels.select_per_page_items, &[item_delta])}
+                    </ClayDropDownItem>
+                }
+            })
+            .collect::<Html>();
+
+        html! {
+            <ClayDropDown
+                class={ClayPaginationBar::PAGINATION_ITEMS_PER_PAGE}
+                close_on_click={true}
+                {trigger} >
+                <ClayDropDownItemList>
+                    {items}
+                </ClayDropDownItemList>
+            </ClayDropDown>
+        }
+    } else {
+        html! {}
+    };
+
+    let active_page = active_page.clamp(1, total_pages.max(1));
+    let (start, end) = ClayPaginationBar::get_results(active_page, delta, total_items);
+    let size_class = ClayPaginationBar::get_size_class(&size);
+
+    with_html_props(
+        html! {
+            <div
+                class={classes!(
+                    class,
+                    ClayPaginationBar::PAGINATION_BAR,
+                    size_class
+                )}
+                ref={node_ref} >
+                {delta_dropdown}
+                <div class={ClayPaginationBar::PAGINATION_RESULTS}>
+                    {PaginationBarLabels::format(labels.results, &[start, end, total_items])}
+                </div>
+                <ClayPagination
+                    {active_page}
+                    {aria_labels}
+                    {disabled_pages}
+                    {ellipsis_buffer}
+                    {href_constructor}
+                    {on_page_change}
+                    {size}
+                    {spritemap}
+                    {total_pages} />
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
@@ -0,0 +1,126 @@
+use std::fmt::Debug;
+use std::rc::Rc;
+
+/// Builds the href of the link to a page, so the pages can be navigated without JavaScript.
+#[derive(Clone)]
+pub struct PaginationHref(Rc<dyn Fn(usize) -> String>);
+
+impl PaginationHref {
+    pub fn new<F: Fn(usize) -> String + 'static>(href_constructor: F) -> Self {
+        Self(Rc::new(href_constructor))
+    }
+
+    pub fn get(&self, page: usize) -> String {
+        (self.0)(page)
+    }
+}
+
+impl PartialEq for PaginationHref {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::as_ptr(&self.0) as *const u8 == Rc::as_ptr(&other.0) as *const u8
+    }
+}
+
+impl Debug for PaginationHref {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PaginationHref")
+    }
+}
+
+/// Labels for the aria attributes of the pagination. In `page`, `{0}` is replaced by the
+/// number of the page.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PaginationAriaLabels {
+    pub next: &'static str,
+    pub page: &'static str,
+    pub previous: &'static str,
+    pub select: &'static str,
+}
+
+impl Default for PaginationAriaLabels {
+    fn default() -> Self {
+        Self {
+            next: "Go to the next page, {0}",
+            page: "Go to page, {0}",
+            previous: "Go to the previous page, {0}",
+            select: "Select a page",
+        }
+    }
+}
+
+impl PaginationAriaLabels {
+    pub(crate) fn format(label: &str, page: usize) -> String {
+        label.replace("{0}", &page.to_string())
+    }
+}
+
+/// One of the elements of the list of pages.
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum PaginationSlot {
+    Page(usize),
+    /// The pages that were collapsed into an ellipsis.
+    Ellipsis(Vec<usize>),
+}
+
+/// Returns the pages to display, from 1 to `total_pages`. The first and last pages, and
+/// `ellipsis_buffer` pages on each side of the active one, are always displayed. The rest are
+/// collapsed into ellipses, unless a single page would be hidden.
+pub(crate) fn get_pagination_slots(
+    active_page: usize,
+    total_pages: usize,
+    ellipsis_buffer: usize,
+) -> Vec<PaginationSlot> {
+    let is_visible = |page: usize| {
+        page == 1 || page == total_pages || active_page.abs_diff(page) <= ellipsis_buffer
+    };
+
+    let mut slots = Vec::new();
+    let mut hidden = Vec::new();
+    for page in 1..=total_pages {
+        if !is_visible(page) {
+            hidden.push(page);
+            continue;
+        }
+
+        match hidden.len() {
+            0 => (),
+            1 => slots.push(PaginationSlot::Page(hidden[0])),
+            _ => slots.push(PaginationSlot::Ellipsis(hidden.clone())),
+        }
+        hidden.clear();
+        slots.push(PaginationSlot::Page(page));
+    }
+
+    slots
+}
+
+/// Labels of the ClayPaginationBar. In `results`, `{0}` and `{1}` are replaced by the first and
+/// the last item of the page, and `{2}` by the total amount of items. In the others, `{0}` is
+/// replaced by the amount of items per page.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PaginationBarLabels {
+    pub per_page_items: &'static str,
+    pub results: &'static str,
+    pub select_per_page_items: &'static str,
+}
+
+impl Default for PaginationBarLabels {
+    fn default() -> Self {
+        Self {
+            per_page_items: "{0} items",
+            results: "Showing {0} to {1} of {2}",
+            select_per_page_items: "{0} items",
+        }
+    }
+}
+
+impl PaginationBarLabels {
+    pub(crate) fn format(label: &str, values: &[usize]) -> String {
+        values
+            .iter()
+            .enumerate()
+            .fold(label.to_string(), |label, (index, value)| {
+                label.replace(&format!("{{{}}}", index), &value.to_string())
+            })
+    }
+}
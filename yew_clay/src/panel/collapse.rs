@@ -0,0 +1,98 @@
+use gloo_events::EventListener;
+use gloo_timers::callback::Timeout;
+use std::rc::Rc;
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{EventTarget, HtmlElement};
+use yew::{hook, use_effect_with, use_mut_ref, NodeRef};
+
+const COLLAPSE: &str = "collapse";
+const COLLAPSING: &str = "collapsing";
+const SHOW: &str = "show";
+const HEIGHT: &str = "height";
+/// The duration of the height transition of Clay, after which the transition is finished even
+/// if no transitionend event was fired (for example with reduced motion).
+const TRANSITION_DURATION: u32 = 400;
+
+/// The listeners of a running transition. Dropping it cancels them.
+struct CollapseTransition {
+    _listener: EventListener,
+    _timeout: Timeout,
+}
+
+/// Hook that animates the height of the element of `node_ref` when `expanded` changes.
+///
+/// The element is rendered with the `collapse` class, plus `show` when it is expanded. While
+/// the height transitions, those classes are replaced by `collapsing`, like the collapse
+/// plugin of Bootstrap does.
+#[hook]
+pub(crate) fn use_collapse_transition(node_ref: &NodeRef, expanded: bool) {
+    let first_render = use_mut_ref(|| true);
+    let node_ref = node_ref.clone();
+    use_effect_with(expanded, move |expanded| {
+        // The initial state is rendered as is, without a transition.
+        let transition = if std::mem::take(&mut *first_render.borrow_mut()) {
+            None
+        } else {
+            node_ref
+                .cast::<HtmlElement>()
+                .map(|element| start_transition(element, *expanded))
+        };
+        move || drop(transition)
+    });
+}
+
+fn start_transition(element: HtmlElement, expanding: bool) -> CollapseTransition {
+    let class_list = element.class_list();
+    log_error(class_list.remove_2(COLLAPSE, SHOW));
+    log_error(class_list.add_1(COLLAPSING));
+
+    let height = format!("{}px", element.scroll_height());
+    let (from, to) = if expanding {
+        ("0px", height.as_str())
+    } else {
+        (height.as_str(), "0px")
+    };
+
+    let style = element.style();
+    log_error(style.set_property(HEIGHT, from));
+    // Reading the height forces a layout, so the transition starts from `from`.
+    element.offset_height();
+    log_error(style.set_property(HEIGHT, to));
+
+    let finish = {
+        let element = element.clone();
+        Rc::new(move || {
+            let class_list = element.class_list();
+            log_error(class_list.remove_1(COLLAPSING));
+            log_error(class_list.add_1(COLLAPSE));
+            if expanding {
+                log_error(class_list.add_1(SHOW));
+            }
+            if let Err(err) = element.style().remove_property(HEIGHT) {
+                gloo_console::error!(err);
+            }
+        })
+    };
+
+    let listener = {
+        let finish = finish.clone();
+        let target: EventTarget = element.clone().unchecked_into();
+        EventListener::new(&element, "transitionend", move |event| {
+            // Transitions of the content bubble up to the element.
+            if event.target().as_ref() == Some(&target) {
+                finish();
+            }
+        })
+    };
+
+    CollapseTransition {
+        _listener: listener,
+        _timeout: Timeout::new(TRANSITION_DURATION, move || finish()),
+    }
+}
+
+fn log_error(result: Result<(), JsValue>) {
+    if let Err(err) = result {
+        gloo_console::error!(err);
+    }
+}
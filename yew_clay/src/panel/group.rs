@@ -0,0 +1,132 @@
+use super::ClayPanel;
+use crate::html_props::{with_html_props, GlobalProps};
+use std::rc::Rc;
+use yew::html::ChildrenWithProps;
+use yew::{
+    classes, function_component, html, use_state_eq, Callback, Classes, ContextProvider, Html,
+    NodeRef, Properties,
+};
+
+/// Shared by a ClayPanelGroup with its panels, so that only one of them is expanded at a time
+/// in accordion mode.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PanelGroupContext {
+    pub accordion: bool,
+    /// The id of the expanded panel.
+    pub expanded: Option<String>,
+    pub set_expanded: Callback<Option<String>>,
+}
+
+/// Props for ClayPanelGroup. For details, check the docs:
+/// <https://clayui.com/docs/components/panel/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayPanelGroupProps {
+    /// Flag to only allow one of the panels to be expanded at a time. Expanding a panel
+    /// collapses the one that was expanded.
+    #[prop_or(false)]
+    pub accordion: bool,
+
+    /// Flag to remove the spacing around the panels.
+    #[prop_or(false)]
+    pub fluid: bool,
+
+    /// Flag to remove the borders and the spacing between the panels.
+    #[prop_or(false)]
+    pub flush: bool,
+
+    /// Flag to reduce the spacing between the panels.
+    #[prop_or(false)]
+    pub small: bool,
+
+    /// Prefix of the ids of the panels that don't set their own.
+    #[prop_or(ClayPanelGroup::DEFAULT_ID.into())]
+    pub id: String,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: ChildrenWithProps<ClayPanel>,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayPanelGroup {
+    const DEFAULT_ID: &'static str = "clay-panel-group";
+    const PANEL_GROUP: &'static str = "panel-group";
+    const PANEL_GROUP_FLUID: &'static str = "panel-group-fluid";
+    const PANEL_GROUP_FLUSH: &'static str = "panel-group-flush";
+    const PANEL_GROUP_SM: &'static str = "panel-group-sm";
+
+    fn get_panel_id(props: &ClayPanelGroupProps, index: usize, id: &Option<String>) -> String {
+        match id {
+            Some(id) => id.clone(),
+            None => format!("{}-{}", props.id, index),
+        }
+    }
+
+    /// The uncontrolled panel that sets `default_expanded`, which is expanded first in
+    /// accordion mode.
+    fn get_default_expanded(props: &ClayPanelGroupProps) -> Option<String> {
+        props
+            .children
+            .iter()
+            .enumerate()
+            .find(|(_, panel)| panel.props.default_expanded && panel.props.expanded.is_none())
+            .map(|(index, panel)| Self::get_panel_id(props, index, &panel.props.id))
+    }
+}
+
+/// A Yew implementation of ClayPanel.Group, which stacks ClayPanels.
+#[function_component(ClayPanelGroup)]
+pub fn clay_panel_group(props: &ClayPanelGroupProps) -> Html {
+    let expanded = use_state_eq(|| ClayPanelGroup::get_default_expanded(props));
+
+    let ClayPanelGroupProps {
+        accordion,
+        fluid,
+        flush,
+        small,
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let context = PanelGroupContext {
+        accordion,
+        expanded: (*expanded).clone(),
+        set_expanded: Callback::from(move |id| expanded.set(id)),
+    };
+
+    let panels = children.iter().enumerate().map(|(index, mut panel)| {
+        let panel_props = Rc::make_mut(&mut panel.props);
+        panel_props.id = Some(ClayPanelGroup::get_panel_id(props, index, &panel_props.id));
+        panel
+    });
+
+    with_html_props(
+        html! {
+            <div
+                class={classes!(
+                    class,
+                    ClayPanelGroup::PANEL_GROUP,
+                    fluid.then_some(ClayPanelGroup::PANEL_GROUP_FLUID),
+                    flush.then_some(ClayPanelGroup::PANEL_GROUP_FLUSH),
+                    small.then_some(ClayPanelGroup::PANEL_GROUP_SM)
+                )}
+                ref={node_ref}
+                role="tablist" >
+                <ContextProvider<PanelGroupContext> {context}>
+                    {for panels}
+                </ContextProvider<PanelGroupContext>>
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
@@ -0,0 +1,44 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayPanelHeader. For details, check the docs:
+/// <https://clayui.com/docs/components/panel/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayPanelHeaderProps {
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayPanelHeader {
+    const PANEL_HEADER: &'static str = "panel-header";
+}
+
+/// A Yew implementation of ClayPanel.Header.
+#[function_component(ClayPanelHeader)]
+pub fn clay_panel_header(props: &ClayPanelHeaderProps) -> Html {
+    let ClayPanelHeaderProps {
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    with_html_props(
+        html! {
+            <div class={classes!(class, ClayPanelHeader::PANEL_HEADER)} ref={node_ref}>
+                {children}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
@@ -0,0 +1,13 @@
+mod body;
+mod collapse;
+mod footer;
+mod group;
+mod header;
+mod panel;
+
+pub use body::*;
+use collapse::*;
+pub use footer::*;
+pub use group::*;
+pub use header::*;
+pub use panel::*;
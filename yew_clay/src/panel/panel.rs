@@ -0,0 +1,250 @@
+use super::{use_collapse_transition, PanelGroupContext};
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::icon::{ClayIcon, ClaySymbol};
+use strum::AsRefStr;
+use web_sys::MouseEvent;
+use yew::{
+    classes, function_component, html, use_context, use_node_ref, use_state_eq, Callback, Children,
+    Classes, Html, NodeRef, Properties,
+};
+
+/// Props for ClayPanel. For details, check the docs:
+/// <https://clayui.com/docs/components/panel/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayPanelProps {
+    /// Flag to render a header that expands and collapses the content of the panel.
+    #[prop_or(false)]
+    pub collapsable: bool,
+
+    /// Flag to indicate if the panel is expanded (controlled).
+    #[prop_or_default]
+    pub expanded: Option<bool>,
+
+    /// Property to set the initial value of `expanded` (uncontrolled). In an accordion
+    /// ClayPanelGroup, only one of the panels should set it.
+    #[prop_or(false)]
+    pub default_expanded: bool,
+
+    /// The id of the content of a collapsable panel, which its header controls. Inside of a
+    /// ClayPanelGroup, it defaults to an id based on the id of the group.
+    #[prop_or_default]
+    pub id: Option<String>,
+
+    /// Callback for when the header of a collapsable panel is clicked.
+    #[prop_or_default]
+    pub on_expanded_change: Option<Callback<bool>>,
+
+    /// The title of the panel, rendered in its header.
+    #[prop_or_default]
+    pub display_title: Option<Html>,
+
+    /// Determines the style of the panel.
+    #[prop_or_default]
+    pub display_type: Option<PanelDisplayType>,
+
+    /// Flag to render the icons that indicate if a collapsable panel is expanded.
+    #[prop_or(true)]
+    pub show_collapse_icon: bool,
+
+    /// The symbol displayed while the panel is expanded.
+    #[prop_or(ClaySymbol::AngleDown)]
+    pub collapse_icon_expanded: ClaySymbol,
+
+    /// The symbol displayed while the panel is collapsed.
+    #[prop_or(ClaySymbol::AngleRight)]
+    pub collapse_icon_collapsed: ClaySymbol,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayPanel {
+    const BTN: &'static str = "btn btn-unstyled";
+    const COLLAPSE: &'static str = "collapse";
+    const COLLAPSE_ICON: &'static str = "collapse-icon collapse-icon-middle";
+    const COLLAPSE_ICON_CLOSED: &'static str = "collapse-icon-closed";
+    const COLLAPSE_ICON_OPEN: &'static str = "collapse-icon-open";
+    const COLLAPSED: &'static str = "collapsed";
+    const PANEL: &'static str = "panel";
+    const PANEL_COLLAPSE: &'static str = "panel-collapse";
+    const PANEL_DISPLAY: &'static str = "panel-";
+    const PANEL_HEADER: &'static str = "panel-header";
+    const PANEL_HEADER_LINK: &'static str = "panel-header-link";
+    const PANEL_TITLE: &'static str = "panel-title";
+    const SHOW: &'static str = "show";
+
+    fn get_display_class(display_type: &Option<PanelDisplayType>) -> Option<String> {
+        display_type.as_ref().map(|display_type| {
+            let display_type = display_type.as_ref();
+            let mut class = String::with_capacity(Self::PANEL_DISPLAY.len() + display_type.len());
+            class.push_str(Self::PANEL_DISPLAY);
+            class.push_str(display_type);
+            class
+        })
+    }
+
+    fn gen_collapse_icons(props: &ClayPanelProps) -> Html {
+        if !props.show_collapse_icon {
+            return html! {};
+        }
+
+        let spritemap = props.spritemap;
+        html! {
+            <>
+                <span class={ClayPanel::COLLAPSE_ICON_CLOSED}>
+                    <ClayIcon {spritemap} symbol={props.collapse_icon_collapsed.clone()} />
+                </span>
+                <span class={ClayPanel::COLLAPSE_ICON_OPEN}>
+                    <ClayIcon {spritemap} symbol={props.collapse_icon_expanded.clone()} />
+                </span>
+            </>
+        }
+    }
+}
+
+/// A Yew implementation of ClayPanel. For more info about ClayPanel, check the documentation:
+/// <https://clayui.com/docs/components/panel.html>
+///
+/// Collapsable panels animate the height of their content when they expand or collapse. Inside
+/// of an accordion ClayPanelGroup, the group decides which panel is expanded, unless the
+/// panel is controlled with `expanded`.
+#[function_component(ClayPanel)]
+pub fn clay_panel(props: &ClayPanelProps) -> Html {
+    let collapse_ref = use_node_ref();
+    let internal_expanded = use_state_eq(|| props.default_expanded);
+    // The group gives an id to each of its panels.
+    let group =
+        use_context::<PanelGroupContext>().filter(|group| group.accordion && props.id.is_some());
+    let id = props.id.clone();
+
+    let expanded = match (props.expanded, &group) {
+        (Some(expanded), _) => expanded,
+        (None, Some(group)) => group.expanded == id,
+        (None, None) => *internal_expanded,
+    };
+    use_collapse_transition(&collapse_ref, expanded);
+
+    let set_expanded = {
+        let controlled = props.expanded.is_some();
+        let on_expanded_change = props.on_expanded_change.clone();
+        let id = id.clone();
+        Callback::from(move |expanded: bool| {
+            if let Some(on_expanded_change) = &on_expanded_change {
+                on_expanded_change.emit(expanded);
+            }
+
+            if controlled {
+                return;
+            }
+
+            match &group {
+                Some(group) => group.set_expanded.emit(id.clone().filter(|_| expanded)),
+                None => internal_expanded.set(expanded),
+            }
+        })
+    };
+
+    let ClayPanelProps {
+        collapsable,
+        display_title,
+        display_type,
+        show_collapse_icon,
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let content = if collapsable {
+        let header_id = id.as_ref().map(|id| format!("{}-header", id));
+        let onclick = set_expanded.reform(move |_: MouseEvent| !expanded);
+
+        html! {
+            <>
+                <button
+                    aria-controls={id.clone()}
+                    aria-expanded={expanded.to_string()}
+                    class={classes!(
+                        ClayPanel::BTN,
+                        ClayPanel::PANEL_HEADER,
+                        ClayPanel::PANEL_HEADER_LINK,
+                        show_collapse_icon.then_some(ClayPanel::COLLAPSE_ICON),
+                        (!expanded).then_some(ClayPanel::COLLAPSED)
+                    )}
+                    id={header_id.clone()}
+                    {onclick}
+                    role="tab"
+                    type="button" >
+                    <span class={ClayPanel::PANEL_TITLE}>{display_title}</span>
+                    {ClayPanel::gen_collapse_icons(props)}
+                </button>
+                <div
+                    aria-labelledby={header_id}
+                    class={classes!(
+                        ClayPanel::PANEL_COLLAPSE,
+                        ClayPanel::COLLAPSE,
+                        expanded.then_some(ClayPanel::SHOW)
+                    )}
+                    {id}
+                    ref={collapse_ref}
+                    role="tabpanel" >
+                    {children}
+                </div>
+            </>
+        }
+    } else {
+        let header = match display_title {
+            Some(display_title) => html! {
+                <div class={ClayPanel::PANEL_HEADER}>
+                    <span class={ClayPanel::PANEL_TITLE}>{display_title}</span>
+                </div>
+            },
+            None => html! {},
+        };
+
+        html! {
+            <>
+                {header}
+                {children}
+            </>
+        }
+    };
+
+    with_html_props(
+        html! {
+            <div
+                class={classes!(
+                    class,
+                    ClayPanel::PANEL,
+                    ClayPanel::get_display_class(&display_type)
+                )}
+                ref={node_ref}
+                role="tablist" >
+                {content}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
+
+/// An enum specifying the display types of ClayPanel.
+#[derive(AsRefStr, Debug, PartialEq, Clone)]
+#[strum(serialize_all = "lowercase")]
+pub enum PanelDisplayType {
+    Secondary,
+    Unstyled,
+}
@@ -1,50 +1,471 @@
 use lru::LruCache;
-use std::{collections::HashMap, future::Future};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::future::Future;
+use std::rc::{Rc, Weak};
+use yew::platform::spawn_local;
+use yew::Callback;
 
-pub enum ValueOption<T> {
-    Value(T),
-    FutureValue(Box<dyn Future<Output = T>>),
+/// A page of data returned by a paginated fetch, along with the cursor that should be used to
+/// load the next page. A cursor of None indicates that there are no more pages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataPage<T> {
+    pub data: T,
+    pub cursor: Option<String>,
 }
 
+struct DataClientState<T> {
+    storage_max_size: usize,
+    cache: LruCache<String, T>,
+    /// Requests that are in flight, along with the callbacks waiting for them to resolve.
+    pending: HashMap<String, Vec<Callback<T>>>,
+    /// The cursor for the next page of each paginated key. A value of None means that the last
+    /// page has already been loaded.
+    cursors: HashMap<String, Option<String>>,
+    subscribers: HashMap<String, Vec<(usize, Callback<T>)>>,
+    next_subscriber_id: usize,
+}
+
+/// An LRU cache of resources that drives the futures that load them. Requests for a key that is
+/// already being fetched are de-duplicated, and subscribers are notified whenever a key
+/// receives a new value.
+///
+/// DataClient is a cheap handle; clones share the same cache.
 pub struct DataClient<T> {
-    cache: LruCache<&'static str, T>,
-    current_futures: HashMap<&'static str, Box<dyn Future<Output = T>>>,
-    cursors: HashMap<&'static str, String>,
+    state: Rc<RefCell<DataClientState<T>>>,
 }
 
-impl<T> DataClient<T> {
+impl<T: Clone + 'static> DataClient<T> {
+    /// Creates a new DataClient. A `storage_max_size` of zero creates an unbounded cache.
     pub fn new(storage_max_size: usize) -> Self {
+        let cache = match storage_max_size {
+            0 => LruCache::unbounded(),
+            size => LruCache::new(size),
+        };
+
         Self {
-            cache: LruCache::new(storage_max_size),
-            current_futures: HashMap::new(),
-            cursors: HashMap::new(),
+            state: Rc::new(RefCell::new(DataClientState {
+                storage_max_size,
+                cache,
+                pending: HashMap::new(),
+                cursors: HashMap::new(),
+                subscribers: HashMap::new(),
+                next_subscriber_id: 0,
+            })),
         }
     }
 
-    pub fn read(&mut self, key: &'static str) -> Option<&T> {
-        self.cache.get(&key)
+    pub fn storage_max_size(&self) -> usize {
+        self.state.borrow().storage_max_size
+    }
+
+    /// Returns the cached value for the key, if there is one.
+    pub fn read(&self, key: &str) -> Option<T> {
+        self.state.borrow_mut().cache.get(key).cloned()
+    }
+
+    /// Stores a value in the cache and notifies the subscribers of the key.
+    pub fn update(&self, key: impl Into<String>, value: T) {
+        self.resolve(key.into(), value);
+    }
+
+    /// Indicates whether a request for the key is currently in flight.
+    pub fn is_fetching(&self, key: &str) -> bool {
+        self.state.borrow().pending.contains_key(key)
+    }
+
+    /// Loads the value for the key. If the value is cached, the callback is called right away.
+    /// If a request for the same key is already in flight, the callback waits for that request
+    /// instead of starting a new one. Otherwise, the future returned by the fetcher is spawned
+    /// and its result is moved into the cache once it resolves.
+    pub fn fetch<F, Fut>(&self, key: impl Into<String>, fetcher: F, callback: Callback<T>)
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T> + 'static,
+    {
+        let key = key.into();
+
+        if let Some(value) = self.read(&key) {
+            callback.emit(value);
+            return;
+        }
+
+        if !self.add_pending(&key, callback) {
+            return;
+        }
+
+        let future = fetcher();
+        let client = self.clone();
+        spawn_local(async move {
+            let value = future.await;
+            client.resolve(key, value);
+        });
     }
 
-    pub fn update(&mut self, key: &'static str, value: ValueOption<T>) {
-        match value {
-            ValueOption::Value(value) => {
-                self.cache.push(key, value);
+    /// Loads the next page of a paginated key. The fetcher receives the cursor returned by the
+    /// previous page (None for the first page). Each page is cached separately, and the cursor is
+    /// stored so that the following call loads the page after it. The subscribers of the key are
+    /// notified of every page that is loaded.
+    pub fn fetch_next_page<F, Fut>(&self, key: impl Into<String>, fetcher: F, callback: Callback<T>)
+    where
+        F: FnOnce(Option<String>) -> Fut,
+        Fut: Future<Output = DataPage<T>> + 'static,
+    {
+        let key = key.into();
+        if !self.has_more(&key) {
+            return;
+        }
+
+        let cursor = self.get_cursor(&key);
+        let page_key = Self::get_page_key(&key, &cursor);
+
+        if let Some(value) = self.read(&page_key) {
+            callback.emit(value);
+            return;
+        }
+
+        if !self.add_pending(&page_key, callback) {
+            return;
+        }
+
+        let future = fetcher(cursor);
+        let client = self.clone();
+        spawn_local(async move {
+            let DataPage { data, cursor } = future.await;
+            client
+                .state
+                .borrow_mut()
+                .cursors
+                .insert(key.clone(), cursor);
+            if page_key == key {
+                client.resolve(page_key, data);
+            } else {
+                client.resolve(page_key, data.clone());
+                client.notify(&key, data);
             }
-            ValueOption::FutureValue(future) => {
-                self.current_futures.insert(key, future);
+        });
+    }
+
+    /// Returns the cursor for the next page of the key.
+    pub fn get_cursor(&self, key: &str) -> Option<String> {
+        self.state.borrow().cursors.get(key).cloned().flatten()
+    }
+
+    /// Sets the cursor for the next page of the key, returning the previous one.
+    pub fn set_cursor(&self, key: impl Into<String>, value: Option<String>) -> Option<String> {
+        self.state
+            .borrow_mut()
+            .cursors
+            .insert(key.into(), value)
+            .flatten()
+    }
+
+    /// Indicates whether there are more pages to be loaded for a paginated key.
+    pub fn has_more(&self, key: &str) -> bool {
+        !matches!(self.state.borrow().cursors.get(key), Some(None))
+    }
+
+    /// Forgets the cursor of a paginated key so that it will be loaded from the first page.
+    pub fn reset_cursor(&self, key: &str) {
+        self.state.borrow_mut().cursors.remove(key);
+    }
+
+    /// Registers a callback that is called every time the key receives a new value. The
+    /// subscription is cancelled when the returned handle is dropped.
+    pub fn subscribe(&self, key: impl Into<String>, callback: Callback<T>) -> DataSubscription<T> {
+        let key = key.into();
+        let mut state = self.state.borrow_mut();
+        let id = state.next_subscriber_id;
+        state.next_subscriber_id += 1;
+        state
+            .subscribers
+            .entry(key.clone())
+            .or_default()
+            .push((id, callback));
+
+        DataSubscription {
+            state: Rc::downgrade(&self.state),
+            key,
+            id,
+        }
+    }
+
+    fn get_page_key(key: &str, cursor: &Option<String>) -> String {
+        match cursor {
+            Some(cursor) => format!("{}#{}", key, cursor),
+            None => key.to_string(),
+        }
+    }
+
+    /// Adds the callback to the waiters of the key. Returns true if there was no request in
+    /// flight for the key, meaning that the caller should start one.
+    fn add_pending(&self, key: &str, callback: Callback<T>) -> bool {
+        let mut state = self.state.borrow_mut();
+        match state.pending.get_mut(key) {
+            Some(waiters) => {
+                waiters.push(callback);
+                false
+            }
+            None => {
+                state.pending.insert(key.to_string(), vec![callback]);
+                true
             }
+        }
+    }
+
+    fn get_subscribers(state: &DataClientState<T>, key: &str) -> Vec<Callback<T>> {
+        state
+            .subscribers
+            .get(key)
+            .map(|subscribers| {
+                subscribers
+                    .iter()
+                    .map(|(_, callback)| callback.clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Notifies the subscribers of the key without caching the value.
+    fn notify(&self, key: &str, value: T) {
+        let subscribers = Self::get_subscribers(&self.state.borrow(), key);
+        for callback in subscribers {
+            callback.emit(value.clone());
+        }
+    }
+
+    fn resolve(&self, key: String, value: T) {
+        // The borrow must be released before emitting, as callbacks may use the client.
+        let (waiters, subscribers) = {
+            let mut state = self.state.borrow_mut();
+            let waiters = state.pending.remove(&key).unwrap_or_default();
+            let subscribers = Self::get_subscribers(&state, &key);
+            state.cache.put(key, value.clone());
+            (waiters, subscribers)
         };
+
+        for callback in waiters.iter().chain(subscribers.iter()) {
+            callback.emit(value.clone());
+        }
+    }
+}
+
+impl<T> Clone for DataClient<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for DataClient<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl<T> Debug for DataClient<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DataClient")
+            .field("storage_max_size", &self.state.borrow().storage_max_size)
+            .finish()
+    }
+}
+
+/// Handle returned by [`DataClient::subscribe`]. Dropping it removes the subscription.
+pub struct DataSubscription<T> {
+    state: Weak<RefCell<DataClientState<T>>>,
+    key: String,
+    id: usize,
+}
+
+impl<T> Drop for DataSubscription<T> {
+    fn drop(&mut self) {
+        if let Some(state) = self.state.upgrade() {
+            let mut state = state.borrow_mut();
+            if let Some(subscribers) = state.subscribers.get_mut(&self.key) {
+                subscribers.retain(|(id, _)| *id != self.id);
+                if subscribers.is_empty() {
+                    state.subscribers.remove(&self.key);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::future::ready;
+
+    fn run(test: impl Future<Output = ()>) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .expect("the runtime to be created");
+        tokio::task::LocalSet::new().block_on(&runtime, test);
+    }
+
+    /// Lets the spawned requests resolve.
+    async fn settle() {
+        for _ in 0..10 {
+            tokio::task::yield_now().await;
+        }
+    }
+
+    fn collect<T: 'static>() -> (Rc<RefCell<Vec<T>>>, Callback<T>) {
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let callback = {
+            let values = values.clone();
+            Callback::from(move |value| values.borrow_mut().push(value))
+        };
+        (values, callback)
     }
 
-    pub fn is_fetching(&self, key: &'static str) -> bool {
-        self.current_futures.contains_key(key)
+    #[test]
+    fn deduplicates_requests_in_flight() {
+        run(async {
+            let client = DataClient::<u32>::new(0);
+            let calls = Rc::new(Cell::new(0));
+            let (values, callback) = collect();
+
+            for _ in 0..2 {
+                let calls = calls.clone();
+                client.fetch(
+                    "key",
+                    move || {
+                        calls.set(calls.get() + 1);
+                        ready(1)
+                    },
+                    callback.clone(),
+                );
+            }
+            assert!(client.is_fetching("key"));
+            settle().await;
+
+            assert_eq!(calls.get(), 1);
+            assert_eq!(*values.borrow(), vec![1, 1]);
+            assert!(!client.is_fetching("key"));
+            assert_eq!(client.read("key"), Some(1));
+        });
     }
 
-    pub fn get_cursor(&mut self, key: &'static str) -> Option<&String> {
-        self.cursors.get(key)
+    #[test]
+    fn returns_cached_values_without_fetching() {
+        let client = DataClient::<u32>::new(0);
+        let (values, callback) = collect();
+
+        client.update("key", 2);
+        client.fetch(
+            "key",
+            || -> std::future::Pending<u32> { unreachable!() },
+            callback,
+        );
+
+        assert_eq!(*values.borrow(), vec![2]);
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_value() {
+        let client = DataClient::<u32>::new(2);
+
+        client.update("a", 1);
+        client.update("b", 2);
+        assert_eq!(client.read("a"), Some(1));
+        client.update("c", 3);
+
+        assert_eq!(client.read("a"), Some(1));
+        assert_eq!(client.read("b"), None);
+        assert_eq!(client.read("c"), Some(3));
     }
 
-    pub fn set_cursor(&mut self, key: &'static str, value: String) -> Option<String> {
-        self.cursors.insert(key, value)
+    #[test]
+    fn follows_the_cursor_of_paginated_keys() {
+        run(async {
+            let client = DataClient::<u32>::new(0);
+            let cursors = Rc::new(RefCell::new(Vec::new()));
+            let pages = [
+                DataPage {
+                    data: 1,
+                    cursor: Some("2".to_string()),
+                },
+                DataPage {
+                    data: 2,
+                    cursor: None,
+                },
+            ];
+
+            for page in pages {
+                let cursors = cursors.clone();
+                client.fetch_next_page(
+                    "list",
+                    move |cursor| {
+                        cursors.borrow_mut().push(cursor);
+                        ready(page)
+                    },
+                    Callback::noop(),
+                );
+                settle().await;
+            }
+
+            assert_eq!(*cursors.borrow(), vec![None, Some("2".to_string())]);
+            assert_eq!(client.read("list"), Some(1));
+            assert_eq!(client.read("list#2"), Some(2));
+            assert!(!client.has_more("list"));
+
+            client.reset_cursor("list");
+            assert!(client.has_more("list"));
+            assert_eq!(client.get_cursor("list"), None);
+        });
+    }
+
+    #[test]
+    fn notifies_the_subscribers_of_the_key_of_every_page() {
+        run(async {
+            let client = DataClient::<u32>::new(0);
+            let (values, callback) = collect();
+            let _subscription = client.subscribe("list", callback);
+
+            client.fetch_next_page(
+                "list",
+                |_| {
+                    ready(DataPage {
+                        data: 1,
+                        cursor: Some("2".to_string()),
+                    })
+                },
+                Callback::noop(),
+            );
+            settle().await;
+            client.fetch_next_page(
+                "list",
+                |_| {
+                    ready(DataPage {
+                        data: 2,
+                        cursor: None,
+                    })
+                },
+                Callback::noop(),
+            );
+            settle().await;
+
+            assert_eq!(*values.borrow(), vec![1, 2]);
+        });
+    }
+
+    #[test]
+    fn stops_notifying_dropped_subscriptions() {
+        let client = DataClient::<u32>::new(0);
+        let (values, callback) = collect();
+
+        let subscription = client.subscribe("key", callback.clone());
+        let _other = client.subscribe("other", callback);
+        client.update("key", 1);
+        drop(subscription);
+        client.update("key", 2);
+        client.update("other", 3);
+
+        assert_eq!(*values.borrow(), vec![1, 3]);
     }
 }
@@ -1,2 +1,5 @@
 mod data_client;
 mod provider;
+
+pub use data_client::*;
+pub use provider::*;
@@ -1,63 +1,111 @@
-// import {ClayIconSpriteContext} from '@clayui/icon';
-// import React, {useContext, useMemo} from 'react';
-
-// import {DataClient} from './DataClient';
-
-// interface IProviderProps extends Omit<IProviderContext, 'client'> {
-// 	/**
-// 	 * The content of the Provider.
-// 	 */
-// 	children: React.ReactNode;
-
-// 	/**
-// 	 * Path to the location of the spritemap resource.
-// 	 */
-// 	spritemap: string;
-
-// 	/**
-// 	 * Set the amount of items that can be cached, set to zero will be
-// 	 * treated as infinite, be aware to set an ideal size to offer a
-// 	 * positive experience for your user but not use a large amount of memory.
-// 	 */
-// 	storageMaxSize?: number;
-// }
-
-// interface IProviderContext {
-// 	client: DataClient;
-
-// 	/**
-// 	 * The theme corresponds to a CSS class to scope the application.
-// 	 */
-// 	theme?: string;
-// }
-
-// const Context = React.createContext<IProviderContext>({} as IProviderContext);
-
-// Context.displayName = 'ClayProviderContext';
-
-// export const Provider = ({
-// 	children,
-// 	spritemap,
-// 	storageMaxSize = 20,
-// 	theme,
-// 	...otherProps
-// }: IProviderProps) => {
-// 	// Use `useMemo` to instantiate the DataClient only once and when
-// 	// updating the property.
-// 	const client = useMemo(
-// 		() => new DataClient({storageMaxSize}),
-// 		[storageMaxSize]
-// 	);
-
-// 	return (
-// 		<Context.Provider value={{client, theme, ...otherProps}}>
-// 			<ClayIconSpriteContext.Provider value={spritemap}>
-// 				{theme ? <div className={theme}>{children}</div> : children}
-// 			</ClayIconSpriteContext.Provider>
-// 		</Context.Provider>
-// 	);
-// };
-
-// export const useProvider = () => {
-// 	return useContext(Context);
-// };
+use super::data_client::DataClient;
+use crate::icon::ClayIconSpriteContext;
+#[cfg(feature = "icon-registry")]
+use crate::icon::IconRegistry;
+use std::any::Any;
+use std::rc::Rc;
+use yew::{
+    function_component, hook, html, use_context, use_memo, Children, ContextProvider, Html,
+    Properties,
+};
+
+/// The DataClient shared through the ClayProvider. Values are stored type-erased so that
+/// any component under the provider can cache its own data; use `Rc::downcast` to recover
+/// the concrete type.
+pub type AnyDataClient = DataClient<Rc<dyn Any>>;
+
+/// The context set by ClayProvider.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClayProviderContext {
+    pub client: AnyDataClient,
+
+    /// The theme corresponds to a CSS class to scope the application.
+    pub theme: Option<String>,
+}
+
+/// Props for ClayProvider. For details, check the docs:
+/// <https://clayui.com/docs/components/provider.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayProviderProps {
+    /// Path to the location of the spritemap resource.
+    pub spritemap: &'static str,
+
+    /// The theme corresponds to a CSS class to scope the application.
+    #[prop_or_default]
+    pub theme: Option<String>,
+
+    /// Set the amount of items that can be cached, set to zero will be
+    /// treated as infinite, be aware to set an ideal size to offer a
+    /// positive experience for your user but not use a large amount of memory.
+    #[prop_or(20)]
+    pub storage_max_size: usize,
+
+    /// Symbols to render inline in every ClayIcon inside of the provider, instead of
+    /// referencing the spritemap.
+    #[cfg(feature = "icon-registry")]
+    #[prop_or_default]
+    pub icon_registry: Option<IconRegistry>,
+
+    /// The content of the Provider.
+    #[prop_or_default]
+    pub children: Children,
+}
+
+impl ClayProvider {
+    fn create_client(storage_max_size: usize) -> AnyDataClient {
+        DataClient::new(storage_max_size)
+    }
+}
+
+/// A Yew implementation of ClayProvider. It sets the spritemap used by every ClayIcon inside
+/// of it, optionally scopes the application with a theme class and provides a DataClient
+/// that caches data fetched by components.
+#[function_component(ClayProvider)]
+pub fn clay_provider(props: &ClayProviderProps) -> Html {
+    // The client is only recreated when the size of the storage changes.
+    let client = use_memo(props.storage_max_size, |storage_max_size| {
+        ClayProvider::create_client(*storage_max_size)
+    });
+
+    let ClayProviderProps {
+        spritemap,
+        theme,
+        children,
+        ..
+    } = props.clone();
+
+    let context = ClayProviderContext {
+        client: (*client).clone(),
+        theme: theme.clone(),
+    };
+
+    let content = if let Some(theme) = theme {
+        html! { <div class={theme}>{children}</div> }
+    } else {
+        html! { <>{children}</> }
+    };
+
+    #[cfg(feature = "icon-registry")]
+    let content = match props.icon_registry.clone() {
+        Some(registry) => html! {
+            <ContextProvider<IconRegistry> context={registry}>
+                {content}
+            </ContextProvider<IconRegistry>>
+        },
+        None => content,
+    };
+
+    html! {
+        <ContextProvider<ClayProviderContext> {context}>
+            <ContextProvider<ClayIconSpriteContext> context={ClayIconSpriteContext(spritemap)}>
+                {content}
+            </ContextProvider<ClayIconSpriteContext>>
+        </ContextProvider<ClayProviderContext>>
+    }
+}
+
+/// Hook to access the context set by the nearest ClayProvider from a function component.
+#[hook]
+pub fn use_provider() -> Option<ClayProviderContext> {
+    use_context::<ClayProviderContext>()
+}
@@ -1,44 +1,157 @@
-use web_sys::Element;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, Node};
 use yew::NodeRef;
 
-// Putting this on hold; seems it's for some react-specific details
+const CONTENT_EDITABLE: &str = "contenteditable";
+const DISABLED: &str = "disabled";
+const HIDDEN: &str = "hidden";
+const HREF: &str = "href";
+const TABINDEX: &str = "tabindex";
+const TYPE: &str = "type";
 
-fn is_fiber_host_component_focusable() -> bool {
-    false
+/// Checks whether an element can receive focus through the keyboard. Elements that are
+/// disabled, hidden or have a negative tabindex are skipped.
+pub fn is_focusable(element: &Element) -> bool {
+    if element.has_attribute(DISABLED) {
+        return false;
+    }
+
+    let tab_index = element
+        .get_attribute(TABINDEX)
+        .and_then(|tab_index| tab_index.trim().parse::<i32>().ok());
+
+    if let Some(tab_index) = tab_index {
+        if tab_index < 0 {
+            return false;
+        }
+    }
+
+    // Elements that are not rendered (e.g. display: none) have no client rects. Unlike the
+    // offset parent, this also holds for rendered elements with a fixed position.
+    if !element.is_instance_of::<HtmlElement>() || element.get_client_rects().length() == 0 {
+        return false;
+    }
+
+    if let Some(content_editable) = element.get_attribute(CONTENT_EDITABLE) {
+        if content_editable.is_empty() || content_editable == "true" {
+            return true;
+        }
+    }
+
+    match element.tag_name().to_lowercase().as_str() {
+        "a" | "area" => element.has_attribute(HREF),
+        "input" => element.get_attribute(TYPE).as_deref() != Some(HIDDEN),
+        "button" | "iframe" | "select" | "textarea" => true,
+        _ => tab_index.is_some(),
+    }
 }
 
-fn collect_focusable_elements(element: Element, focusable_elements: &mut Vec<Element>) {
-    if let Some(child) = element.first_element_child() {
-        collect_focusable_elements(child, focusable_elements)
-    };
+fn collect_focusable_elements(element: Element, focusable_elements: &mut Vec<HtmlElement>) {
+    if element.has_attribute(HIDDEN) {
+        return;
+    }
 
-    if let Some(sibling) = element.next_element_sibling() {
-        collect_focusable_elements(sibling, focusable_elements)
-    };
+    if is_focusable(&element) {
+        if let Ok(html_element) = element.clone().dyn_into::<HtmlElement>() {
+            focusable_elements.push(html_element);
+        }
+    }
 
-    if is_fiber_host_component_focusable() {
-        focusable_elements.push(element);
+    let mut child = element.first_element_child();
+    while let Some(current) = child {
+        child = current.next_element_sibling();
+        collect_focusable_elements(current, focusable_elements);
     }
 }
 
-fn get_focusable_elements_in_scope(fiber_node: NodeRef) -> Vec<Element> {
-    let mut focusable_elements: Vec<Element> = Vec::new();
-    let element = match fiber_node.cast::<Element>() {
+/// Returns all focusable elements contained by the scope in document order.
+pub fn get_focusable_elements_in_scope(scope: &NodeRef) -> Vec<HtmlElement> {
+    let mut focusable_elements: Vec<HtmlElement> = Vec::new();
+    let element = match scope.cast::<Element>() {
         Some(elem) => elem,
         None => return focusable_elements,
     };
 
-    if let Some(child) = element.first_element_child() {
-        collect_focusable_elements(child, &mut focusable_elements)
-    };
+    let mut child = element.first_element_child();
+    while let Some(current) = child {
+        child = current.next_element_sibling();
+        collect_focusable_elements(current, &mut focusable_elements);
+    }
 
     focusable_elements
 }
 
-trait FocusManagement {
-    fn get_next_focus_in_doc_ref() -> Option<NodeRef>;
-    fn get_prev_focus_in_doc_ref() -> Option<NodeRef>;
-    fn move_focus_in_scope(scope: NodeRef) {
-        let fiber_focus_elements = get_focusable_elements_in_scope(scope);
+fn get_active_element() -> Option<Element> {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.active_element())
+}
+
+/// The direction in which focus should move within a scope.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum FocusDirection {
+    Next,
+    Previous,
+    First,
+    Last,
+}
+
+/// Moves the focus to the next or previous focusable element of the scope, relative to the
+/// element that currently has focus. Returns the newly focused element, or None if there was
+/// nothing to move to.
+pub fn move_focus_in_scope(scope: &NodeRef, direction: FocusDirection) -> Option<HtmlElement> {
+    let focusable_elements = get_focusable_elements_in_scope(scope);
+
+    let current_index = get_active_element().and_then(|active| {
+        let active: &Node = &active;
+        focusable_elements
+            .iter()
+            .position(|element| element.is_same_node(Some(active)))
+    });
+
+    let next_index = match (direction, current_index) {
+        (FocusDirection::First, _) | (FocusDirection::Next, None) => Some(0),
+        (FocusDirection::Last, _) | (FocusDirection::Previous, None) => {
+            focusable_elements.len().checked_sub(1)
+        }
+        (FocusDirection::Next, Some(index)) => Some(index + 1),
+        (FocusDirection::Previous, Some(index)) => index.checked_sub(1),
+    };
+
+    let next = next_index.and_then(|index| focusable_elements.get(index).cloned())?;
+
+    if let Err(err) = next.focus() {
+        gloo_console::error!(err);
+        return None;
+    }
+
+    Some(next)
+}
+
+/// Handle that moves the focus between the focusable elements under its scope.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct FocusManager {
+    scope: NodeRef,
+}
+
+impl FocusManager {
+    pub fn new(scope: NodeRef) -> Self {
+        Self { scope }
+    }
+
+    pub fn focus_next(&self) -> Option<HtmlElement> {
+        move_focus_in_scope(&self.scope, FocusDirection::Next)
+    }
+
+    pub fn focus_previous(&self) -> Option<HtmlElement> {
+        move_focus_in_scope(&self.scope, FocusDirection::Previous)
+    }
+
+    pub fn focus_first(&self) -> Option<HtmlElement> {
+        move_focus_in_scope(&self.scope, FocusDirection::First)
+    }
+
+    pub fn focus_last(&self) -> Option<HtmlElement> {
+        move_focus_in_scope(&self.scope, FocusDirection::Last)
     }
 }
@@ -1,8 +1,41 @@
-use yew::Children;
+use crate::shared::focus_management::FocusManager;
+use std::rc::Rc;
+use yew::html::IntoPropValue;
+use yew::{Children, Html};
 
+/// The content of a FocusScope. Either regular children, or a function that receives the
+/// FocusManager of the scope so that the content can move focus programmatically.
+#[derive(Clone)]
 pub enum FocusManagerChildren {
     Children(Children),
-    Function(Box<dyn Fn() -> ()>),
+    Function(Rc<dyn Fn(FocusManager) -> Html>),
+}
+
+impl FocusManagerChildren {
+    pub fn render(&self, focus_manager: FocusManager) -> Html {
+        match self {
+            Self::Children(children) => children.iter().collect::<Html>(),
+            Self::Function(render) => render(focus_manager),
+        }
+    }
+}
+
+impl Default for FocusManagerChildren {
+    fn default() -> Self {
+        Self::Children(Children::default())
+    }
+}
+
+impl From<Children> for FocusManagerChildren {
+    fn from(children: Children) -> Self {
+        Self::Children(children)
+    }
+}
+
+impl IntoPropValue<FocusManagerChildren> for Children {
+    fn into_prop_value(self) -> FocusManagerChildren {
+        FocusManagerChildren::Children(self)
+    }
 }
 
 impl PartialEq for FocusManagerChildren {
@@ -10,8 +43,8 @@ impl PartialEq for FocusManagerChildren {
         match (self, other) {
             (Self::Children(l0), Self::Children(r0)) => l0 == r0,
             (Self::Function(l0), Self::Function(r0)) => {
-                let left: *const dyn Fn() -> () = l0.as_ref();
-                let right: *const dyn Fn() -> () = r0.as_ref();
+                let left = Rc::as_ptr(l0) as *const u8;
+                let right = Rc::as_ptr(r0) as *const u8;
                 left == right
             }
             _ => false,
@@ -23,7 +56,10 @@ impl core::fmt::Debug for FocusManagerChildren {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::Children(arg0) => f.debug_tuple("Children").field(arg0).finish(),
-            Self::Function(_) => f.debug_tuple("Function").field(&"dyn Fn() -> ()").finish(),
+            Self::Function(_) => f
+                .debug_tuple("Function")
+                .field(&"dyn Fn(FocusManager) -> Html")
+                .finish(),
         }
     }
 }
@@ -1,41 +1,192 @@
-use self::focuse_manager_children::FocusManagerChildren;
-use yew::{Callback, Component, FocusEvent, Properties};
+use super::focus_management::FocusManager;
+use super::keys::{ARROW_DOWN, ARROW_LEFT, ARROW_RIGHT, ARROW_UP, TAB};
+use web_sys::KeyboardEvent;
+use yew::{
+    classes, function_component, hook, html, use_context, use_memo, Callback, Classes,
+    ContextProvider, FocusEvent, Html, NodeRef, Properties,
+};
+
+pub use self::focus_conflict_context::FocusConflictContext;
+pub use self::focuse_manager_children::FocusManagerChildren;
 
 mod focus_conflict_context;
 mod focuse_manager_children;
 
-#[derive(Debug, Properties, PartialEq)]
-struct Props {
+/// Props for FocusScope.
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct FocusScopeProps {
     /// Flag indicates whether the focus will also be controlled with the right
     /// and left arrow keys.
     #[prop_or_default]
-    arrow_keys_left_right: bool,
+    pub arrow_keys_left_right: bool,
 
     /// Flag that indicates if the focus will be controlled by the arrow keys.
     /// Disabling means that it will still be controlled by tab and shift + tab.
+    #[prop_or(true)]
+    pub arrow_keys_up_down: bool,
+
+    #[prop_or_default]
+    pub children: FocusManagerChildren,
+
+    /// Callback for when an element within the scope receives focus.
+    #[prop_or_default]
+    pub onfocus: Option<Callback<FocusEvent>>,
+
+    /// Element to render as the container of the scope.
+    #[prop_or("div".into())]
+    pub container_element: String,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// NodeRef of the element that delimits the scope.
     #[prop_or_default]
+    pub node_ref: NodeRef,
+}
+
+/// The FocusManager of a scope created with `use_focus_scope`, along with the handler to set as
+/// the `onkeydown` of the element that delimits the scope.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusScopeHandle {
+    pub focus_manager: FocusManager,
+    pub onkeydown: Callback<KeyboardEvent>,
+}
+
+/// Hook that moves the focus between the focusable elements inside of the element of
+/// `node_ref` using the arrow keys and Tab / Shift+Tab, the way FocusScope does.
+#[hook]
+pub fn use_focus_scope(
+    node_ref: &NodeRef,
+    arrow_keys_left_right: bool,
     arrow_keys_up_down: bool,
+) -> FocusScopeHandle {
+    let nested = use_context::<FocusConflictContext>()
+        .map(|context| context.0)
+        .unwrap_or(false);
+    let focus_manager = use_memo(node_ref.clone(), |node_ref| {
+        FocusManager::new(node_ref.clone())
+    });
 
-    children: FocusManagerChildren,
+    let onkeydown = {
+        let focus_manager = (*focus_manager).clone();
+        Callback::from(move |event: KeyboardEvent| {
+            let moved = FocusScope::handle_key_down(
+                &focus_manager,
+                arrow_keys_left_right,
+                arrow_keys_up_down,
+                &event,
+            );
 
-    onfocus: Callback<FocusEvent>,
+            // The outer scopes must not move the focus again, but other keys, like Escape,
+            // still reach the handlers of the ancestors.
+            if nested && moved {
+                event.stop_propagation();
+            }
+        })
+    };
+
+    FocusScopeHandle {
+        focus_manager: (*focus_manager).clone(),
+        onkeydown,
+    }
 }
 
-// TODO: Continue implementing focus scope:  https://github.com/liferay/clay/blob/master/packages/clay-shared/src/FocusScope.tsx
-// Next: complete props (add onFocus) and continue
+/// Hook that keeps the focus inside of the element of `node_ref`. Tab and Shift+Tab move the
+/// focus like in a FocusScope, but wrap around at the boundaries instead of leaving the scope.
+/// Key downs that were already handled by a nested scope are ignored.
+#[hook]
+pub fn use_focus_trap(node_ref: &NodeRef) -> FocusScopeHandle {
+    let focus_manager = use_memo(node_ref.clone(), |node_ref| {
+        FocusManager::new(node_ref.clone())
+    });
 
-struct FocusScope;
+    let onkeydown = {
+        let focus_manager = (*focus_manager).clone();
+        Callback::from(move |event: KeyboardEvent| {
+            if event.key() != TAB || event.default_prevented() {
+                return;
+            }
 
-impl Component for FocusScope {
-    type Message = ();
+            event.prevent_default();
+            if event.shift_key() {
+                focus_manager
+                    .focus_previous()
+                    .or_else(|| focus_manager.focus_last());
+            } else {
+                focus_manager
+                    .focus_next()
+                    .or_else(|| focus_manager.focus_first());
+            }
+        })
+    };
 
-    type Properties = Props;
+    FocusScopeHandle {
+        focus_manager: (*focus_manager).clone(),
+        onkeydown,
+    }
+}
+
+/// FocusScope is a component only for controlling focus and listening for children's key down
+/// events. Focus moves between the focusable elements it contains using the arrow keys and
+/// Tab / Shift+Tab. Adapted from:
+/// <https://github.com/liferay/clay/blob/master/packages/clay-shared/src/FocusScope.tsx>
+#[function_component(FocusScope)]
+pub fn focus_scope(props: &FocusScopeProps) -> Html {
+    let FocusScopeHandle {
+        focus_manager,
+        onkeydown,
+    } = use_focus_scope(
+        &props.node_ref,
+        props.arrow_keys_left_right,
+        props.arrow_keys_up_down,
+    );
+
+    let FocusScopeProps {
+        children,
+        onfocus,
+        container_element,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
 
-    fn create(_ctx: &yew::Context<Self>) -> Self {
-        todo!()
+    html! {
+        <ContextProvider<FocusConflictContext> context={FocusConflictContext(true)}>
+            <@{container_element}
+                class={classes!(class)}
+                onfocusin={onfocus}
+                {onkeydown}
+                ref={node_ref} >
+                {children.render(focus_manager)}
+            </@>
+        </ContextProvider<FocusConflictContext>>
     }
+}
+
+impl FocusScope {
+    /// Moves the focus according to the key, and returns whether it was moved.
+    fn handle_key_down(
+        focus_manager: &FocusManager,
+        arrow_keys_left_right: bool,
+        arrow_keys_up_down: bool,
+        event: &KeyboardEvent,
+    ) -> bool {
+        let key = event.key();
+        let moved = match key.as_str() {
+            TAB if event.shift_key() => focus_manager.focus_previous(),
+            TAB => focus_manager.focus_next(),
+            ARROW_DOWN if arrow_keys_up_down => focus_manager.focus_next(),
+            ARROW_UP if arrow_keys_up_down => focus_manager.focus_previous(),
+            ARROW_RIGHT if arrow_keys_left_right => focus_manager.focus_next(),
+            ARROW_LEFT if arrow_keys_left_right => focus_manager.focus_previous(),
+            _ => return false,
+        };
 
-    fn view(&self, _ctx: &yew::Context<Self>) -> yew::Html {
-        todo!()
+        // When Tab reaches the boundaries of the scope, the browser takes over so that the
+        // focus can leave the scope. Arrow keys never scroll the page while inside a scope.
+        if moved.is_some() || key != TAB {
+            event.prevent_default();
+        }
+        moved.is_some()
     }
 }
@@ -1,13 +1,17 @@
-pub const BACKSPACE: &'static str = "Backspace";
-pub const DELETE: &'static str = "Delete";
-pub const ARROW_DOWN: &'static str = "ArrowDown";
-pub const END: &'static str = "End";
-pub const ESCAPE: &'static str = "Escape";
-pub const F2: &'static str = "F2";
-pub const HOME: &'static str = "Home";
-pub const ARROW_LEFT: &'static str = "ArrowLeft";
-pub const R: &'static str = "R";
-pub const ARROW_RIGHT: &'static str = "ArrowRight";
-pub const SPACEBAR: &'static str = " ";
-pub const TAB: &'static str = "Tab";
-pub const ARROW_UP: &'static str = "ArrowUp";
+// The key names used across the components; not every feature set uses all of them.
+#![allow(dead_code)]
+
+pub const BACKSPACE: &str = "Backspace";
+pub const DELETE: &str = "Delete";
+pub const ARROW_DOWN: &str = "ArrowDown";
+pub const END: &str = "End";
+pub const ESCAPE: &str = "Escape";
+pub const ENTER: &str = "Enter";
+pub const F2: &str = "F2";
+pub const HOME: &str = "Home";
+pub const ARROW_LEFT: &str = "ArrowLeft";
+pub const R: &str = "R";
+pub const ARROW_RIGHT: &str = "ArrowRight";
+pub const SPACEBAR: &str = " ";
+pub const TAB: &str = "Tab";
+pub const ARROW_UP: &str = "ArrowUp";
@@ -1,3 +1,3 @@
-mod focus_management;
-mod focus_scope;
-mod keys;
+pub mod focus_management;
+pub mod focus_scope;
+pub mod keys;
@@ -0,0 +1,40 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayTableBody. For details, check the docs:
+/// <https://clayui.com/docs/components/table/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayTableBodyProps {
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+/// A Yew implementation of ClayTable.Body.
+#[function_component(ClayTableBody)]
+pub fn clay_table_body(props: &ClayTableBodyProps) -> Html {
+    let ClayTableBodyProps {
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    with_html_props(
+        html! {
+            <tbody class={classes!(class)} ref={node_ref}>
+                {children}
+            </tbody>
+        },
+        props.html_props.as_ref(),
+    )
+}
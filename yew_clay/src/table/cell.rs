@@ -0,0 +1,201 @@
+use super::{CellAlign, SortDirection};
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::icon::{ClayIcon, ClaySymbol};
+use web_sys::MouseEvent;
+use yew::{
+    classes, function_component, html, Callback, Children, Classes, Html, NodeRef, Properties,
+};
+
+/// Props for ClayTableCell. For details, check the docs:
+/// <https://clayui.com/docs/components/table/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayTableCellProps {
+    /// The alignment of the content of the cell.
+    #[prop_or_default]
+    pub align: Option<CellAlign>,
+
+    /// Flag to make the cell take up the remaining width of the table.
+    #[prop_or(false)]
+    pub expanded: bool,
+
+    /// Flag to keep the cell visible while the table scrolls horizontally. Only the first cell
+    /// of a row can be sticky.
+    #[prop_or(false)]
+    pub sticky: bool,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayTableCell {
+    const TABLE_CELL_EXPAND: &'static str = "table-cell-expand";
+    const TEXT: &'static str = "text-";
+    const STICKY_LEFT: &'static str = "position: sticky; left: 0; z-index: 1;";
+
+    fn get_align_class(align: Option<CellAlign>) -> Option<String> {
+        align.map(|align| {
+            let align = align.as_ref();
+            let mut class = String::with_capacity(Self::TEXT.len() + align.len());
+            class.push_str(Self::TEXT);
+            class.push_str(align);
+            class
+        })
+    }
+
+    fn get_classes(align: Option<CellAlign>, expanded: bool) -> Classes {
+        classes!(
+            Self::get_align_class(align),
+            expanded.then_some(Self::TABLE_CELL_EXPAND)
+        )
+    }
+
+    fn get_sticky_style(sticky: bool) -> Option<&'static str> {
+        sticky.then_some(Self::STICKY_LEFT)
+    }
+}
+
+/// A Yew implementation of ClayTable.Cell.
+#[function_component(ClayTableCell)]
+pub fn clay_table_cell(props: &ClayTableCellProps) -> Html {
+    let ClayTableCellProps {
+        align,
+        expanded,
+        sticky,
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    with_html_props(
+        html! {
+            <td
+                class={classes!(class, ClayTableCell::get_classes(align, expanded))}
+                ref={node_ref}
+                style={ClayTableCell::get_sticky_style(sticky)} >
+                {children}
+            </td>
+        },
+        props.html_props.as_ref(),
+    )
+}
+
+/// Props for ClayTableCellHeader. For details, check the docs:
+/// <https://clayui.com/docs/components/table/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayTableCellHeaderProps {
+    /// The alignment of the content of the cell.
+    #[prop_or_default]
+    pub align: Option<CellAlign>,
+
+    /// Flag to make the column take up the remaining width of the table.
+    #[prop_or(false)]
+    pub expanded: bool,
+
+    /// Flag to keep the cell visible while the table scrolls horizontally. Only the first cell
+    /// of a row can be sticky.
+    #[prop_or(false)]
+    pub sticky: bool,
+
+    /// The direction the column is sorted in, if the table is sorted by this column.
+    #[prop_or_default]
+    pub sort_direction: Option<SortDirection>,
+
+    /// Callback for when the header is clicked to sort the column. The header is only
+    /// rendered as sortable when it is set.
+    #[prop_or_default]
+    pub on_sort: Option<Callback<()>>,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayTableCellHeader {
+    const TABLE_HEAD_TITLE: &'static str = "table-head-title";
+    const SORT_BUTTON: &'static str = "btn btn-unstyled";
+    const INLINE_ITEM_AFTER: &'static str = "inline-item inline-item-after";
+
+    fn get_sort_symbol(sort_direction: Option<SortDirection>) -> ClaySymbol {
+        match sort_direction {
+            Some(SortDirection::Ascending) => ClaySymbol::OrderArrowUp,
+            Some(SortDirection::Descending) => ClaySymbol::OrderArrowDown,
+            None => ClaySymbol::OrderArrow,
+        }
+    }
+}
+
+/// A Yew implementation of ClayTable.Cell rendered as a header cell. When `on_sort` is set, its
+/// content becomes a button that displays the sort direction of the column.
+#[function_component(ClayTableCellHeader)]
+pub fn clay_table_cell_header(props: &ClayTableCellHeaderProps) -> Html {
+    let ClayTableCellHeaderProps {
+        align,
+        expanded,
+        sticky,
+        sort_direction,
+        on_sort,
+        spritemap,
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let content = match on_sort {
+        Some(on_sort) => html! {
+            <button
+                class={ClayTableCellHeader::SORT_BUTTON}
+                onclick={on_sort.reform(|_: MouseEvent| ())}
+                type="button" >
+                {children}
+                <span class={ClayTableCellHeader::INLINE_ITEM_AFTER}>
+                    <ClayIcon
+                        {spritemap}
+                        symbol={ClayTableCellHeader::get_sort_symbol(sort_direction)} />
+                </span>
+            </button>
+        },
+        None => html! { {children} },
+    };
+
+    with_html_props(
+        html! {
+            <th
+                aria-sort={sort_direction.map(|direction| direction.as_ref().to_string())}
+                class={classes!(
+                    class,
+                    ClayTableCellHeader::TABLE_HEAD_TITLE,
+                    ClayTableCell::get_classes(align, expanded)
+                )}
+                ref={node_ref}
+                style={ClayTableCell::get_sticky_style(sticky)} >
+                {content}
+            </th>
+        },
+        props.html_props.as_ref(),
+    )
+}
@@ -0,0 +1,456 @@
+use super::{
+    ClayTable, ClayTableBody, ClayTableCell, ClayTableCellHeader, ClayTableHead, ClayTableRow,
+    ClayVirtualTableBody, SortDirection, TableColumn, TableSort,
+};
+use crate::form::ClayCheckbox;
+use crate::html_props::GlobalProps;
+use crate::virtual_list::RowHeight;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use yew::virtual_dom::{Key, VList};
+use yew::{
+    function_component, html, use_callback, use_memo, use_node_ref, use_state_eq, Callback,
+    Classes, Html, NodeRef, Properties,
+};
+
+/// Props for ClayDataTable.
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayDataTableProps<T>
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    /// The items displayed in the rows of the table.
+    #[prop_or_default]
+    pub items: Vec<T>,
+
+    /// The columns of the table, which describe how to render and sort the items.
+    #[prop_or_default]
+    pub columns: Vec<TableColumn<T>>,
+
+    /// The column the table is sorted by (controlled).
+    #[prop_or_default]
+    pub sort: Option<TableSort>,
+
+    /// Property to set the initial sort of the table (uncontrolled).
+    #[prop_or_default]
+    pub default_sort: Option<TableSort>,
+
+    /// Callback for when the header of a sortable column is clicked.
+    #[prop_or_default]
+    pub on_sort_change: Option<Callback<TableSort>>,
+
+    /// Flag to indicate that the items are already sorted by the parent, usually because they
+    /// are loaded again in `on_sort_change`. Otherwise the items are sorted on the client with
+    /// the comparison of the column.
+    #[prop_or(false)]
+    pub manual_sort: bool,
+
+    /// Flag to render a checkbox at the start of each row to select it.
+    #[prop_or(false)]
+    pub selectable: bool,
+
+    /// The selected items (controlled).
+    #[prop_or_default]
+    pub selected: Option<Vec<T>>,
+
+    /// Callback for when the selected items change.
+    #[prop_or_default]
+    pub on_selected_change: Option<Callback<Vec<T>>>,
+
+    /// Flag to keep the header visible while the rows scroll. Use `container_class` to limit
+    /// the height of the table.
+    #[prop_or(false)]
+    pub sticky_header: bool,
+
+    /// Flag to keep the first cell of each row visible while the table scrolls horizontally.
+    /// When the table is selectable, the first cell is the one with the checkbox.
+    #[prop_or(false)]
+    pub sticky_first_column: bool,
+
+    /// The height of the rows, to only render the ones that are visible while the table
+    /// scrolls. Use `container_class` to limit the height of the table. Without it, all of the
+    /// rows are rendered.
+    #[prop_or_default]
+    pub virtualized: Option<RowHeight>,
+
+    /// The amount of rows rendered before and after the visible ones when the table is
+    /// virtualized.
+    #[prop_or(5)]
+    pub overscan: usize,
+
+    /// Returns a key that identifies an item, so that its row is kept when the items are
+    /// sorted, added or removed. Without it, the rows are keyed by their position.
+    #[prop_or_default]
+    pub item_key: Option<Callback<T, Key>>,
+
+    /// The aria-label of the checkbox that selects all of the items.
+    #[prop_or("Select all".into())]
+    pub select_all_aria_label: String,
+
+    /// The aria-label of the checkboxes that select a row.
+    #[prop_or("Select row".into())]
+    pub select_row_aria_label: String,
+
+    /// Path to the spritemap that Icon should use when referencing symbols.
+    #[prop_or_default]
+    pub spritemap: Option<&'static str>,
+
+    /// Classes to add to the responsive container of the table.
+    #[prop_or_default]
+    pub container_class: Classes,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying table.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl<T> ClayDataTable<T>
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    /// Returns the items in the order they should be displayed.
+    fn sort_items<'a>(
+        items: &'a [T],
+        columns: &[TableColumn<T>],
+        sort: &Option<TableSort>,
+        manual_sort: bool,
+    ) -> Vec<&'a T> {
+        let mut rows: Vec<&T> = items.iter().collect();
+        if manual_sort {
+            return rows;
+        }
+
+        let sort = sort.as_ref().and_then(|sort| {
+            columns
+                .iter()
+                .find(|column| column.id == sort.column)
+                .map(|column| (column, sort.direction))
+        });
+
+        if let Some((column, direction)) = sort {
+            rows.sort_by(|a, b| {
+                let ordering = column.compare(a, b).unwrap_or(Ordering::Equal);
+                match direction {
+                    SortDirection::Ascending => ordering,
+                    SortDirection::Descending => ordering.reverse(),
+                }
+            });
+        }
+
+        rows
+    }
+
+    /// The sort after clicking the header of a column. Clicking the column the table is
+    /// already sorted by reverses the direction.
+    fn get_next_sort(sort: &Option<TableSort>, column: &str) -> TableSort {
+        let direction = match sort {
+            Some(sort) if sort.column == column => sort.direction.toggle(),
+            _ => SortDirection::Ascending,
+        };
+
+        TableSort {
+            column: column.to_string(),
+            direction,
+        }
+    }
+
+    /// Adds all of the items to the selection, or removes them from it.
+    fn select_all(selected: &[T], items: &[T], checked: bool) -> Vec<T> {
+        let mut next: Vec<T> = selected
+            .iter()
+            .filter(|item| !items.contains(item))
+            .cloned()
+            .collect();
+        if checked {
+            next.extend(items.iter().cloned());
+        }
+        next
+    }
+
+    fn select_item(selected: &[T], item: &T, checked: bool) -> Vec<T> {
+        let mut next: Vec<T> = selected
+            .iter()
+            .filter(|selected_item| *selected_item != item)
+            .cloned()
+            .collect();
+        if checked {
+            next.push(item.clone());
+        }
+        next
+    }
+
+    fn gen_row(
+        item: &T,
+        columns: &[TableColumn<T>],
+        selected: &[T],
+        set_selected: &Callback<Vec<T>>,
+        selectable: bool,
+        sticky_first_column: bool,
+        select_row_aria_label: &str,
+    ) -> Html {
+        let is_selected = selected.contains(item);
+        let select_cell = if selectable {
+            let on_change = {
+                let selected = selected.to_vec();
+                let item = item.clone();
+                set_selected.reform(move |checked: bool| {
+                    ClayDataTable::<T>::select_item(&selected, &item, checked)
+                })
+            };
+
+            html! {
+                <ClayTableCell sticky={sticky_first_column}>
+                    <ClayCheckbox
+                        aria_label={select_row_aria_label.to_string()}
+                        checked={is_selected}
+                        {on_change} />
+                </ClayTableCell>
+            }
+        } else {
+            html! {}
+        };
+
+        let cells = columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                html! {
+                    <ClayTableCell
+                        align={column.align}
+                        expanded={column.expanded}
+                        sticky={sticky_first_column && !selectable && index == 0} >
+                        {column.render(item)}
+                    </ClayTableCell>
+                }
+            })
+            .collect::<Html>();
+
+        html! {
+            <ClayTableRow active={is_selected}>
+                {select_cell}
+                {cells}
+            </ClayTableRow>
+        }
+    }
+}
+
+/// A table that renders a list of items with typed column definitions. Columns can be sorted,
+/// either on the client or through `on_sort_change`, and rows can be selected with checkboxes.
+/// It is built with ClayTable and its sub components. Long tables can be `virtualized`, so
+/// only the rows that are visible are rendered.
+#[function_component(ClayDataTable)]
+pub fn clay_data_table<T>(props: &ClayDataTableProps<T>) -> Html
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    let container_ref = use_node_ref();
+    let internal_sort = use_state_eq(|| props.default_sort.clone());
+    let internal_selected = use_state_eq(Vec::<T>::new);
+
+    let sort = props.sort.clone().or_else(|| (*internal_sort).clone());
+    let selected = props
+        .selected
+        .clone()
+        .unwrap_or_else(|| (*internal_selected).clone());
+
+    let set_sort = {
+        let controlled = props.sort.is_some();
+        let on_sort_change = props.on_sort_change.clone();
+        Callback::from(move |sort: TableSort| {
+            if let Some(on_sort_change) = &on_sort_change {
+                on_sort_change.emit(sort.clone());
+            }
+
+            if !controlled {
+                internal_sort.set(Some(sort));
+            }
+        })
+    };
+
+    let set_selected = use_callback(
+        (
+            props.on_selected_change.clone(),
+            props.selected.is_some(),
+            internal_selected.setter(),
+        ),
+        |selected: Vec<T>, (on_selected_change, controlled, set_internal_selected)| {
+            if let Some(on_selected_change) = on_selected_change {
+                on_selected_change.emit(selected.clone());
+            }
+
+            if !controlled {
+                set_internal_selected.set(selected);
+            }
+        },
+    );
+
+    // The sorted items and the function that renders their rows only change along with what
+    // they depend on, so that a virtualized body isn't rendered again on every render.
+    let rows = use_memo(
+        (
+            props.items.clone(),
+            props.columns.clone(),
+            sort.clone(),
+            props.manual_sort,
+        ),
+        |(items, columns, sort, manual_sort)| {
+            ClayDataTable::<T>::sort_items(items, columns, sort, *manual_sort)
+                .into_iter()
+                .cloned()
+                .collect::<Vec<T>>()
+        },
+    );
+    let render_row = use_memo(
+        (
+            props.columns.clone(),
+            selected.clone(),
+            set_selected.clone(),
+            props.selectable,
+            props.sticky_first_column,
+            props.select_row_aria_label.clone(),
+        ),
+        |deps| {
+            let (columns, selected, set_selected, selectable, sticky_first_column, aria_label) =
+                deps.clone();
+            Callback::from(move |item: T| {
+                ClayDataTable::<T>::gen_row(
+                    &item,
+                    &columns,
+                    &selected,
+                    &set_selected,
+                    selectable,
+                    sticky_first_column,
+                    &aria_label,
+                )
+            })
+        },
+    );
+
+    let ClayDataTableProps {
+        items,
+        columns,
+        selectable,
+        sticky_header,
+        sticky_first_column,
+        virtualized,
+        overscan,
+        item_key,
+        select_all_aria_label,
+        spritemap,
+        container_class,
+        class,
+        node_ref,
+        html_props,
+        ..
+    } = props.clone();
+
+    let select_all_header = if selectable {
+        let all_selected = !items.is_empty() && items.iter().all(|item| selected.contains(item));
+        let some_selected = items.iter().any(|item| selected.contains(item));
+        let on_change = {
+            let selected = selected.clone();
+            let items = items.clone();
+            set_selected.reform(move |checked: bool| {
+                ClayDataTable::<T>::select_all(&selected, &items, checked)
+            })
+        };
+
+        html! {
+            <ClayTableCellHeader sticky={sticky_first_column}>
+                <ClayCheckbox
+                    aria_label={select_all_aria_label}
+                    checked={all_selected}
+                    indeterminate={some_selected && !all_selected}
+                    {on_change} />
+            </ClayTableCellHeader>
+        }
+    } else {
+        html! {}
+    };
+
+    let headers = columns
+        .iter()
+        .enumerate()
+        .map(|(index, column)| {
+            let sort_direction = sort
+                .as_ref()
+                .filter(|sort| sort.column == column.id)
+                .map(|sort| sort.direction);
+            let on_sort = column.is_sortable().then(|| {
+                let sort = sort.clone();
+                let id = column.id.clone();
+                set_sort.reform(move |_: ()| ClayDataTable::<T>::get_next_sort(&sort, &id))
+            });
+
+            html! {
+                <ClayTableCellHeader
+                    align={column.align}
+                    expanded={column.expanded}
+                    {on_sort}
+                    {sort_direction}
+                    {spritemap}
+                    sticky={sticky_first_column && !selectable && index == 0} >
+                    {column.header.clone()}
+                </ClayTableCellHeader>
+            }
+        })
+        .collect::<Html>();
+
+    let body = match virtualized {
+        Some(row_height) => {
+            let column_count = columns.len() + usize::from(selectable);
+            let items = (*rows).clone();
+            let render_row = (*render_row).clone();
+
+            html! {
+                <ClayVirtualTableBody<T>
+                    {column_count}
+                    {items}
+                    {item_key}
+                    {overscan}
+                    {render_row}
+                    {row_height}
+                    scroll_ref={container_ref.clone()} />
+            }
+        }
+        None => {
+            let rows = rows
+                .iter()
+                .map(|item| {
+                    let row = render_row.emit(item.clone());
+                    match &item_key {
+                        Some(item_key) => {
+                            VList::with_children(vec![row], Some(item_key.emit(item.clone())))
+                                .into()
+                        }
+                        None => row,
+                    }
+                })
+                .collect::<Html>();
+
+            html! {
+                <ClayTableBody>
+                    {rows}
+                </ClayTableBody>
+            }
+        }
+    };
+
+    html! {
+        <ClayTable {class} {container_class} {container_ref} {html_props} {node_ref}>
+            <ClayTableHead sticky={sticky_header}>
+                <ClayTableRow>
+                    {select_all_header}
+                    {headers}
+                </ClayTableRow>
+            </ClayTableHead>
+            {body}
+        </ClayTable>
+    }
+}
@@ -0,0 +1,53 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayTableHead. For details, check the docs:
+/// <https://clayui.com/docs/components/table/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayTableHeadProps {
+    /// Flag to keep the header visible while the rows scroll. The header sticks to the top of
+    /// the nearest scrolling container, which is the responsive container of the ClayTable.
+    #[prop_or(false)]
+    pub sticky: bool,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayTableHead {
+    const STICKY_TOP: &'static str = "position: sticky; top: 0; z-index: 2;";
+}
+
+/// A Yew implementation of ClayTable.Head.
+#[function_component(ClayTableHead)]
+pub fn clay_table_head(props: &ClayTableHeadProps) -> Html {
+    let ClayTableHeadProps {
+        sticky,
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    with_html_props(
+        html! {
+            <thead
+                class={classes!(class)}
+                ref={node_ref}
+                style={sticky.then_some(ClayTableHead::STICKY_TOP)} >
+                {children}
+            </thead>
+        },
+        props.html_props.as_ref(),
+    )
+}
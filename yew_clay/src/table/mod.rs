@@ -0,0 +1,17 @@
+mod body;
+mod cell;
+mod data_table;
+mod head;
+mod row;
+mod table;
+mod types;
+mod virtual_body;
+
+pub use body::*;
+pub use cell::*;
+pub use data_table::*;
+pub use head::*;
+pub use row::*;
+pub use table::*;
+pub use types::*;
+pub use virtual_body::*;
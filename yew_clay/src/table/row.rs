@@ -0,0 +1,61 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayTableRow. For details, check the docs:
+/// <https://clayui.com/docs/components/table/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayTableRowProps {
+    /// Flag to highlight the row, for example when it is selected.
+    #[prop_or(false)]
+    pub active: bool,
+
+    /// Flag to display the row as a divider between groups of rows.
+    #[prop_or(false)]
+    pub divider: bool,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayTableRow {
+    const TABLE_ACTIVE: &'static str = "table-active";
+    const TABLE_DIVIDER: &'static str = "table-divider";
+}
+
+/// A Yew implementation of ClayTable.Row.
+#[function_component(ClayTableRow)]
+pub fn clay_table_row(props: &ClayTableRowProps) -> Html {
+    let ClayTableRowProps {
+        active,
+        divider,
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    with_html_props(
+        html! {
+            <tr
+                class={classes!(
+                    class,
+                    active.then_some(ClayTableRow::TABLE_ACTIVE),
+                    divider.then_some(ClayTableRow::TABLE_DIVIDER)
+                )}
+                ref={node_ref} >
+                {children}
+            </tr>
+        },
+        props.html_props.as_ref(),
+    )
+}
@@ -0,0 +1,123 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{classes, function_component, html, Children, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayTable. For details, check the docs:
+/// <https://clayui.com/docs/components/table/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayTableProps {
+    /// Flag to remove the borders between the rows.
+    #[prop_or(false)]
+    pub borderless: bool,
+
+    /// Flag to add borders between the columns.
+    #[prop_or(false)]
+    pub bordered_columns: bool,
+
+    /// Flag to keep the content of the header cells in a single line.
+    #[prop_or(false)]
+    pub heading_no_wrap: bool,
+
+    /// Flag to highlight the row under the mouse.
+    #[prop_or(true)]
+    pub hover: bool,
+
+    /// Flag to keep the content of all the cells in a single line.
+    #[prop_or(false)]
+    pub no_wrap: bool,
+
+    /// Flag to wrap the table in a container that scrolls horizontally when the table does not
+    /// fit.
+    #[prop_or(true)]
+    pub responsive: bool,
+
+    /// Classes to add to the responsive container, like a max-height for tables with a sticky
+    /// header.
+    #[prop_or_default]
+    pub container_class: Classes,
+
+    /// NodeRef for the responsive container, which is the element that scrolls when the
+    /// table is virtualized.
+    #[prop_or_default]
+    pub container_ref: NodeRef,
+
+    /// Flag to alternate the background of the rows.
+    #[prop_or(false)]
+    pub striped: bool,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying table.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayTable {
+    const TABLE: &'static str = "table table-autofit table-list";
+    const TABLE_BORDERED: &'static str = "table-bordered";
+    const TABLE_BORDERLESS: &'static str = "table-borderless";
+    const TABLE_HEADING_NOWRAP: &'static str = "table-heading-nowrap";
+    const TABLE_HOVER: &'static str = "table-hover";
+    const TABLE_NOWRAP: &'static str = "table-nowrap";
+    const TABLE_RESPONSIVE: &'static str = "table-responsive";
+    const TABLE_STRIPED: &'static str = "table-striped";
+}
+
+/// A Yew implementation of ClayTable. For more info about ClayTable, check the documentation:
+/// <https://clayui.com/docs/components/table.html>
+#[function_component(ClayTable)]
+pub fn clay_table(props: &ClayTableProps) -> Html {
+    let ClayTableProps {
+        borderless,
+        bordered_columns,
+        heading_no_wrap,
+        hover,
+        no_wrap,
+        responsive,
+        container_class,
+        container_ref,
+        striped,
+        children,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let table = with_html_props(
+        html! {
+            <table
+                class={classes!(
+                    class,
+                    ClayTable::TABLE,
+                    borderless.then_some(ClayTable::TABLE_BORDERLESS),
+                    bordered_columns.then_some(ClayTable::TABLE_BORDERED),
+                    heading_no_wrap.then_some(ClayTable::TABLE_HEADING_NOWRAP),
+                    hover.then_some(ClayTable::TABLE_HOVER),
+                    no_wrap.then_some(ClayTable::TABLE_NOWRAP),
+                    striped.then_some(ClayTable::TABLE_STRIPED)
+                )}
+                ref={node_ref} >
+                {children}
+            </table>
+        },
+        props.html_props.as_ref(),
+    );
+
+    if responsive {
+        html! {
+            <div
+                class={classes!(container_class, ClayTable::TABLE_RESPONSIVE)}
+                ref={container_ref} >
+                {table}
+            </div>
+        }
+    } else {
+        table
+    }
+}
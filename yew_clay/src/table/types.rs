@@ -0,0 +1,160 @@
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::rc::Rc;
+use strum::AsRefStr;
+use yew::Html;
+
+/// The alignment of the content of a table cell.
+#[derive(AsRefStr, Debug, PartialEq, Clone, Copy)]
+#[strum(serialize_all = "lowercase")]
+pub enum CellAlign {
+    Left,
+    Center,
+    Right,
+}
+
+/// The direction in which a column is sorted. It is also used as the value of aria-sort.
+#[derive(AsRefStr, Debug, PartialEq, Clone, Copy, Default)]
+#[strum(serialize_all = "lowercase")]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    pub fn toggle(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+/// The column a ClayDataTable is sorted by, identified by its id.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TableSort {
+    pub column: String,
+    pub direction: SortDirection,
+}
+
+/// Describes a column of a ClayDataTable: its header and how to render and sort the items.
+///
+/// ```ignore
+/// let columns = vec![
+///     TableColumn::new("name", "Name", |user: &User| html! { &user.name })
+///         .sort_by(|a: &User, b: &User| a.name.cmp(&b.name))
+///         .expanded(),
+///     TableColumn::new("age", "Age", |user: &User| html! { user.age })
+///         .with_align(CellAlign::Right),
+/// ];
+/// ```
+pub struct TableColumn<T> {
+    /// Identifies the column in a TableSort.
+    pub id: String,
+    /// The content of the header cell.
+    pub header: String,
+    pub align: Option<CellAlign>,
+    /// Flag to make the column take up the remaining width of the table.
+    pub expanded: bool,
+    sortable: bool,
+    render: Rc<dyn Fn(&T) -> Html>,
+    compare: Option<Rc<CompareFn<T>>>,
+}
+
+type CompareFn<T> = dyn Fn(&T, &T) -> Ordering;
+
+impl<T> TableColumn<T> {
+    pub fn new<F: Fn(&T) -> Html + 'static>(
+        id: impl Into<String>,
+        header: impl Into<String>,
+        render: F,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            header: header.into(),
+            align: None,
+            expanded: false,
+            sortable: false,
+            render: Rc::new(render),
+            compare: None,
+        }
+    }
+
+    /// Makes the column sortable without a way to compare the items. The items are expected to
+    /// be sorted by the parent when `on_sort_change` is called.
+    pub fn sortable(mut self) -> Self {
+        self.sortable = true;
+        self
+    }
+
+    /// Makes the column sortable on the client, comparing the items with `compare`.
+    pub fn sort_by<F: Fn(&T, &T) -> Ordering + 'static>(mut self, compare: F) -> Self {
+        self.sortable = true;
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    pub fn with_align(mut self, align: CellAlign) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    pub fn expanded(mut self) -> Self {
+        self.expanded = true;
+        self
+    }
+
+    pub fn is_sortable(&self) -> bool {
+        self.sortable
+    }
+
+    pub fn render(&self, item: &T) -> Html {
+        (self.render)(item)
+    }
+
+    /// Compares two items, if the column can be sorted on the client.
+    pub fn compare(&self, a: &T, b: &T) -> Option<Ordering> {
+        self.compare.as_ref().map(|compare| compare(a, b))
+    }
+}
+
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            id: self.id.clone(),
+            header: self.header.clone(),
+            align: self.align,
+            expanded: self.expanded,
+            sortable: self.sortable,
+            render: Rc::clone(&self.render),
+            compare: self.compare.clone(),
+        }
+    }
+}
+
+/// Columns are compared by their id, header, alignment and flags, but not by their functions,
+/// so that columns built on every render don't render all of the rows again. The rows are
+/// rendered again when the items or any of those fields change.
+impl<T> PartialEq for TableColumn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && self.header == other.header
+            && self.align == other.align
+            && self.expanded == other.expanded
+            && self.sortable == other.sortable
+            && self.compare.is_some() == other.compare.is_some()
+    }
+}
+
+impl<T> Debug for TableColumn<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TableColumn")
+            .field("id", &self.id)
+            .field("header", &self.header)
+            .field("align", &self.align)
+            .field("expanded", &self.expanded)
+            .field("sortable", &self.sortable)
+            .finish()
+    }
+}
@@ -0,0 +1,120 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::virtual_list::{use_virtual_window, RowHeight, VirtualSlot};
+use std::fmt::Debug;
+use yew::virtual_dom::{Key, VList};
+use yew::{classes, function_component, html, Callback, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayVirtualTableBody.
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayVirtualTableBodyProps<T>
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    /// The items of the rows, in the order they are displayed.
+    #[prop_or_default]
+    pub items: Vec<T>,
+
+    /// Renders the row of an item, usually a ClayTableRow.
+    pub render_row: Callback<T, Html>,
+
+    /// Returns a key that identifies an item, so that its row is kept when the items are
+    /// reordered, added or removed. Without it, the rows are keyed by their index.
+    #[prop_or_default]
+    pub item_key: Option<Callback<T, Key>>,
+
+    /// The height of the rows. With `RowHeight::Estimated`, the rows are measured once they
+    /// are rendered.
+    pub row_height: RowHeight,
+
+    /// The amount of rows rendered before and after the visible ones.
+    #[prop_or(5)]
+    pub overscan: usize,
+
+    /// NodeRef of the element that scrolls, usually the `container_ref` of ClayTable.
+    pub scroll_ref: NodeRef,
+
+    /// The amount of columns of the table, so the empty rows span all of them.
+    #[prop_or(1)]
+    pub column_count: usize,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl<T> ClayVirtualTableBody<T>
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    const SPACER: &'static str = "spacer-";
+
+    fn gen_spacer(position: usize, height: f64, column_count: usize) -> Html {
+        html! {
+            <tr aria-hidden="true" key={format!("{}{}", Self::SPACER, position)}>
+                <td
+                    colspan={column_count.to_string()}
+                    style={format!("height: {}px; padding: 0; border: 0;", height)} />
+            </tr>
+        }
+    }
+}
+
+/// A ClayTableBody that only renders the rows that are visible in the scroll container of the
+/// table. The rest of the rows are replaced by empty rows of the same height.
+#[function_component(ClayVirtualTableBody)]
+pub fn clay_virtual_table_body<T>(props: &ClayVirtualTableBodyProps<T>) -> Html
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    let slots = use_virtual_window(
+        &props.scroll_ref,
+        &props.node_ref,
+        props.items.len(),
+        props.row_height,
+        props.overscan,
+    );
+
+    let ClayVirtualTableBodyProps {
+        items,
+        render_row,
+        item_key,
+        column_count,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let rows = slots
+        .into_iter()
+        .enumerate()
+        .map(|(position, slot)| match slot {
+            // The row is wrapped in a keyed fragment, since a tbody can not contain other
+            // elements.
+            VirtualSlot::Item(index) => {
+                let item = items[index].clone();
+                let key = item_key
+                    .as_ref()
+                    .map_or_else(|| Key::from(index), |item_key| item_key.emit(item.clone()));
+                VList::with_children(vec![render_row.emit(item)], Some(key)).into()
+            }
+            VirtualSlot::Spacer(height) => {
+                ClayVirtualTableBody::<T>::gen_spacer(position, height, column_count)
+            }
+        })
+        .collect::<Html>();
+
+    with_html_props(
+        html! {
+            <tbody class={classes!(class)} ref={node_ref}>
+                {rows}
+            </tbody>
+        },
+        props.html_props.as_ref(),
+    )
+}
@@ -0,0 +1,73 @@
+use super::{get_tab_id, get_tab_panel_id, ClayTabPane, DEFAULT_TABS_ID};
+use crate::html_props::{with_html_props, GlobalProps};
+use std::rc::Rc;
+use yew::html::ChildrenWithProps;
+use yew::{classes, function_component, html, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayTabsContent. For details, check the docs:
+/// <https://clayui.com/docs/components/tabs/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayTabsContentProps {
+    /// The index of the panel to display, the same one as the selected tab of ClayTabs.
+    #[prop_or(0)]
+    pub active_index: usize,
+
+    /// Prefix of the ids of the tabs and their panels, the same one as the id of ClayTabs.
+    /// Defaults to `clay-tabs`, like the id of ClayTabs.
+    #[prop_or_default]
+    pub id: Option<String>,
+
+    /// Flag to only render the content of each panel once it is displayed for the first time.
+    #[prop_or(false)]
+    pub lazy: bool,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: ChildrenWithProps<ClayTabPane>,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayTabsContent {
+    const TAB_CONTENT: &'static str = "tab-content";
+}
+
+/// A Yew implementation of ClayTabs.Content. It displays the ClayTabPane at `active_index`.
+#[function_component(ClayTabsContent)]
+pub fn clay_tabs_content(props: &ClayTabsContentProps) -> Html {
+    let ClayTabsContentProps {
+        active_index,
+        id,
+        lazy,
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let id = id.unwrap_or_else(|| DEFAULT_TABS_ID.into());
+    let panes = children.iter().enumerate().map(|(index, mut pane)| {
+        let pane_props = Rc::make_mut(&mut pane.props);
+        pane_props.active = index == active_index;
+        pane_props.lazy |= lazy;
+        pane_props.id = Some(get_tab_panel_id(&id, index));
+        pane_props.aria_labelledby = Some(get_tab_id(&id, index));
+        pane
+    });
+
+    with_html_props(
+        html! {
+            <div class={classes!(class, ClayTabsContent::TAB_CONTENT)} ref={node_ref}>
+                {for panes}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
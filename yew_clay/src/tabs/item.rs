@@ -0,0 +1,101 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use web_sys::MouseEvent;
+use yew::{
+    classes, function_component, html, use_node_ref, Callback, Children, Classes, Html, NodeRef,
+    Properties,
+};
+
+/// Props for ClayTabsItem. For details, check the docs:
+/// <https://clayui.com/docs/components/tabs/api.html>
+///
+/// `active`, `id` and `aria_controls` are set by the parent ClayTabs.
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayTabsItemProps {
+    /// Flag to indicate if the tab is the selected one.
+    #[prop_or(false)]
+    pub active: bool,
+
+    /// Flag to indicate if the tab can not be selected.
+    #[prop_or(false)]
+    pub disabled: bool,
+
+    /// Callback for when the tab is clicked.
+    #[prop_or_default]
+    pub on_click: Option<Callback<MouseEvent>>,
+
+    /// The id of the tab.
+    #[prop_or_default]
+    pub id: Option<String>,
+
+    /// The id of the panel that the tab displays.
+    #[prop_or_default]
+    pub aria_controls: Option<String>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    /// NodeRef for the button of the tab.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the list item.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayTabsItem {
+    const ACTIVE: &'static str = "active";
+    const NAV_ITEM: &'static str = "nav-item";
+    const NAV_LINK: &'static str = "nav-link";
+    const BTN: &'static str = "btn btn-unstyled";
+}
+
+/// A Yew implementation of ClayTabs.Item. Only the selected tab can be reached with Tab, the
+/// others are focused with the arrow keys.
+#[function_component(ClayTabsItem)]
+pub fn clay_tabs_item(props: &ClayTabsItemProps) -> Html {
+    let li_ref = use_node_ref();
+
+    let ClayTabsItemProps {
+        active,
+        disabled,
+        on_click,
+        id,
+        aria_controls,
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let tabindex = if active { "0" } else { "-1" };
+
+    with_html_props(
+        html! {
+            <li class={ClayTabsItem::NAV_ITEM} ref={li_ref} role="none">
+                <button
+                    aria-controls={aria_controls}
+                    aria-selected={active.to_string()}
+                    class={classes!(
+                        class,
+                        ClayTabsItem::BTN,
+                        ClayTabsItem::NAV_LINK,
+                        active.then_some(ClayTabsItem::ACTIVE)
+                    )}
+                    {disabled}
+                    {id}
+                    onclick={on_click}
+                    ref={node_ref}
+                    role="tab"
+                    {tabindex}
+                    type="button" >
+                    {children}
+                </button>
+            </li>
+        },
+        props.html_props.as_ref(),
+    )
+}
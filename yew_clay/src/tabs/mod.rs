@@ -0,0 +1,11 @@
+mod content;
+mod item;
+mod tab_pane;
+mod tabs;
+mod types;
+
+pub use content::*;
+pub use item::*;
+pub use tab_pane::*;
+pub use tabs::*;
+pub use types::*;
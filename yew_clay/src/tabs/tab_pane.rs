@@ -0,0 +1,96 @@
+use crate::html_props::{with_html_props, GlobalProps};
+use yew::{
+    classes, function_component, html, use_mut_ref, Children, Classes, Html, NodeRef, Properties,
+};
+
+/// Props for ClayTabPane. For details, check the docs:
+/// <https://clayui.com/docs/components/tabs/api.html>
+///
+/// `active`, `id`, `aria_labelledby` and `lazy` are set by the parent ClayTabsContent.
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayTabPaneProps {
+    /// Flag to indicate if the panel is the one of the selected tab.
+    #[prop_or(false)]
+    pub active: bool,
+
+    /// Flag to only render the children once the panel has been displayed.
+    #[prop_or(false)]
+    pub lazy: bool,
+
+    /// The id of the panel.
+    #[prop_or_default]
+    pub id: Option<String>,
+
+    /// The id of the tab that displays the panel.
+    #[prop_or_default]
+    pub aria_labelledby: Option<String>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: Children,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayTabPane {
+    const ACTIVE: &'static str = "active";
+    const FADE: &'static str = "fade";
+    const SHOW: &'static str = "show";
+    const TAB_PANE: &'static str = "tab-pane";
+}
+
+/// A Yew implementation of ClayTabs.TabPane.
+#[function_component(ClayTabPane)]
+pub fn clay_tab_pane(props: &ClayTabPaneProps) -> Html {
+    // Once a lazy panel has been displayed, its children are kept so their state is not lost
+    // when another tab is selected.
+    let shown = use_mut_ref(|| false);
+
+    let ClayTabPaneProps {
+        active,
+        lazy,
+        id,
+        aria_labelledby,
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    if active {
+        *shown.borrow_mut() = true;
+    }
+    let content = if !lazy || *shown.borrow() {
+        html! { {children} }
+    } else {
+        html! {}
+    };
+
+    with_html_props(
+        html! {
+            <div
+                aria-labelledby={aria_labelledby}
+                class={classes!(
+                    class,
+                    ClayTabPane::TAB_PANE,
+                    ClayTabPane::FADE,
+                    active.then_some(ClayTabPane::ACTIVE),
+                    active.then_some(ClayTabPane::SHOW)
+                )}
+                {id}
+                ref={node_ref}
+                role="tabpanel"
+                tabindex="0" >
+                {content}
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
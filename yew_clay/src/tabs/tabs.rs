@@ -0,0 +1,191 @@
+use super::{
+    get_tab_id, get_tab_panel_id, ClayTabsItem, TabsActivation, TabsDisplayType, DEFAULT_TABS_ID,
+};
+use crate::html_props::{with_html_props, GlobalProps};
+use crate::shared::keys::{ARROW_LEFT, ARROW_RIGHT, END, HOME};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement, KeyboardEvent, MouseEvent};
+use yew::html::ChildrenWithProps;
+use yew::{classes, function_component, html, Callback, Classes, Html, NodeRef, Properties};
+
+/// Props for ClayTabs. For details, check the docs:
+/// <https://clayui.com/docs/components/tabs/api.html>
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayTabsProps {
+    /// The index of the selected tab. Pass the same index to ClayTabsContent.
+    #[prop_or(0)]
+    pub active_index: usize,
+
+    /// Callback for when a different tab is selected.
+    #[prop_or_default]
+    pub on_active_change: Option<Callback<usize>>,
+
+    /// Decides whether the tabs are selected as soon as they are focused with the keyboard.
+    #[prop_or_default]
+    pub activation: TabsActivation,
+
+    /// Determines the style of the tabs.
+    #[prop_or_default]
+    pub display_type: TabsDisplayType,
+
+    /// Flag to make the tabs take all of the available width.
+    #[prop_or(false)]
+    pub justified: bool,
+
+    /// Flag to use the modern style of the tabs, which underlines the selected one.
+    #[prop_or(false)]
+    pub modern: bool,
+
+    /// Prefix of the ids of the tabs and their panels. Pass the same id to ClayTabsContent, so
+    /// each tab is linked to its panel through `aria-controls`. Defaults to `clay-tabs`, so it
+    /// has to be set when a page displays more than one ClayTabs.
+    #[prop_or_default]
+    pub id: Option<String>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    #[prop_or_default]
+    pub children: ChildrenWithProps<ClayTabsItem>,
+
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the underlying component.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl ClayTabs {
+    const NAV: &'static str = "nav";
+    const NAV_JUSTIFIED: &'static str = "nav-justified";
+    const NAV_TABS: &'static str = "nav-tabs";
+    const NAV_UNDERLINE: &'static str = "nav-underline";
+    const TAB_SELECTOR: &'static str = "[role='tab']:not([disabled])";
+
+    fn get_display_class(display_type: TabsDisplayType, modern: bool) -> &'static str {
+        match (display_type, modern) {
+            (TabsDisplayType::Basic, false) => Self::NAV_TABS,
+            _ => Self::NAV_UNDERLINE,
+        }
+    }
+
+    /// The tabs that can be focused, in order.
+    fn get_tabs(node_ref: &NodeRef) -> Vec<HtmlElement> {
+        let tabs = match node_ref
+            .cast::<Element>()
+            .and_then(|list| list.query_selector_all(Self::TAB_SELECTOR).ok())
+        {
+            Some(tabs) => tabs,
+            None => return Vec::new(),
+        };
+
+        (0..tabs.length())
+            .filter_map(|index| tabs.item(index))
+            .filter_map(|tab| tab.dyn_into::<HtmlElement>().ok())
+            .collect()
+    }
+
+    /// Moves the focus between the tabs with the arrow keys, Home and End. With automatic
+    /// activation, the focused tab is also selected.
+    fn get_key_down_handler(
+        node_ref: NodeRef,
+        activation: TabsActivation,
+    ) -> Callback<KeyboardEvent> {
+        Callback::from(move |event: KeyboardEvent| {
+            let tabs = Self::get_tabs(&node_ref);
+            if tabs.is_empty() {
+                return;
+            }
+
+            let last = tabs.len() - 1;
+            let current = event
+                .target()
+                .and_then(|target| target.dyn_into::<HtmlElement>().ok())
+                .and_then(|target| tabs.iter().position(|tab| *tab == target));
+
+            let next = match (event.key().as_str(), current) {
+                (ARROW_LEFT, Some(0)) => last,
+                (ARROW_LEFT, Some(current)) => current - 1,
+                (ARROW_RIGHT, Some(current)) if current < last => current + 1,
+                (ARROW_RIGHT, Some(_)) => 0,
+                (HOME, Some(_)) => 0,
+                (END, Some(_)) => last,
+                _ => return,
+            };
+            event.prevent_default();
+
+            let tab = &tabs[next];
+            if let Err(err) = tab.focus() {
+                gloo_console::error!(err);
+            }
+            if activation == TabsActivation::Automatic {
+                tab.click();
+            }
+        })
+    }
+}
+
+/// A Yew implementation of ClayTabs. For more info about ClayTabs, check the documentation:
+/// <https://clayui.com/docs/components/tabs.html>
+///
+/// The selected tab is controlled through `active_index` and `on_active_change`. The panels
+/// are rendered separately with ClayTabsContent, which takes the same `active_index`.
+#[function_component(ClayTabs)]
+pub fn clay_tabs(props: &ClayTabsProps) -> Html {
+    let ClayTabsProps {
+        active_index,
+        on_active_change,
+        activation,
+        display_type,
+        justified,
+        modern,
+        id,
+        class,
+        children,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let id = id.unwrap_or_else(|| DEFAULT_TABS_ID.into());
+    let onkeydown = ClayTabs::get_key_down_handler(node_ref.clone(), activation);
+
+    let items = children.iter().enumerate().map(|(index, mut item)| {
+        let item_props = Rc::make_mut(&mut item.props);
+        item_props.active = index == active_index;
+        item_props.id = Some(get_tab_id(&id, index));
+        item_props.aria_controls = Some(get_tab_panel_id(&id, index));
+
+        let on_click = item_props.on_click.clone();
+        let on_active_change = on_active_change.clone();
+        item_props.on_click = Some(Callback::from(move |event: MouseEvent| {
+            if let Some(on_click) = &on_click {
+                on_click.emit(event);
+            }
+            if let Some(on_active_change) = &on_active_change {
+                on_active_change.emit(index);
+            }
+        }));
+
+        item
+    });
+
+    with_html_props(
+        html! {
+            <ul
+                class={classes!(
+                    class,
+                    ClayTabs::NAV,
+                    ClayTabs::get_display_class(display_type, modern),
+                    justified.then_some(ClayTabs::NAV_JUSTIFIED)
+                )}
+                {onkeydown}
+                ref={node_ref}
+                role="tablist" >
+                {for items}
+            </ul>
+        },
+        props.html_props.as_ref(),
+    )
+}
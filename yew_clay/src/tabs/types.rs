@@ -0,0 +1,31 @@
+/// The style of the tabs.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum TabsDisplayType {
+    #[default]
+    Basic,
+    Underline,
+}
+
+/// Decides when a tab is selected while moving through the tabs with the keyboard.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum TabsActivation {
+    /// The tab that receives the focus is selected right away.
+    Automatic,
+    /// The focused tab is only selected with Enter or Space.
+    #[default]
+    Manual,
+}
+
+/// The prefix of the ids when ClayTabs and ClayTabsContent are not given an `id`. Both use the
+/// same one, so that the tabs are still linked to their panels.
+pub(crate) const DEFAULT_TABS_ID: &str = "clay-tabs";
+
+/// The id of the tab at `index`, which labels its panel.
+pub(crate) fn get_tab_id(id: &str, index: usize) -> String {
+    format!("{}-tab-{}", id, index)
+}
+
+/// The id of the panel at `index`, which is controlled by its tab.
+pub(crate) fn get_tab_panel_id(id: &str, index: usize) -> String {
+    format!("{}-tabpanel-{}", id, index)
+}
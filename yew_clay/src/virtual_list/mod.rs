@@ -0,0 +1,5 @@
+mod virtual_list;
+mod window;
+
+pub use virtual_list::*;
+pub use window::*;
@@ -0,0 +1,132 @@
+use super::{use_virtual_window, RowHeight, VirtualSlot};
+use crate::html_props::{with_html_props, GlobalProps};
+use std::fmt::Debug;
+use yew::virtual_dom::Key;
+use yew::{
+    classes, function_component, html, use_node_ref, Callback, Classes, Html, NodeRef, Properties,
+};
+
+/// Props for ClayVirtualList.
+#[derive(Debug, Properties, PartialEq, Clone)]
+pub struct ClayVirtualListProps<T>
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    /// The items of the list.
+    #[prop_or_default]
+    pub items: Vec<T>,
+
+    /// Renders an item of the list.
+    pub render_item: Callback<T, Html>,
+
+    /// Returns a key that identifies an item, so that its element is kept when the items are
+    /// reordered, added or removed. Without it, the items are keyed by their index.
+    #[prop_or_default]
+    pub item_key: Option<Callback<T, Key>>,
+
+    /// The height of the rows. With `RowHeight::Estimated`, the rows are measured once they
+    /// are rendered.
+    pub row_height: RowHeight,
+
+    /// The amount of items rendered before and after the visible ones, so that they are
+    /// ready when the list scrolls.
+    #[prop_or(5)]
+    pub overscan: usize,
+
+    /// The height of the list, as a CSS length. The list scrolls when its items do not fit.
+    #[prop_or("100%".into())]
+    pub height: String,
+
+    /// The role of the element that contains the items.
+    #[prop_or_default]
+    pub role: Option<String>,
+
+    #[prop_or_default]
+    pub class: Classes,
+
+    /// NodeRef for the scroll container of the list.
+    #[prop_or_default]
+    pub node_ref: NodeRef,
+
+    /// A catchall prop to pass down anything not specified here to the scroll container.
+    #[prop_or_default]
+    pub html_props: Option<GlobalProps>,
+}
+
+impl<T> ClayVirtualList<T>
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    const SPACER: &'static str = "spacer-";
+
+    fn gen_spacer(position: usize, height: f64) -> Html {
+        html! {
+            <div
+                aria-hidden="true"
+                key={format!("{}{}", Self::SPACER, position)}
+                style={format!("height: {}px;", height)} />
+        }
+    }
+}
+
+/// Renders a long list of items, but only creates the elements of the ones that are visible
+/// in the scroll container. The rest of the list is replaced by empty space, so the scrollbar
+/// behaves as if all of the items were rendered.
+#[function_component(ClayVirtualList)]
+pub fn clay_virtual_list<T>(props: &ClayVirtualListProps<T>) -> Html
+where
+    T: Debug + PartialEq + Clone + 'static,
+{
+    let items_ref = use_node_ref();
+    let slots = use_virtual_window(
+        &props.node_ref,
+        &items_ref,
+        props.items.len(),
+        props.row_height,
+        props.overscan,
+    );
+
+    let ClayVirtualListProps {
+        items,
+        render_item,
+        item_key,
+        height,
+        role,
+        class,
+        node_ref,
+        ..
+    } = props.clone();
+
+    let children = slots
+        .into_iter()
+        .enumerate()
+        .map(|(position, slot)| match slot {
+            VirtualSlot::Item(index) => {
+                let item = items[index].clone();
+                let key = item_key
+                    .as_ref()
+                    .map_or_else(|| Key::from(index), |item_key| item_key.emit(item.clone()));
+                html! {
+                    <div {key}>
+                        {render_item.emit(item)}
+                    </div>
+                }
+            }
+            VirtualSlot::Spacer(height) => ClayVirtualList::<T>::gen_spacer(position, height),
+        })
+        .collect::<Html>();
+
+    with_html_props(
+        html! {
+            <div
+                class={classes!(class)}
+                ref={node_ref}
+                style={format!("height: {}; overflow-y: auto;", height)} >
+                <div ref={items_ref} {role}>
+                    {children}
+                </div>
+            </div>
+        },
+        props.html_props.as_ref(),
+    )
+}
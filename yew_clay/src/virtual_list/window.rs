@@ -0,0 +1,391 @@
+use gloo_events::EventListener;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, FocusEvent, HtmlElement, Node};
+use yew::{
+    hook, use_effect, use_effect_with, use_force_update, use_mut_ref, use_state_eq, NodeRef,
+    UseStateHandle,
+};
+
+/// The height of the rows of a virtualized list, in pixels.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RowHeight {
+    /// All of the rows have the same height.
+    Fixed(f64),
+    /// The rows have different heights. They are measured once they are rendered, and the
+    /// estimate is used for the rows that have not been rendered yet.
+    Estimated(f64),
+}
+
+/// One of the elements to render in a virtualized list, in order.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum VirtualSlot {
+    /// The item at this index of the list.
+    Item(usize),
+    /// An empty element of this height that takes the place of the items that are not
+    /// rendered.
+    Spacer(f64),
+}
+
+/// The part of the scroll container that is visible, relative to the top of the items.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+struct Viewport {
+    start: f64,
+    end: f64,
+}
+
+/// Hook that decides which items of a long list to render. Only the items that are visible in
+/// the element of `scroll_ref`, plus `overscan` items on each side, are returned as slots; the
+/// rest are replaced by spacers of the same height.
+///
+/// The elements of the slots have to be rendered, in order, as the only children of the
+/// element of `items_ref`. Items are rendered with their index as key, so the element of an
+/// item is kept while it stays visible. The item that has the focus is always rendered, so the
+/// focus is not lost when it scrolls out of view.
+#[hook]
+pub fn use_virtual_window(
+    scroll_ref: &NodeRef,
+    items_ref: &NodeRef,
+    item_count: usize,
+    row_height: RowHeight,
+    overscan: usize,
+) -> Vec<VirtualSlot> {
+    let viewport = use_state_eq(Viewport::default);
+    let focused = use_state_eq(|| None::<usize>);
+    let measured = use_mut_ref(HashMap::<usize, f64>::new);
+    let slots_ref = use_mut_ref(Vec::<VirtualSlot>::new);
+    let force_update = use_force_update();
+
+    let offsets = get_offsets(item_count, row_height, &measured.borrow());
+    let range = get_visible_range(&offsets, *viewport, overscan);
+    let focused_index = (*focused).filter(|index| *index < item_count);
+    let slots = get_slots(&offsets, range, focused_index);
+    *slots_ref.borrow_mut() = slots.clone();
+
+    {
+        let scroll_ref = scroll_ref.clone();
+        let items_ref = items_ref.clone();
+        let slots_ref = slots_ref.clone();
+        let viewport = viewport.clone();
+        let focused = focused.clone();
+        use_effect_with((scroll_ref, items_ref), move |(scroll_ref, items_ref)| {
+            let listeners = attach_listeners(scroll_ref, items_ref, slots_ref, viewport, focused);
+            move || drop(listeners)
+        });
+    }
+
+    {
+        // The heights are measured after every render, since the content of the items may
+        // have changed.
+        let scroll_ref = scroll_ref.clone();
+        let items_ref = items_ref.clone();
+        use_effect(move || {
+            if let RowHeight::Estimated(estimate) = row_height {
+                let changed = measure_items(
+                    &items_ref,
+                    &slots_ref.borrow(),
+                    &mut measured.borrow_mut(),
+                    estimate,
+                );
+                if changed {
+                    force_update.force_update();
+                }
+            }
+            if let Some(next_viewport) = read_viewport(&scroll_ref, &items_ref) {
+                viewport.set(next_viewport);
+            }
+            || ()
+        });
+    }
+
+    slots
+}
+
+/// The position of the top of each item, followed by the total height of the list.
+fn get_offsets(
+    item_count: usize,
+    row_height: RowHeight,
+    measured: &HashMap<usize, f64>,
+) -> Vec<f64> {
+    let mut offsets = Vec::with_capacity(item_count + 1);
+    let mut offset = 0.0;
+    offsets.push(offset);
+    for index in 0..item_count {
+        offset += match row_height {
+            RowHeight::Fixed(height) => height,
+            RowHeight::Estimated(height) => measured.get(&index).copied().unwrap_or(height),
+        };
+        offsets.push(offset);
+    }
+    offsets
+}
+
+fn get_visible_range(offsets: &[f64], viewport: Viewport, overscan: usize) -> Range<usize> {
+    let item_count = offsets.len() - 1;
+    let start = offsets
+        .partition_point(|offset| *offset <= viewport.start)
+        .saturating_sub(1)
+        .min(item_count);
+    let end = offsets
+        .partition_point(|offset| *offset < viewport.end)
+        .min(item_count);
+
+    start.saturating_sub(overscan)..(end + overscan).min(item_count)
+}
+
+fn get_slots(offsets: &[f64], range: Range<usize>, focused: Option<usize>) -> Vec<VirtualSlot> {
+    let mut indexes: Vec<usize> = range.clone().collect();
+    if let Some(focused) = focused.filter(|focused| !range.contains(focused)) {
+        let position = indexes.partition_point(|index| *index < focused);
+        indexes.insert(position, focused);
+    }
+
+    let mut slots = Vec::with_capacity(indexes.len() + 3);
+    let mut next = 0;
+    for index in indexes {
+        if offsets[index] > offsets[next] {
+            slots.push(VirtualSlot::Spacer(offsets[index] - offsets[next]));
+        }
+        slots.push(VirtualSlot::Item(index));
+        next = index + 1;
+    }
+
+    let total = offsets[offsets.len() - 1];
+    if total > offsets[next] {
+        slots.push(VirtualSlot::Spacer(total - offsets[next]));
+    }
+
+    slots
+}
+
+fn read_viewport(scroll_ref: &NodeRef, items_ref: &NodeRef) -> Option<Viewport> {
+    let container = scroll_ref.cast::<Element>()?;
+    let items = items_ref.cast::<Element>()?;
+
+    // Anything rendered above the items (like the header of a table) is not part of the list.
+    let items_top = items.get_bounding_client_rect().top()
+        - container.get_bounding_client_rect().top()
+        + container.scroll_top() as f64;
+    let scroll_top = container.scroll_top() as f64 - items_top;
+
+    Some(Viewport {
+        start: scroll_top.max(0.0),
+        end: scroll_top + container.client_height() as f64,
+    })
+}
+
+/// Stores the heights of the rendered items. Returns true if any of them is different from the
+/// height that was used to lay out the list.
+fn measure_items(
+    items_ref: &NodeRef,
+    slots: &[VirtualSlot],
+    measured: &mut HashMap<usize, f64>,
+    estimate: f64,
+) -> bool {
+    let mut changed = false;
+    let mut child = items_ref
+        .cast::<Element>()
+        .and_then(|items| items.first_element_child());
+
+    for slot in slots {
+        let element = match child {
+            Some(element) => element,
+            None => break,
+        };
+        child = element.next_element_sibling();
+
+        if let (VirtualSlot::Item(index), Some(element)) = (slot, element.dyn_ref::<HtmlElement>())
+        {
+            let height = element.offset_height() as f64;
+            let previous = measured.insert(*index, height).unwrap_or(estimate);
+            changed |= (previous - height).abs() >= 1.0;
+        }
+    }
+
+    changed
+}
+
+/// Finds the index of the item that contains the node.
+fn find_item(items_ref: &NodeRef, slots: &[VirtualSlot], node: &Node) -> Option<usize> {
+    let mut child = items_ref
+        .cast::<Element>()
+        .and_then(|items| items.first_element_child());
+
+    for slot in slots {
+        let element = child?;
+        if element.contains(Some(node)) {
+            return match slot {
+                VirtualSlot::Item(index) => Some(*index),
+                VirtualSlot::Spacer(_) => None,
+            };
+        }
+        child = element.next_element_sibling();
+    }
+
+    None
+}
+
+fn attach_listeners(
+    scroll_ref: &NodeRef,
+    items_ref: &NodeRef,
+    slots_ref: Rc<RefCell<Vec<VirtualSlot>>>,
+    viewport: UseStateHandle<Viewport>,
+    focused: UseStateHandle<Option<usize>>,
+) -> Vec<EventListener> {
+    let mut listeners = Vec::with_capacity(4);
+
+    let update_viewport = {
+        let scroll_ref = scroll_ref.clone();
+        let items_ref = items_ref.clone();
+        Rc::new(move || {
+            if let Some(next_viewport) = read_viewport(&scroll_ref, &items_ref) {
+                viewport.set(next_viewport);
+            }
+        })
+    };
+
+    if let Some(container) = scroll_ref.cast::<Element>() {
+        let update_viewport = update_viewport.clone();
+        listeners.push(EventListener::new(&container, "scroll", move |_| {
+            update_viewport()
+        }));
+    }
+
+    if let Some(window) = web_sys::window() {
+        listeners.push(EventListener::new(&window, "resize", move |_| {
+            update_viewport()
+        }));
+    }
+
+    if let Some(items) = items_ref.cast::<Element>() {
+        {
+            let items_ref = items_ref.clone();
+            let slots_ref = slots_ref.clone();
+            let focused = focused.clone();
+            listeners.push(EventListener::new(&items, "focusin", move |event| {
+                let index = event
+                    .target()
+                    .and_then(|target| target.dyn_into::<Node>().ok())
+                    .and_then(|target| find_item(&items_ref, &slots_ref.borrow(), &target));
+                focused.set(index);
+            }));
+        }
+
+        let items_ref = items_ref.clone();
+        listeners.push(EventListener::new(&items, "focusout", move |event| {
+            let next_target = event
+                .dyn_ref::<FocusEvent>()
+                .and_then(|event| event.related_target())
+                .and_then(|target| target.dyn_into::<Node>().ok());
+            let stays_inside = match (items_ref.cast::<Node>(), next_target) {
+                (Some(items), Some(next_target)) => items.contains(Some(&next_target)),
+                _ => false,
+            };
+            if !stays_inside {
+                focused.set(None);
+            }
+        }));
+    }
+
+    listeners
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn viewport(start: f64, end: f64) -> Viewport {
+        Viewport { start, end }
+    }
+
+    #[test]
+    fn offsets_of_fixed_rows() {
+        let offsets = get_offsets(3, RowHeight::Fixed(10.0), &HashMap::new());
+
+        assert_eq!(offsets, vec![0.0, 10.0, 20.0, 30.0]);
+    }
+
+    #[test]
+    fn offsets_of_estimated_rows_use_the_measured_heights() {
+        let measured = HashMap::from([(1, 25.0)]);
+        let offsets = get_offsets(3, RowHeight::Estimated(10.0), &measured);
+
+        assert_eq!(offsets, vec![0.0, 10.0, 35.0, 45.0]);
+    }
+
+    #[test]
+    fn offsets_of_an_empty_list() {
+        assert_eq!(
+            get_offsets(0, RowHeight::Fixed(10.0), &HashMap::new()),
+            vec![0.0]
+        );
+    }
+
+    #[test]
+    fn visible_range_includes_partially_visible_rows() {
+        let offsets = get_offsets(10, RowHeight::Fixed(10.0), &HashMap::new());
+
+        assert_eq!(get_visible_range(&offsets, viewport(0.0, 30.0), 0), 0..3);
+        assert_eq!(get_visible_range(&offsets, viewport(15.0, 35.0), 0), 1..4);
+    }
+
+    #[test]
+    fn visible_range_adds_the_overscan_within_the_bounds() {
+        let offsets = get_offsets(10, RowHeight::Fixed(10.0), &HashMap::new());
+
+        assert_eq!(get_visible_range(&offsets, viewport(40.0, 60.0), 2), 2..8);
+        assert_eq!(get_visible_range(&offsets, viewport(0.0, 20.0), 2), 0..4);
+        assert_eq!(get_visible_range(&offsets, viewport(80.0, 120.0), 2), 6..10);
+    }
+
+    #[test]
+    fn visible_range_of_an_empty_list() {
+        let offsets = get_offsets(0, RowHeight::Fixed(10.0), &HashMap::new());
+
+        assert_eq!(get_visible_range(&offsets, viewport(0.0, 100.0), 3), 0..0);
+    }
+
+    #[test]
+    fn slots_replace_the_hidden_rows_with_spacers() {
+        let offsets = get_offsets(10, RowHeight::Fixed(10.0), &HashMap::new());
+
+        assert_eq!(
+            get_slots(&offsets, 3..5, None),
+            vec![
+                VirtualSlot::Spacer(30.0),
+                VirtualSlot::Item(3),
+                VirtualSlot::Item(4),
+                VirtualSlot::Spacer(50.0),
+            ]
+        );
+        assert_eq!(
+            get_slots(&offsets, 0..10, None).len(),
+            10,
+            "no spacers are needed when every row is visible"
+        );
+    }
+
+    #[test]
+    fn slots_keep_the_focused_row() {
+        let offsets = get_offsets(10, RowHeight::Fixed(10.0), &HashMap::new());
+
+        assert_eq!(
+            get_slots(&offsets, 5..7, Some(1)),
+            vec![
+                VirtualSlot::Spacer(10.0),
+                VirtualSlot::Item(1),
+                VirtualSlot::Spacer(30.0),
+                VirtualSlot::Item(5),
+                VirtualSlot::Item(6),
+                VirtualSlot::Spacer(30.0),
+            ]
+        );
+        assert_eq!(
+            get_slots(&offsets, 5..7, Some(6)),
+            get_slots(&offsets, 5..7, None)
+        );
+    }
+}